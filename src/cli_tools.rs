@@ -4,7 +4,7 @@
 
 use anyhow::{bail, Context, Result};
 use dociium::doc_engine::DocEngine;
-use std::sync::Arc;
+use std::{path::Path, sync::Arc};
 
 /// Validates that item_path doesn't include the package name as a prefix
 fn validate_item_path(item_path: &str, package_name: &str) -> Result<()> {
@@ -104,6 +104,27 @@ pub async fn handle_command(cmd: crate::Commands, engine: Arc<DocEngine>) -> Res
             .await
         }
 
+        SearchSymbolsGlobal {
+            query,
+            kinds,
+            limit,
+            scope,
+            context,
+            fetch_missing,
+        } => {
+            let kinds_vec = kinds.map(|k| k.split(',').map(|s| s.to_string()).collect());
+            search_symbols_global(
+                &query,
+                kinds_vec,
+                limit,
+                scope.as_deref(),
+                context.as_deref(),
+                fetch_missing,
+                &engine,
+            )
+            .await
+        }
+
         // ===== Python/Node.js Tools =====
         GetImplementation {
             language,
@@ -112,36 +133,90 @@ pub async fn handle_command(cmd: crate::Commands, engine: Arc<DocEngine>) -> Res
             context,
         } => get_implementation(&language, &package, &path, context.as_deref(), &engine).await,
 
+        PackageInfo {
+            language,
+            package,
+            context,
+        } => package_info(&language, &package, context.as_deref(), &engine).await,
+
         ListClassMethods {
+            language,
             package,
             path,
             private,
+            inherited,
             context,
-        } => list_class_methods(&package, &path, private, &context, &engine).await,
+        } => {
+            list_class_methods(
+                &language, &package, &path, private, inherited, &context, &engine,
+            )
+            .await
+        }
+
+        GetClassInfo {
+            package,
+            path,
+            context,
+        } => get_class_info(&package, &path, &context, &engine).await,
 
         GetClassMethod {
+            language,
             package,
             path,
             method,
             context,
-        } => get_class_method(&package, &path, &method, &context, &engine).await,
+        } => get_class_method(&language, &package, &path, &method, &context, &engine).await,
 
         SearchPackageCode {
+            language,
             package,
             pattern,
             mode,
             limit,
             context,
-        } => search_package_code(&package, &pattern, &mode, limit, &context, &engine).await,
+        } => {
+            search_package_code(
+                &language, &package, &pattern, &mode, limit, &context, &engine,
+            )
+            .await
+        }
 
         SemanticSearch {
             package,
             query,
+            language,
             limit,
             context,
-        } => semantic_search(&package, &query, limit, &context, &engine).await,
+        } => semantic_search(&language, &package, &query, limit, &context, &engine).await,
+
+        CompletePath {
+            language,
+            prefix,
+            package,
+            version,
+            limit,
+            context,
+        } => {
+            complete_path(
+                &language,
+                &prefix,
+                package.as_deref(),
+                version.as_deref(),
+                limit,
+                context.as_deref(),
+                &engine,
+            )
+            .await
+        }
 
         // ===== Cache Management =====
+        Index {
+            from,
+            jobs,
+            context,
+            strict,
+        } => index_manifest(&from, jobs, context.as_deref(), strict, &engine).await,
+
         CacheStats => cache_stats(&engine).await,
 
         ClearCache { crate_name } => clear_cache(crate_name.as_deref(), &engine).await,
@@ -261,6 +336,39 @@ async fn search_symbols(
     Ok(())
 }
 
+async fn search_symbols_global(
+    query: &str,
+    kinds: Option<Vec<String>>,
+    limit: u32,
+    scope: Option<&str>,
+    context: Option<&str>,
+    fetch_missing: bool,
+    engine: &DocEngine,
+) -> Result<()> {
+    use dociium::doc_engine::types::SymbolSearchScope;
+    let scope = match scope.map(|s| s.trim().to_lowercase()).as_deref() {
+        None => None,
+        Some("lockfile") => Some(SymbolSearchScope::Lockfile),
+        Some("cache") => Some(SymbolSearchScope::Cache),
+        Some(other) => bail!("Unknown scope '{other}'. Expected 'lockfile' or 'cache'"),
+    };
+
+    let response = engine
+        .search_symbols_global(
+            query,
+            kinds.as_deref(),
+            limit,
+            scope,
+            context,
+            fetch_missing,
+        )
+        .await
+        .context("Failed to search symbols across crates")?;
+
+    println!("{}", serde_json::to_string_pretty(&response)?);
+    Ok(())
+}
+
 // ===== Python/Node.js Tool Implementations =====
 
 async fn get_implementation(
@@ -285,81 +393,172 @@ async fn get_implementation(
     Ok(())
 }
 
+async fn package_info(
+    language: &str,
+    package: &str,
+    context: Option<&str>,
+    engine: &DocEngine,
+) -> Result<()> {
+    let info = match language.trim().to_lowercase().as_str() {
+        "python" => serde_json::to_value(engine.python_package_info(package, context).await?)?,
+        "node" => serde_json::to_value(engine.node_package_info(package, context).await?)?,
+        other => bail!("Unsupported language '{other}'. Expected 'python' or 'node'"),
+    };
+
+    println!("{}", serde_json::to_string_pretty(&info)?);
+    Ok(())
+}
+
 async fn list_class_methods(
+    language: &str,
     package: &str,
     path: &str,
     include_private: bool,
+    include_inherited: bool,
     context: &str,
     engine: &DocEngine,
 ) -> Result<()> {
-    use dociium::doc_engine::python_analyzer::MethodInfo;
     // Validate that item_path doesn't include package name prefix
     validate_item_path(path, package)?;
+    let context_path = std::path::PathBuf::from(context);
 
-    // Parse path: "relative/path#ClassName"
-    let parts: Vec<&str> = path.split('#').collect();
-    if parts.len() != 2 {
-        bail!("Path must be in format 'path/to/file#ClassName'");
-    }
+    let methods = match language.trim().to_lowercase().as_str() {
+        "python" => {
+            // "relative/path#ClassName", or a dotted import path such as "requests.Session"
+            let (relative_path, class_name) = engine
+                .python_processor
+                .resolve_item_path(package, &context_path, path)
+                .await
+                .context("Failed to resolve item path")?;
+            let methods = engine
+                .python_processor
+                .list_class_methods(
+                    package,
+                    &context_path,
+                    &relative_path,
+                    &class_name,
+                    include_private,
+                    include_inherited,
+                )
+                .await
+                .context("Failed to list class methods")?;
+            serde_json::to_value(methods)?
+        }
+        "node" => {
+            if include_inherited {
+                bail!("--inherited is only supported for Python");
+            }
+            let (relative_path, class_name) = path
+                .split_once('#')
+                .context("Node.js item paths must be 'path/to/file#ClassName'")?;
+            let methods = engine
+                .node_processor
+                .list_class_methods(
+                    package,
+                    &context_path,
+                    relative_path,
+                    class_name,
+                    include_private,
+                )
+                .await
+                .context("Failed to list class methods")?;
+            serde_json::to_value(methods)?
+        }
+        other => bail!("Unsupported language '{other}'. Expected 'python' or 'node'"),
+    };
 
-    let relative_path = parts[0];
-    let class_name = parts[1];
+    println!("{}", serde_json::to_string_pretty(&methods)?);
+    Ok(())
+}
+
+async fn get_class_info(
+    package: &str,
+    path: &str,
+    context: &str,
+    engine: &DocEngine,
+) -> Result<()> {
+    use dociium::doc_engine::python_analyzer::ClassInfo;
+    // Validate that item_path doesn't include package name prefix
+    validate_item_path(path, package)?;
+
+    // "relative/path#ClassName", or a dotted import path such as "requests.Session"
     let context_path = std::path::PathBuf::from(context);
+    let (relative_path, class_name) = engine
+        .python_processor
+        .resolve_item_path(package, &context_path, path)
+        .await
+        .context("Failed to resolve item path")?;
+    let (relative_path, class_name) = (relative_path.as_str(), class_name.as_str());
 
-    let methods: Vec<MethodInfo> = engine
+    let info: ClassInfo = engine
         .python_processor
-        .list_class_methods(
-            package,
-            &context_path,
-            relative_path,
-            class_name,
-            include_private,
-        )
+        .get_class_info(package, &context_path, relative_path, class_name)
         .await
-        .context("Failed to list class methods")?;
+        .context("Failed to get class info")?;
 
-    println!("{}", serde_json::to_string_pretty(&methods)?);
+    println!("{}", serde_json::to_string_pretty(&info)?);
     Ok(())
 }
 
 async fn get_class_method(
+    language: &str,
     package: &str,
     path: &str,
     method_name: &str,
     context: &str,
     engine: &DocEngine,
 ) -> Result<()> {
-    use dociium::doc_engine::python_analyzer::MethodInfo;
     // Validate that item_path doesn't include package name prefix
     validate_item_path(path, package)?;
-
-    // Parse path: "relative/path#ClassName"
-    let parts: Vec<&str> = path.split('#').collect();
-    if parts.len() != 2 {
-        bail!("Path must be in format 'path/to/file#ClassName'");
-    }
-
-    let relative_path = parts[0];
-    let class_name = parts[1];
     let context_path = std::path::PathBuf::from(context);
 
-    let method: MethodInfo = engine
-        .python_processor
-        .get_class_method(
-            package,
-            &context_path,
-            relative_path,
-            class_name,
-            method_name,
-        )
-        .await
-        .context("Failed to get class method")?;
+    let method = match language.trim().to_lowercase().as_str() {
+        "python" => {
+            // "relative/path#ClassName", or a dotted import path such as "requests.Session"
+            let (relative_path, class_name) = engine
+                .python_processor
+                .resolve_item_path(package, &context_path, path)
+                .await
+                .context("Failed to resolve item path")?;
+            let method = engine
+                .python_processor
+                .get_class_method(
+                    package,
+                    &context_path,
+                    &relative_path,
+                    &class_name,
+                    method_name,
+                )
+                .await
+                .context("Failed to get class method")?;
+            serde_json::to_value(method)?
+        }
+        "node" => {
+            let (relative_path, class_name) = path
+                .split_once('#')
+                .context("Node.js item paths must be 'path/to/file#ClassName'")?;
+            let method = engine
+                .node_processor
+                .get_class_method(
+                    package,
+                    &context_path,
+                    relative_path,
+                    class_name,
+                    method_name,
+                )
+                .await
+                .context("Failed to get class method")?;
+            serde_json::to_value(method)?
+        }
+        other => bail!("Unsupported language '{other}'. Expected 'python' or 'node'"),
+    };
 
     println!("{}", serde_json::to_string_pretty(&method)?);
     Ok(())
 }
 
 async fn search_package_code(
+    language: &str,
     package: &str,
     pattern: &str,
     mode: &str,
@@ -379,34 +578,110 @@ async fn search_package_code(
 
     let context_path = std::path::PathBuf::from(context);
 
-    let results: Vec<SearchResult> = engine
-        .python_processor
-        .search_package(package, &context_path, pattern, search_mode, limit as usize)
-        .await
-        .context("Failed to search package code")?;
+    let results: Vec<SearchResult> = match language.trim().to_lowercase().as_str() {
+        "python" => {
+            engine
+                .python_processor
+                .search_package(package, &context_path, pattern, search_mode, limit as usize)
+                .await
+        }
+        "node" => {
+            engine
+                .node_processor
+                .search_package(package, &context_path, pattern, search_mode, limit as usize)
+                .await
+        }
+        other => bail!("Unsupported language '{other}'. Expected 'python' or 'node'"),
+    }
+    .context("Failed to search package code")?;
 
     println!("{}", serde_json::to_string_pretty(&results)?);
     Ok(())
 }
 
 async fn semantic_search(
+    language: &str,
     package: &str,
     query: &str,
     limit: u32,
     context: &str,
     engine: &DocEngine,
 ) -> Result<()> {
-    let results = engine
-        .semantic_search("python", package, query, limit as usize, Some(context))
-        .await
-        .context("Failed to perform semantic search")?;
+    let language = language.trim().to_lowercase();
+    let packages: Vec<String> = package
+        .split(',')
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+        .collect();
+    let results = if language == "python" && (packages.len() > 1 || package.trim() == "*") {
+        engine
+            .semantic_search_python_packages(&packages, query, limit as usize, Some(context))
+            .await
+    } else {
+        engine
+            .semantic_search(&language, package, query, limit as usize, Some(context))
+            .await
+    }
+    .context("Failed to perform semantic search")?;
 
     println!("{}", serde_json::to_string_pretty(&results)?);
     Ok(())
 }
 
+async fn complete_path(
+    language: &str,
+    prefix: &str,
+    package: Option<&str>,
+    version: Option<&str>,
+    limit: u32,
+    context: Option<&str>,
+    engine: &DocEngine,
+) -> Result<()> {
+    let completions = engine
+        .complete_path(
+            &language.trim().to_lowercase(),
+            prefix,
+            package,
+            version,
+            limit as usize,
+            context,
+        )
+        .await
+        .context("Failed to complete path")?;
+
+    println!("{}", serde_json::to_string_pretty(&completions)?);
+    Ok(())
+}
+
 // ===== Cache Management Implementations =====
 
+async fn index_manifest(
+    manifest: &Path,
+    jobs: usize,
+    context: Option<&str>,
+    strict: bool,
+    engine: &DocEngine,
+) -> Result<()> {
+    let report = engine
+        .index_manifest(manifest, jobs, context)
+        .await
+        .with_context(|| format!("Failed to index dependencies from {}", manifest.display()))?;
+
+    println!("{}", serde_json::to_string_pretty(&report)?);
+    eprintln!(
+        "Indexed {}/{} entries ({} failed)",
+        report.succeeded, report.total, report.failed
+    );
+    if strict && report.failed > 0 {
+        bail!(
+            "{} of {} entries failed to index",
+            report.failed,
+            report.total
+        );
+    }
+    Ok(())
+}
+
 async fn cache_stats(engine: &DocEngine) -> Result<()> {
     use dociium::doc_engine::types::CacheStatistics;
     let stats: CacheStatistics = engine
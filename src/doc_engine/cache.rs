@@ -79,6 +79,7 @@ impl Cache {
         fs::create_dir_all(cache_dir.join("items"))?;
         fs::create_dir_all(cache_dir.join("crates"))?;
         fs::create_dir_all(cache_dir.join("indexes"))?;
+        fs::create_dir_all(cache_dir.join("python"))?;
 
         let memory_cache = Arc::new(Mutex::new(HashMap::new()));
         let item_cache = Arc::new(Mutex::new(HashMap::new()));
@@ -473,6 +474,19 @@ impl Cache {
         Ok(None)
     }
 
+    /// Active cache configuration
+    pub fn config(&self) -> &CacheConfig {
+        &self.config
+    }
+
+    /// On-disk location of a crate's cached search index
+    pub fn crate_index_path(&self, crate_name: &str, version: &str) -> PathBuf {
+        self.cache_dir.join("crates").join(format!(
+            "{}.crate",
+            self.sanitize_filename(&format!("{crate_name}@{version}"))
+        ))
+    }
+
     /// Store crate-level search index data
     pub fn store_crate_index(
         &self,
@@ -497,10 +511,7 @@ impl Cache {
         }
 
         // Store to disk
-        let file_path = self
-            .cache_dir
-            .join("crates")
-            .join(format!("{}.crate", self.sanitize_filename(&cache_key)));
+        let file_path = self.crate_index_path(crate_name, version);
         let serialized = bincode::serialize(&entry)?;
         let compressed = if self.config.enable_compression {
             self._compress_data(&serialized)?
@@ -536,10 +547,7 @@ impl Cache {
         }
 
         // Check disk cache
-        let file_path = self
-            .cache_dir
-            .join("crates")
-            .join(format!("{}.crate", self.sanitize_filename(&cache_key)));
+        let file_path = self.crate_index_path(crate_name, version);
         if file_path.exists() {
             let compressed = fs::read(&file_path)?;
             let serialized = if self.config.enable_compression {
@@ -568,6 +576,90 @@ impl Cache {
         Ok(None)
     }
 
+    /// On-disk location of a persisted Python semantic index.
+    ///
+    /// The file name combines the package name with a digest of the package
+    /// root so that the same package installed in different environments
+    /// gets separate entries.
+    pub fn python_index_path(&self, package_name: &str, package_root: &Path) -> PathBuf {
+        let digest = sha2::Sha256::digest(package_root.to_string_lossy().as_bytes());
+        self.cache_dir.join("python").join(format!(
+            "{}@{:.16}.pyidx",
+            self.sanitize_filename(package_name),
+            format!("{digest:x}")
+        ))
+    }
+
+    /// Store a serialized Python semantic index
+    pub fn store_python_index(
+        &self,
+        package_name: &str,
+        package_root: &Path,
+        bytes: &[u8],
+    ) -> Result<()> {
+        let file_path = self.python_index_path(package_name, package_root);
+        let data = if self.config.enable_compression {
+            self._compress_data(bytes)?
+        } else {
+            bytes.to_vec()
+        };
+        fs::write(&file_path, data)?;
+
+        debug!("Stored Python index for: {}", file_path.display());
+        self.increment_stat("puts");
+        Ok(())
+    }
+
+    /// Retrieve a serialized Python semantic index
+    pub fn get_python_index(
+        &self,
+        package_name: &str,
+        package_root: &Path,
+    ) -> Result<Option<Vec<u8>>> {
+        let file_path = self.python_index_path(package_name, package_root);
+        if !file_path.exists() {
+            debug!("Python index cache miss for: {}", package_name);
+            self.increment_stat("misses");
+            return Ok(None);
+        }
+
+        let data = fs::read(&file_path)?;
+        let bytes = if self.config.enable_compression {
+            self._decompress_data(&data)?
+        } else {
+            data
+        };
+        debug!("Python index cache hit (disk) for: {}", package_name);
+        self.increment_stat("hits");
+        Ok(Some(bytes))
+    }
+
+    /// List `(crate_name, version)` pairs with a crate index on disk
+    pub fn list_crate_indexes(&self) -> Result<Vec<(String, String)>> {
+        let dir_path = self.cache_dir.join("crates");
+        let mut indexes = Vec::new();
+        if !dir_path.exists() {
+            return Ok(indexes);
+        }
+
+        for entry in fs::read_dir(&dir_path)? {
+            let path = entry?.path();
+            if !path.is_file() || path.extension().is_none_or(|ext| ext != "crate") {
+                continue;
+            }
+            if let Some((name, version)) = path
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .and_then(|stem| stem.rsplit_once('@'))
+            {
+                indexes.push((name.to_string(), version.to_string()));
+            }
+        }
+
+        indexes.sort();
+        Ok(indexes)
+    }
+
     /// Clear all cache entries
     pub fn clear_all(&self) -> Result<CacheOperationResult> {
         let mut items_affected = 0;
@@ -591,7 +683,7 @@ impl Cache {
         }
 
         // Clear disk cache
-        for subdir in &["items", "crates", "indexes"] {
+        for subdir in &["items", "crates", "indexes", "python"] {
             let dir_path = self.cache_dir.join(subdir);
             if dir_path.exists() {
                 for entry in fs::read_dir(&dir_path)? {
@@ -735,7 +827,7 @@ impl Cache {
 
         // Count disk entries
         let mut disk_entries = 0;
-        for subdir in &["items", "crates", "indexes"] {
+        for subdir in &["items", "crates", "indexes", "python"] {
             let dir_path = self.cache_dir.join(subdir);
             if dir_path.exists() {
                 for entry in fs::read_dir(&dir_path)? {
@@ -916,7 +1008,7 @@ impl Cache {
         let mut oldest = SystemTime::now();
 
         // Check all cache directories
-        for subdir in &["items", "crates", "indexes"] {
+        for subdir in &["items", "crates", "indexes", "python"] {
             let dir_path = self.cache_dir.join(subdir);
             if dir_path.exists() {
                 for entry in fs::read_dir(&dir_path)? {
@@ -1,4 +1,5 @@
-//! Finds installed packages on the local filesystem using command-line tools.
+//! Finds installed packages on the local filesystem, scanning well-known
+//! locations first and falling back to command-line tools.
 
 use anyhow::{anyhow, Context, Result};
 use semver::Version;
@@ -20,10 +21,12 @@ pub fn find_python_package_path(package_name: &str) -> Result<PathBuf> {
 ///
 /// Resolution order:
 /// 1. Environment variable overrides (`DOC_PYTHON_PACKAGE_PATH*`)
-/// 2. Native Python introspection (using importlib - works with all package managers)
-/// 3. `pip show` command (if pip is available)
-/// 4. `uv pip show` command (fallback for uv-managed environments)
-/// 5. Direct site-packages scanning (last resort fallback)
+/// 2. The standard library of the context's interpreter
+/// 3. Native Python introspection (using importlib - works with all package managers)
+/// 4. `pip show` command (if pip is available)
+/// 5. `uv pip show` command (fallback for uv-managed environments)
+/// 6. Direct site-packages scanning
+/// 7. Typeshed stubs of standard library modules implemented in C (last resort)
 pub fn find_python_package_path_with_context(
     package_name: &str,
     context_path: Option<&Path>,
@@ -49,6 +52,11 @@ pub fn find_python_package_path_with_context(
         }
     }
 
+    // Strategy 0: The standard library, which precedes site-packages on sys.path
+    if let Some(path) = find_python_stdlib_module(package_name, context_path) {
+        return Ok(path);
+    }
+
     // Strategy 1: Try native Python introspection using importlib
     // This is the most reliable method and works with all package managers
     if let Ok(path) = try_python_introspection(package_name, context_path) {
@@ -70,9 +78,15 @@ pub fn find_python_package_path_with_context(
         return Ok(path);
     }
 
+    // Strategy 5: Standard library modules implemented in C, through typeshed's stubs
+    if let Some(path) = find_typeshed_stdlib_module(package_name, context_path) {
+        return Ok(path);
+    }
+
     Err(anyhow!(
         "Could not find Python package '{}' using any available method. Tried: \
-         Python introspection, pip show, uv pip show, and site-packages scanning.",
+         the standard library, Python introspection, pip show, uv pip show, \
+         and site-packages scanning.",
         package_name
     ))
 }
@@ -208,17 +222,17 @@ fn find_package_in_site_packages(site_packages: &Path, package_name: &str) -> Re
     // Check for package as directory
     if package_path.is_dir() {
         // Verify it's a valid Python package
-        // It should have __init__.py or at least one .py file
-        let init_py = package_path.join("__init__.py");
-        if init_py.exists() {
+        // It should have __init__.py(i) or at least one module, which may be
+        // a stub or a compiled extension
+        if package_path.join("__init__.py").exists() || package_path.join("__init__.pyi").exists() {
             return Ok(package_path);
         }
 
-        // Check for namespace package (has .py files but no __init__.py)
+        // Check for namespace package (has modules but no __init__.py)
         if let Ok(entries) = std::fs::read_dir(&package_path) {
             for entry in entries.flatten() {
                 if let Some(ext) = entry.path().extension() {
-                    if ext == "py" {
+                    if ext == "py" || ext == "pyi" || ext == "so" || ext == "pyd" {
                         return Ok(package_path);
                     }
                 }
@@ -232,6 +246,12 @@ fn find_package_in_site_packages(site_packages: &Path, package_name: &str) -> Re
         return Ok(single_file);
     }
 
+    // Compiled single-module packages are only readable through their stubs
+    let stubs = site_packages.join(format!("{package_name}-stubs"));
+    if stubs.is_dir() {
+        return Ok(stubs);
+    }
+
     Err(anyhow!("Package not found in site-packages"))
 }
 
@@ -347,7 +367,261 @@ fn try_site_packages_scan(package_name: &str, context_path: Option<&Path>) -> Re
     Err(anyhow!("Package not found in site-packages scan"))
 }
 
-/// Finds the installation path of a Node.js package using `npm root`.
+/// Finds the site-packages directory of the Python environment serving `context_path`.
+///
+/// Resolution order:
+/// 1. `DOC_PYTHON_PACKAGE_PATH` (global override)
+/// 2. `.venv` or `venv` in the context directory
+/// 3. The active `VIRTUAL_ENV`
+pub fn find_python_site_packages(context_path: Option<&Path>) -> Result<PathBuf> {
+    if let Ok(global_override) = std::env::var("DOC_PYTHON_PACKAGE_PATH") {
+        let p = PathBuf::from(global_override);
+        if p.is_dir() {
+            return Ok(p);
+        }
+    }
+
+    let base_dir = context_path.unwrap_or_else(|| Path::new("."));
+    let mut venv_candidates = vec![base_dir.join(".venv"), base_dir.join("venv")];
+    if let Ok(virtual_env) = std::env::var("VIRTUAL_ENV") {
+        venv_candidates.push(PathBuf::from(virtual_env));
+    }
+
+    for venv_dir in venv_candidates {
+        let windows = venv_dir.join("Lib").join("site-packages");
+        if windows.is_dir() {
+            return Ok(windows);
+        }
+        let Ok(entries) = std::fs::read_dir(venv_dir.join("lib")) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let site_packages = entry.path().join("site-packages");
+            let is_python_dir = entry.file_name().to_string_lossy().starts_with("python");
+            if is_python_dir && site_packages.is_dir() {
+                return Ok(site_packages);
+            }
+        }
+    }
+
+    Err(anyhow!(
+        "No Python virtual environment found in '{}' (looked for .venv, venv and VIRTUAL_ENV)",
+        base_dir.display()
+    ))
+}
+
+/// Lists the top-level importable packages and modules in a site-packages directory.
+///
+/// Private names (`_foo`), metadata directories and compiled-only modules are skipped.
+pub fn list_python_site_packages(site_packages: &Path) -> Result<Vec<String>> {
+    let entries = std::fs::read_dir(site_packages)
+        .with_context(|| format!("Failed to read {}", site_packages.display()))?;
+
+    let mut packages: Vec<String> = entries
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.path();
+            let name = entry.file_name().to_string_lossy().into_owned();
+            if name.starts_with('_') || name.starts_with('.') || name.contains('-') {
+                return None;
+            }
+            if path.is_dir() {
+                find_package_in_site_packages(site_packages, &name)
+                    .ok()
+                    .map(|_| name)
+            } else {
+                name.strip_suffix(".py").map(str::to_string)
+            }
+        })
+        .collect();
+    packages.sort();
+    packages.dedup();
+    Ok(packages)
+}
+
+/// Finds the standard library of the interpreter a site-packages directory
+/// belongs to.
+///
+/// System installs keep it in the parent of site-packages (`lib/python3.X`);
+/// virtual environments name the base interpreter's `bin` directory in
+/// `pyvenv.cfg`, next to which its `lib/python3.X` lives.
+pub fn find_python_stdlib(site_packages: &Path) -> Option<PathBuf> {
+    let is_stdlib = |dir: &Path| dir.join("os.py").is_file();
+    let lib_dir = site_packages.parent()?;
+    if is_stdlib(lib_dir) {
+        return Some(lib_dir.to_path_buf());
+    }
+
+    // `venv/lib/python3.X/site-packages` on Unix, `venv/Lib/site-packages` on Windows
+    let version_dir = lib_dir.file_name()?;
+    let venv = if version_dir == "Lib" {
+        lib_dir.parent()?
+    } else {
+        lib_dir.parent()?.parent()?
+    };
+    let config = std::fs::read_to_string(venv.join("pyvenv.cfg")).ok()?;
+    let home = config.lines().find_map(|line| {
+        let (key, value) = line.split_once('=')?;
+        (key.trim() == "home").then(|| PathBuf::from(value.trim()))
+    })?;
+    let prefix = home.parent()?;
+    [
+        prefix.join("lib").join(version_dir),
+        prefix.join("Lib"),
+        home.join("Lib"),
+    ]
+    .into_iter()
+    .find(|dir| is_stdlib(dir))
+}
+
+/// Finds the standard library of a system Python install, preferring the
+/// newest version when several are installed.
+fn find_system_python_stdlib() -> Option<PathBuf> {
+    let prefixes = [
+        "/usr/local/lib",
+        "/usr/lib",
+        "/opt/homebrew/lib",
+        "/Library/Frameworks/Python.framework/Versions/Current/lib",
+    ];
+    let version = |dir: &Path| -> Option<(u32, u32)> {
+        let name = dir.file_name()?.to_str()?.strip_prefix("python")?;
+        let (major, minor) = name.split_once('.')?;
+        Some((major.parse().ok()?, minor.parse().ok()?))
+    };
+    prefixes
+        .iter()
+        .filter_map(|prefix| std::fs::read_dir(prefix).ok())
+        .flat_map(|entries| entries.flatten().map(|entry| entry.path()))
+        .filter(|dir| dir.join("os.py").is_file())
+        .filter_map(|dir| Some((version(&dir)?, dir)))
+        .max()
+        .map(|(_, dir)| dir)
+}
+
+/// Standard library of the Python serving `context_path`, and the
+/// environment's site-packages when there is one.
+fn python_stdlib_for_context(context_path: Option<&Path>) -> (Option<PathBuf>, Option<PathBuf>) {
+    let site_packages = find_python_site_packages(context_path).ok();
+    let stdlib = site_packages
+        .as_deref()
+        .and_then(find_python_stdlib)
+        .or_else(find_system_python_stdlib);
+    (stdlib, site_packages)
+}
+
+/// Finds a module of the standard library (`asyncio`, `json`, `pathlib`)
+/// of the Python serving `context_path`.
+///
+/// The standard library is located through the environment's `pyvenv.cfg`
+/// or common install prefixes.
+pub fn find_python_stdlib_module(
+    package_name: &str,
+    context_path: Option<&Path>,
+) -> Option<PathBuf> {
+    let (stdlib, _) = python_stdlib_for_context(context_path);
+    let stdlib = stdlib?;
+    let package = stdlib.join(package_name);
+    if package.join("__init__.py").is_file() {
+        return Some(package);
+    }
+    let module = stdlib.join(format!("{package_name}.py"));
+    module.is_file().then_some(module)
+}
+
+/// Finds typeshed's stub for a standard library module without Python
+/// source, such as `math` or `_json`.
+fn find_typeshed_stdlib_module(package_name: &str, context_path: Option<&Path>) -> Option<PathBuf> {
+    let (stdlib, site_packages) = python_stdlib_for_context(context_path);
+    let typeshed = site_packages
+        .into_iter()
+        .chain(stdlib.map(|stdlib| stdlib.join("site-packages")))
+        .find_map(|site_packages| find_typeshed_stdlib(&site_packages))?;
+    let package = typeshed.join(package_name);
+    if package.join("__init__.pyi").is_file() {
+        return Some(package);
+    }
+    let module = typeshed.join(format!("{package_name}.pyi"));
+    module.is_file().then_some(module)
+}
+
+/// Finds a typeshed copy of the standard library stubs bundled with a type
+/// checker in site-packages. It is the only readable description of
+/// builtins such as `dict`, which have no Python source.
+pub fn find_typeshed_stdlib(site_packages: &Path) -> Option<PathBuf> {
+    ["mypy/typeshed/stdlib", "jedi/third_party/typeshed/stdlib"]
+        .into_iter()
+        .map(|relative| site_packages.join(relative))
+        .find(|dir| dir.join("builtins.pyi").is_file())
+}
+
+/// Finds the stub-only distribution (`<pkg>-stubs`) installed next to a
+/// Python package. Typeshed's `types-<pkg>` distributions install here too.
+///
+/// Standard library modules are described by typeshed's `stdlib`
+/// directory, when a type checker bundling it is installed; for single-file
+/// modules this is the `.pyi` file itself. Typeshed is looked up next to
+/// the standard library and in the site-packages of `context_path`.
+pub fn find_python_stub_dir(
+    package_root: &Path,
+    package_name: &str,
+    context_path: Option<&Path>,
+) -> Option<PathBuf> {
+    let parent = package_root.parent()?;
+    let stubs = parent.join(format!("{package_name}-stubs"));
+    if stubs.is_dir() && stubs != package_root {
+        return Some(stubs);
+    }
+
+    if !parent.join("os.py").is_file() {
+        return None;
+    }
+    let typeshed = std::iter::once(parent.join("site-packages"))
+        .chain(find_python_site_packages(context_path).ok())
+        .find_map(|site_packages| find_typeshed_stdlib(&site_packages))?;
+    let stub = match package_root.is_dir() {
+        true => typeshed.join(package_name),
+        false => typeshed.join(format!("{package_name}.pyi")),
+    };
+    stub.exists().then_some(stub)
+}
+
+/// Finds the `.pyi` stub describing a module of a Python package.
+///
+/// `relative_path` is the module's path inside the package root, e.g.
+/// `sessions.py` or a compiled `_speedups.cpython-312-x86_64-linux-gnu.so`.
+/// A stub next to the module wins over one in a `<pkg>-stubs` directory.
+pub fn find_python_stub(
+    package_root: &Path,
+    package_name: &str,
+    relative_path: &str,
+    context_path: Option<&Path>,
+) -> Option<PathBuf> {
+    let relative = Path::new(relative_path);
+    let file_name = relative.file_name()?.to_str()?;
+    // `_speedups.cpython-312-x86_64-linux-gnu.so` describes module `_speedups`
+    let module = match file_name.split_once('.') {
+        Some((module, _)) => module,
+        None => file_name,
+    };
+    let stub = relative.with_file_name(format!("{module}.pyi"));
+    let inline = package_root.join(&stub);
+    if inline.is_file() {
+        return Some(inline);
+    }
+    let in_stub_dir = find_python_stub_dir(package_root, package_name, context_path)?.join(&stub);
+    in_stub_dir.is_file().then_some(in_stub_dir)
+}
+
+/// Finds the installation path of a Node.js package the way Node resolves
+/// `require`/`import`, without needing npm.
+///
+/// Resolution order:
+/// 1. Environment variable overrides (`DOC_NODE_PACKAGE_PATH*`)
+/// 2. `node_modules` directories from `context_path` upwards, including the
+///    pnpm virtual store (`node_modules/.pnpm`) for packages not hoisted
+/// 3. Yarn Plug'n'Play maps (`.pnp.data.json` / `.pnp.cjs`)
+/// 4. `NODE_PATH` and global install prefixes (npm, nvm, pnpm, Yarn, Bun)
+/// 5. `npm root` (last resort)
 pub fn find_node_package_path(package_name: &str, context_path: &Path) -> Result<PathBuf> {
     // Environment variable overrides (precedence order):
     //  1. DOC_NODE_PACKAGE_PATH (points to a node_modules directory)
@@ -369,6 +643,193 @@ pub fn find_node_package_path(package_name: &str, context_path: &Path) -> Result
         }
     }
 
+    let context_dir = context_path
+        .canonicalize()
+        .unwrap_or_else(|_| context_path.to_path_buf());
+
+    // Strategy 1: node_modules hierarchy (hoisted installs, pnpm, Bun)
+    for dir in context_dir.ancestors() {
+        let node_modules = dir.join("node_modules");
+        if let Some(path) = find_package_in_node_modules(&node_modules, package_name) {
+            return Ok(path);
+        }
+    }
+
+    // Strategy 2: Yarn Plug'n'Play
+    if let Some(path) = context_dir
+        .ancestors()
+        .find_map(|dir| find_package_in_pnp_map(dir, package_name))
+    {
+        return Ok(path);
+    }
+
+    // Strategy 3: NODE_PATH and global prefixes
+    for node_modules in global_node_modules_dirs() {
+        let candidate = node_modules.join(package_name);
+        if candidate.join("package.json").is_file() {
+            return Ok(candidate);
+        }
+    }
+
+    // Strategy 4: npm
+    if let Ok(path) = try_npm_root(package_name, &context_dir) {
+        return Ok(path);
+    }
+
+    Err(anyhow!(
+        "Could not find Node.js package '{}' from '{}'. Tried: node_modules \
+         directories (including pnpm's store), Yarn Plug'n'Play maps, NODE_PATH, \
+         global install prefixes and npm root.",
+        package_name,
+        context_dir.display()
+    ))
+}
+
+/// A package directly in `node_modules`, or only in pnpm's virtual store
+/// when it is not hoisted.
+fn find_package_in_node_modules(node_modules: &Path, package_name: &str) -> Option<PathBuf> {
+    let direct = node_modules.join(package_name);
+    if direct.join("package.json").is_file() {
+        return Some(direct);
+    }
+    let store = node_modules.join(".pnpm");
+    if !store.is_dir() {
+        return None;
+    }
+    // pnpm's hidden hoisting target for transitive dependencies
+    let hoisted = store.join("node_modules").join(package_name);
+    if hoisted.join("package.json").is_file() {
+        return Some(hoisted);
+    }
+    // `.pnpm/<name>@<version>[_<peers>]/node_modules/<name>` (pnpm v8 and
+    // later write peers as `(<peer>@<version>)`), `/` in scoped names written
+    // as `+`
+    let prefix = format!("{}@", package_name.replace('/', "+"));
+    let mut versions: Vec<(Option<Version>, PathBuf)> = std::fs::read_dir(&store)
+        .ok()?
+        .flatten()
+        .filter_map(|entry| {
+            let name = entry.file_name().to_string_lossy().into_owned();
+            let version = name.strip_prefix(&prefix)?;
+            let version = version.split(['_', '(']).next().unwrap_or(version);
+            let path = entry.path().join("node_modules").join(package_name);
+            path.join("package.json")
+                .is_file()
+                .then(|| (Version::parse(version).ok(), path))
+        })
+        .collect();
+    versions.sort();
+    versions.pop().map(|(_, path)| path)
+}
+
+/// Look a package up in the Yarn Plug'n'Play map of the project at
+/// `project_dir`. Only unplugged packages (`.yarn/unplugged`) have a
+/// directory to read; packages zipped in `.yarn/cache` are skipped.
+fn find_package_in_pnp_map(project_dir: &Path, package_name: &str) -> Option<PathBuf> {
+    let data = std::fs::read_to_string(project_dir.join(".pnp.data.json"))
+        .ok()
+        .or_else(|| {
+            let script = std::fs::read_to_string(project_dir.join(".pnp.cjs")).ok()?;
+            pnp_runtime_state(&script)
+        })?;
+    let state: serde_json::Value = serde_json::from_str(&data).ok()?;
+    let registry = state.get("packageRegistryData")?.as_array()?;
+
+    // `[name, [[reference, info], ...]]`, with a `null` name for the project
+    fn references<'a>(
+        registry: &'a [serde_json::Value],
+        name: Option<&'a str>,
+    ) -> impl Iterator<Item = &'a serde_json::Value> {
+        registry
+            .iter()
+            .filter(move |entry| entry.get(0).and_then(|n| n.as_str()) == name)
+            .filter_map(|entry| entry.get(1)?.as_array())
+            .flatten()
+    }
+    // The reference the top-level workspace depends on, if any
+    let wanted = references(registry, None)
+        .filter_map(|reference| reference.get(1)?.get("packageDependencies")?.as_array())
+        .flatten()
+        .find(|dependency| dependency.get(0).and_then(|n| n.as_str()) == Some(package_name))
+        .and_then(|dependency| dependency.get(1)?.as_str().map(str::to_string));
+
+    let mut locations = references(registry, Some(package_name)).filter_map(|reference| {
+        let name = reference.get(0)?.as_str()?;
+        let location = reference.get(1)?.get("packageLocation")?.as_str()?;
+        Some((name.to_string(), project_dir.join(location)))
+    });
+    let location = match wanted {
+        Some(wanted) => locations.find(|(reference, _)| *reference == wanted),
+        None => locations.next(),
+    }?
+    .1;
+    location.join("package.json").is_file().then_some(location)
+}
+
+/// The JSON state embedded in `.pnp.cjs` as `RAW_RUNTIME_STATE`.
+fn pnp_runtime_state(script: &str) -> Option<String> {
+    let start = script.find("RAW_RUNTIME_STATE")?;
+    let rest = &script[start..];
+    let open = rest.find('\'')? + 1;
+    let mut state = String::new();
+    let mut chars = rest[open..].chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next()? {
+                // Line continuation of the multi-line literal
+                '\n' => {}
+                'n' => state.push('\n'),
+                escaped => state.push(escaped),
+            },
+            '\'' => return Some(state),
+            c => state.push(c),
+        }
+    }
+    None
+}
+
+/// `NODE_PATH` entries and the global `node_modules` of common Node
+/// installations.
+fn global_node_modules_dirs() -> Vec<PathBuf> {
+    let mut dirs: Vec<PathBuf> = std::env::var_os("NODE_PATH")
+        .map(|paths| std::env::split_paths(&paths).collect())
+        .unwrap_or_default();
+    for prefix_var in ["npm_config_prefix", "NPM_CONFIG_PREFIX", "PREFIX"] {
+        if let Some(prefix) = std::env::var_os(prefix_var) {
+            dirs.push(PathBuf::from(&prefix).join("lib/node_modules"));
+            dirs.push(PathBuf::from(prefix).join("node_modules"));
+        }
+    }
+    if let Some(nvm_bin) = std::env::var_os("NVM_BIN") {
+        dirs.push(PathBuf::from(nvm_bin).join("../lib/node_modules"));
+    }
+    if let Some(pnpm_home) = std::env::var_os("PNPM_HOME") {
+        dirs.push(PathBuf::from(pnpm_home).join("global/5/node_modules"));
+    }
+    if let Some(home) = dirs::home_dir() {
+        dirs.extend([
+            home.join(".npm-global/lib/node_modules"),
+            home.join(".local/share/pnpm/global/5/node_modules"),
+            home.join(".config/yarn/global/node_modules"),
+            home.join(".bun/install/global/node_modules"),
+        ]);
+    }
+    if let Some(app_data) = std::env::var_os("APPDATA") {
+        dirs.push(PathBuf::from(app_data).join("npm/node_modules"));
+    }
+    dirs.extend(
+        [
+            "/usr/local/lib/node_modules",
+            "/usr/lib/node_modules",
+            "/opt/homebrew/lib/node_modules",
+        ]
+        .map(PathBuf::from),
+    );
+    dirs
+}
+
+/// Locate a package through `npm root`.
+fn try_npm_root(package_name: &str, context_path: &Path) -> Result<PathBuf> {
     let output = Command::new("npm")
         .arg("root")
         .current_dir(context_path)
@@ -392,6 +853,7 @@ pub fn find_node_package_path(package_name: &str, context_path: &Path) -> Result
     }
     Ok(package_path)
 }
+
 /// Finds the source directory of a Rust crate installed by cargo.
 ///
 /// This searches the local cargo registry for crates and also handles
@@ -527,6 +989,138 @@ mod tests {
         }
     }
 
+    #[test]
+    fn finds_python_stdlib_modules_and_typeshed_stubs() {
+        use std::fs;
+        let temp = tempfile::tempdir().unwrap();
+        let stdlib = temp.path().join("base/lib/python3.12");
+        let typeshed = stdlib.join("site-packages/mypy/typeshed/stdlib");
+        for dir in [stdlib.join("json"), typeshed.join("json")] {
+            fs::create_dir_all(dir).unwrap();
+        }
+        fs::write(stdlib.join("os.py"), "").unwrap();
+        fs::write(stdlib.join("pathlib.py"), "").unwrap();
+        fs::write(stdlib.join("json/__init__.py"), "").unwrap();
+        fs::write(typeshed.join("builtins.pyi"), "").unwrap();
+        fs::write(typeshed.join("math.pyi"), "").unwrap();
+        fs::write(typeshed.join("json/__init__.pyi"), "").unwrap();
+
+        let project = temp.path().join("project");
+        let site_packages = project.join(".venv/lib/python3.12/site-packages");
+        fs::create_dir_all(&site_packages).unwrap();
+        fs::write(
+            project.join(".venv/pyvenv.cfg"),
+            format!("home = {}\n", temp.path().join("base/bin").display()),
+        )
+        .unwrap();
+
+        let context = Some(project.as_path());
+        assert_eq!(
+            find_python_stdlib_module("json", context),
+            Some(stdlib.join("json"))
+        );
+        assert_eq!(
+            find_python_stdlib_module("pathlib", context),
+            Some(stdlib.join("pathlib.py"))
+        );
+        assert_eq!(find_python_stdlib_module("math", context), None);
+        assert_eq!(
+            find_typeshed_stdlib_module("math", context),
+            Some(typeshed.join("math.pyi"))
+        );
+        assert_eq!(
+            find_python_stub_dir(&stdlib.join("json"), "json", context),
+            Some(typeshed.join("json"))
+        );
+
+        // Without typeshed next to the standard library, the context's
+        // environment supplies it
+        let other_stdlib = temp.path().join("other/lib/python3.11");
+        fs::create_dir_all(&other_stdlib).unwrap();
+        fs::write(other_stdlib.join("os.py"), "").unwrap();
+        fs::write(other_stdlib.join("csv.py"), "").unwrap();
+        let venv_typeshed = site_packages.join("mypy/typeshed/stdlib");
+        fs::create_dir_all(&venv_typeshed).unwrap();
+        fs::write(venv_typeshed.join("builtins.pyi"), "").unwrap();
+        fs::write(venv_typeshed.join("csv.pyi"), "").unwrap();
+        assert_eq!(
+            find_python_stub_dir(&other_stdlib.join("csv.py"), "csv", context),
+            Some(venv_typeshed.join("csv.pyi"))
+        );
+    }
+
+    #[test]
+    fn finds_node_packages_without_npm() {
+        use std::fs;
+        let temp = tempfile::tempdir().unwrap();
+        let root = temp.path().canonicalize().unwrap();
+        let write = |relative: &str, contents: &str| {
+            let path = root.join(relative);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, contents).unwrap();
+        };
+        write("node_modules/hoisted/package.json", "{}");
+        write(
+            "node_modules/.pnpm/@scope+lib@1.2.0/node_modules/@scope/lib/package.json",
+            "{}",
+        );
+        write(
+            "node_modules/.pnpm/@scope+lib@1.10.0_react@18.2.0/node_modules/@scope/lib/package.json",
+            "{}",
+        );
+        // pnpm v8+ spells peers in parentheses
+        write(
+            "node_modules/.pnpm/peerlib@1.9.0/node_modules/peerlib/package.json",
+            "{}",
+        );
+        write(
+            "node_modules/.pnpm/peerlib@1.10.0(react-dom@18.2.0)(react@18.2.0)/node_modules/peerlib/package.json",
+            "{}",
+        );
+        write(
+            "pnp/.yarn/unplugged/left-pad-npm-1.3.0/node_modules/left-pad/package.json",
+            "{}",
+        );
+        write(
+            "pnp/.pnp.cjs",
+            r#"#!/usr/bin/env node
+/* eslint-disable */
+const RAW_RUNTIME_STATE =
+'{\
+  "packageRegistryData": [\
+    [null, [[null, {"packageLocation": "./", "packageDependencies": [["left-pad", "npm:1.3.0"]]}]]],\
+    ["left-pad", [["npm:1.3.0", {"packageLocation": "./.yarn/unplugged/left-pad-npm-1.3.0/node_modules/left-pad/", "packageDependencies": []}]]]\
+  ]\
+}';
+"#,
+        );
+        let nested = root.join("packages/app/src");
+        fs::create_dir_all(&nested).unwrap();
+
+        assert_eq!(
+            find_package_in_node_modules(&root.join("node_modules"), "hoisted"),
+            Some(root.join("node_modules/hoisted"))
+        );
+        assert_eq!(
+            find_node_package_path("hoisted", &nested).unwrap(),
+            root.join("node_modules/hoisted")
+        );
+        assert_eq!(
+            find_node_package_path("@scope/lib", &nested).unwrap(),
+            root.join("node_modules/.pnpm/@scope+lib@1.10.0_react@18.2.0/node_modules/@scope/lib")
+        );
+        assert_eq!(
+            find_node_package_path("peerlib", &nested).unwrap(),
+            root.join(
+                "node_modules/.pnpm/peerlib@1.10.0(react-dom@18.2.0)(react@18.2.0)/node_modules/peerlib"
+            )
+        );
+        assert_eq!(
+            find_package_in_pnp_map(&root.join("pnp"), "left-pad"),
+            Some(root.join("pnp/./.yarn/unplugged/left-pad-npm-1.3.0/node_modules/left-pad/"))
+        );
+    }
+
     #[test]
     #[cfg(feature = "integration-tests")]
     fn finds_crate_in_registry() {
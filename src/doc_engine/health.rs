@@ -0,0 +1,645 @@
+//! Health tracking for built indexes.
+//!
+//! Every Rust symbol index and Python semantic index built by the engine is
+//! registered here together with its build duration, size and validation
+//! result. Searches against an index update its performance counters, and
+//! builds publish [`BuildProgress`] events that the server forwards to clients
+//! as MCP progress notifications. Events are stamped with the id of the
+//! request whose work triggered the build so each caller only sees its own.
+
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{HashMap, HashSet},
+    future::Future,
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex,
+    },
+    time::{Duration, Instant, SystemTime},
+};
+use tokio::sync::broadcast;
+
+use crate::doc_engine::types::SearchIndexData;
+use crate::index_core::{
+    BuildPhase, BuildProgress, HealthIssue, HealthLevel, HealthStatus, PerformanceMetrics,
+    ValidationResult, ValidationStats,
+};
+
+/// Maximum number of individual messages kept per validation category.
+const MAX_VALIDATION_MESSAGES: usize = 20;
+
+/// Capacity of the build progress broadcast channel.
+const PROGRESS_CHANNEL_CAPACITY: usize = 256;
+
+static NEXT_REQUEST_ID: AtomicU64 = AtomicU64::new(1);
+
+tokio::task_local! {
+    /// Request on whose behalf the current task is running
+    static BUILD_REQUEST: u64;
+}
+
+/// Allocate an id for a request that follows the progress of its builds.
+pub fn next_request_id() -> u64 {
+    NEXT_REQUEST_ID.fetch_add(1, Ordering::Relaxed)
+}
+
+/// Run `future` with the progress events of builds it triggers attributed to
+/// `request_id`.
+pub async fn attribute_builds<F: Future>(request_id: u64, future: F) -> F::Output {
+    BUILD_REQUEST.scope(request_id, future).await
+}
+
+/// Request the current task runs for, to carry into blocking workers.
+pub fn current_request() -> Option<u64> {
+    BUILD_REQUEST.try_with(|id| *id).ok()
+}
+
+/// Run blocking `work` on behalf of `request_id` (see [`current_request`]).
+pub fn in_request<R>(request_id: Option<u64>, work: impl FnOnce() -> R) -> R {
+    match request_id {
+        Some(id) => BUILD_REQUEST.sync_scope(id, work),
+        None => work(),
+    }
+}
+
+/// Kind of index being tracked
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IndexKind {
+    RustSymbols,
+    PythonSemantic,
+    NodeSemantic,
+}
+
+impl IndexKind {
+    /// Language the index serves ("rust", "python" or "node")
+    pub fn language(&self) -> &'static str {
+        match self {
+            IndexKind::RustSymbols => "rust",
+            IndexKind::PythonSemantic => "python",
+            IndexKind::NodeSemantic => "node",
+        }
+    }
+}
+
+/// Progress update for a single index build
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IndexBuildEvent {
+    pub index_id: String,
+    pub progress: BuildProgress,
+    /// Request whose work triggered the build, if any
+    #[serde(skip)]
+    pub request_id: Option<u64>,
+}
+
+/// Static facts about a freshly built index
+#[derive(Debug, Clone)]
+pub struct BuiltIndex {
+    pub kind: IndexKind,
+    pub name: String,
+    pub version: Option<String>,
+    /// On-disk source the index was built from (package root / cache file)
+    pub source: Option<PathBuf>,
+    pub item_count: usize,
+    pub size_bytes: u64,
+    /// When the underlying data was produced (defaults to build time)
+    pub data_timestamp: Option<SystemTime>,
+    pub validation: ValidationResult,
+}
+
+/// Health report for one index
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IndexHealthReport {
+    pub index_id: String,
+    pub kind: IndexKind,
+    pub name: String,
+    pub version: Option<String>,
+    pub source: Option<String>,
+    pub item_count: usize,
+    pub size_bytes: u64,
+    pub build_duration_ms: u64,
+    pub built_at: String,
+    pub data_age_seconds: u64,
+    pub stale: bool,
+    pub health: HealthStatus,
+    pub validation: ValidationResult,
+    pub performance: PerformanceMetrics,
+}
+
+/// Health of every index known to the engine
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IndexHealthResponse {
+    pub status: HealthLevel,
+    pub indexes: Vec<IndexHealthReport>,
+    /// Builds that have started but not yet finished
+    pub in_progress: Vec<IndexBuildEvent>,
+}
+
+#[derive(Debug)]
+struct IndexRecord {
+    info: BuiltIndex,
+    built_at: SystemTime,
+    build_duration: Duration,
+    searches: u64,
+    search_time: Duration,
+    cache_hits: u64,
+    cache_misses: u64,
+}
+
+/// Registry of built indexes plus a progress event channel
+#[derive(Debug)]
+pub struct IndexHealthTracker {
+    records: Mutex<HashMap<String, IndexRecord>>,
+    active: Mutex<HashMap<String, IndexBuildEvent>>,
+    events: broadcast::Sender<IndexBuildEvent>,
+}
+
+impl Default for IndexHealthTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl IndexHealthTracker {
+    pub fn new() -> Self {
+        let (events, _) = broadcast::channel(PROGRESS_CHANNEL_CAPACITY);
+        Self {
+            records: Mutex::new(HashMap::new()),
+            active: Mutex::new(HashMap::new()),
+            events,
+        }
+    }
+
+    /// Subscribe to build progress events from all index builds
+    pub fn subscribe(&self) -> broadcast::Receiver<IndexBuildEvent> {
+        self.events.subscribe()
+    }
+
+    /// Publish a progress update for `index_id`
+    pub fn report_progress(
+        &self,
+        index_id: &str,
+        phase: BuildPhase,
+        items_processed: usize,
+        total_items: usize,
+        started: Instant,
+    ) {
+        let elapsed = started.elapsed();
+        let estimated_remaining_seconds = (items_processed > 0 && total_items > items_processed)
+            .then(|| {
+                let per_item = elapsed.as_secs_f64() / items_processed as f64;
+                (per_item * (total_items - items_processed) as f64).ceil() as u64
+            });
+        let event = IndexBuildEvent {
+            index_id: index_id.to_string(),
+            progress: BuildProgress {
+                phase: phase.clone(),
+                items_processed,
+                total_items,
+                elapsed_seconds: elapsed.as_secs(),
+                estimated_remaining_seconds,
+            },
+            request_id: current_request(),
+        };
+
+        {
+            let mut active = self.active.lock().unwrap();
+            if phase.is_complete() || phase.is_failed() {
+                active.remove(index_id);
+            } else {
+                active.insert(index_id.to_string(), event.clone());
+            }
+        }
+        // No receivers is fine: progress is best-effort
+        let _ = self.events.send(event);
+    }
+
+    /// Register a completed build, replacing any previous record for the index
+    pub fn record_build(&self, index_id: &str, info: BuiltIndex, build_duration: Duration) {
+        let mut records = self.records.lock().unwrap();
+        let previous = records.remove(index_id);
+        let mut record = IndexRecord {
+            info,
+            built_at: SystemTime::now(),
+            build_duration,
+            searches: 0,
+            search_time: Duration::ZERO,
+            cache_hits: 0,
+            cache_misses: 1,
+        };
+        if let Some(previous) = previous {
+            record.searches = previous.searches;
+            record.search_time = previous.search_time;
+            record.cache_hits = previous.cache_hits;
+            record.cache_misses += previous.cache_misses;
+        }
+        records.insert(index_id.to_string(), record);
+    }
+
+    /// Count a lookup that was served by an already built index
+    pub fn record_cache_hit(&self, index_id: &str) {
+        if let Some(record) = self.records.lock().unwrap().get_mut(index_id) {
+            record.cache_hits += 1;
+        }
+    }
+
+    /// Count a search executed against an index
+    pub fn record_search(&self, index_id: &str, duration: Duration) {
+        if let Some(record) = self.records.lock().unwrap().get_mut(index_id) {
+            record.searches += 1;
+            record.search_time += duration;
+        }
+    }
+
+    /// Forget an index (e.g. after its cache entry was cleared)
+    pub fn remove_matching(&self, predicate: impl Fn(&str) -> bool) {
+        self.records
+            .lock()
+            .unwrap()
+            .retain(|index_id, _| !predicate(index_id));
+    }
+
+    /// Build health reports for all recorded indexes.
+    ///
+    /// `is_stale` decides staleness per index from its kind, source and data
+    /// age; it may touch the filesystem, so call this off the async runtime.
+    pub fn report(
+        &self,
+        kind: Option<IndexKind>,
+        is_stale: impl Fn(&BuiltIndex, SystemTime, Duration) -> Option<String>,
+    ) -> IndexHealthResponse {
+        let now = SystemTime::now();
+        let last_check = chrono::Utc::now().to_rfc3339();
+
+        let mut indexes: Vec<IndexHealthReport> = {
+            let records = self.records.lock().unwrap();
+            records
+                .iter()
+                .filter(|(_, record)| kind.is_none_or(|k| record.info.kind == k))
+                .map(|(index_id, record)| {
+                    let data_timestamp = record.info.data_timestamp.unwrap_or(record.built_at);
+                    let data_age = now.duration_since(data_timestamp).unwrap_or_default();
+                    let staleness = is_stale(&record.info, record.built_at, data_age);
+                    let health = health_for(index_id, record, staleness.as_deref(), &last_check);
+                    let average_search_time_ms = if record.searches > 0 {
+                        record.search_time.as_secs_f64() * 1000.0 / record.searches as f64
+                    } else {
+                        0.0
+                    };
+
+                    IndexHealthReport {
+                        index_id: index_id.clone(),
+                        kind: record.info.kind,
+                        name: record.info.name.clone(),
+                        version: record.info.version.clone(),
+                        source: record.info.source.as_ref().map(|p| p.display().to_string()),
+                        item_count: record.info.item_count,
+                        size_bytes: record.info.size_bytes,
+                        build_duration_ms: record.build_duration.as_millis() as u64,
+                        built_at: chrono::DateTime::<chrono::Utc>::from(record.built_at)
+                            .to_rfc3339(),
+                        data_age_seconds: data_age.as_secs(),
+                        stale: staleness.is_some(),
+                        health,
+                        validation: record.info.validation.clone(),
+                        performance: PerformanceMetrics {
+                            search_time_ms: record.search_time.as_millis() as u64,
+                            index_time_ms: record.build_duration.as_millis() as u64,
+                            total_searches: record.searches,
+                            cache_hits: record.cache_hits,
+                            cache_misses: record.cache_misses,
+                            average_search_time_ms,
+                        },
+                    }
+                })
+                .collect()
+        };
+        indexes.sort_by(|a, b| a.index_id.cmp(&b.index_id));
+
+        let mut in_progress: Vec<IndexBuildEvent> =
+            self.active.lock().unwrap().values().cloned().collect();
+        in_progress.sort_by(|a, b| a.index_id.cmp(&b.index_id));
+
+        let status = indexes
+            .iter()
+            .map(|report| report.health.status.clone())
+            .max_by_key(severity_rank)
+            .unwrap_or(HealthLevel::Unknown);
+
+        IndexHealthResponse {
+            status,
+            indexes,
+            in_progress,
+        }
+    }
+}
+
+fn health_for(
+    index_id: &str,
+    record: &IndexRecord,
+    staleness: Option<&str>,
+    timestamp: &str,
+) -> HealthStatus {
+    let issue = |severity: HealthLevel, message: String| HealthIssue {
+        severity,
+        message,
+        component: index_id.to_string(),
+        timestamp: timestamp.to_string(),
+    };
+
+    let mut issues = Vec::new();
+    let validation = &record.info.validation;
+    if record.info.item_count == 0 {
+        issues.push(issue(
+            HealthLevel::Critical,
+            "Index contains no items".to_string(),
+        ));
+    }
+    if !validation.is_valid {
+        let invalid = validation.stats.invalid_items;
+        let severity = if invalid * 2 > validation.stats.total_checked {
+            HealthLevel::Critical
+        } else {
+            HealthLevel::Warning
+        };
+        issues.push(issue(
+            severity,
+            format!(
+                "{invalid} of {} items failed validation",
+                validation.stats.total_checked
+            ),
+        ));
+    }
+    if let Some(reason) = staleness {
+        issues.push(issue(HealthLevel::Warning, reason.to_string()));
+    }
+
+    let status = issues
+        .iter()
+        .map(|i| i.severity.clone())
+        .max_by_key(severity_rank)
+        .unwrap_or(HealthLevel::Healthy);
+
+    HealthStatus {
+        status,
+        issues,
+        last_check: timestamp.to_string(),
+        uptime_seconds: SystemTime::now()
+            .duration_since(record.built_at)
+            .unwrap_or_default()
+            .as_secs(),
+    }
+}
+
+fn severity_rank(level: &HealthLevel) -> u8 {
+    match level {
+        HealthLevel::Healthy => 0,
+        HealthLevel::Unknown => 1,
+        HealthLevel::Warning => 2,
+        HealthLevel::Critical => 3,
+    }
+}
+
+/// Accumulates validation findings, capping the number of stored messages.
+#[derive(Default)]
+pub(crate) struct ValidationCollector {
+    errors: Vec<String>,
+    warnings: Vec<String>,
+    suppressed_errors: usize,
+    suppressed_warnings: usize,
+    pub(crate) stats: ValidationStatsBuilder,
+}
+
+#[derive(Default)]
+pub(crate) struct ValidationStatsBuilder {
+    pub(crate) total_checked: usize,
+    pub(crate) invalid_items: usize,
+    pub(crate) missing_docs: usize,
+    pub(crate) broken_links: usize,
+}
+
+impl ValidationCollector {
+    pub(crate) fn error(&mut self, message: String) {
+        if self.errors.len() < MAX_VALIDATION_MESSAGES {
+            self.errors.push(message);
+        } else {
+            self.suppressed_errors += 1;
+        }
+    }
+
+    pub(crate) fn warning(&mut self, message: String) {
+        if self.warnings.len() < MAX_VALIDATION_MESSAGES {
+            self.warnings.push(message);
+        } else {
+            self.suppressed_warnings += 1;
+        }
+    }
+
+    pub(crate) fn finish(mut self) -> ValidationResult {
+        if self.suppressed_errors > 0 {
+            self.errors
+                .push(format!("... and {} more errors", self.suppressed_errors));
+        }
+        if self.suppressed_warnings > 0 {
+            self.warnings.push(format!(
+                "... and {} more warnings",
+                self.suppressed_warnings
+            ));
+        }
+        let stats = ValidationStats {
+            total_checked: self.stats.total_checked,
+            valid_items: self
+                .stats
+                .total_checked
+                .saturating_sub(self.stats.invalid_items),
+            invalid_items: self.stats.invalid_items,
+            missing_docs: self.stats.missing_docs,
+            broken_links: self.stats.broken_links,
+        };
+        ValidationResult {
+            is_valid: self.errors.is_empty(),
+            errors: self.errors,
+            warnings: self.warnings,
+            stats,
+        }
+    }
+}
+
+/// Validate rustdoc search index data.
+///
+/// Items without a name or path and `parent_index` values pointing outside
+/// `paths` are errors; undocumented items are counted as missing docs.
+pub fn validate_search_index(data: &SearchIndexData) -> ValidationResult {
+    let mut collector = ValidationCollector::default();
+    let mut seen = HashSet::new();
+
+    for (idx, item) in data.items.iter().enumerate() {
+        collector.stats.total_checked += 1;
+        let mut valid = true;
+
+        if item.name.is_empty() {
+            collector.error(format!("Item #{idx} ({}) has no name", item.kind));
+            valid = false;
+        }
+        if item.path.is_empty() {
+            collector.error(format!("Item #{idx} '{}' has no path", item.name));
+            valid = false;
+        }
+        if let Some(parent) = item.parent_index {
+            if parent >= data.paths.len() {
+                collector.error(format!(
+                    "Item #{idx} '{}' has dangling parent_index {parent} ({} paths)",
+                    item.name,
+                    data.paths.len()
+                ));
+                collector.stats.broken_links += 1;
+                valid = false;
+            }
+        }
+        if item.description.trim().is_empty() {
+            collector.stats.missing_docs += 1;
+        }
+        if valid && !seen.insert((item.path.as_str(), item.name.as_str(), item.kind.as_str())) {
+            collector.warning(format!(
+                "Duplicate {} '{}::{}'",
+                item.kind, item.path, item.name
+            ));
+        }
+        if !valid {
+            collector.stats.invalid_items += 1;
+        }
+    }
+
+    if data.items.is_empty() {
+        collector.warning(format!(
+            "Search index for {}@{} has no items",
+            data.crate_name, data.version
+        ));
+    }
+
+    collector.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::doc_engine::types::SearchIndexItem;
+
+    fn item(name: &str, path: &str, parent_index: Option<usize>) -> SearchIndexItem {
+        SearchIndexItem {
+            name: name.to_string(),
+            kind: "function".to_string(),
+            path: path.to_string(),
+            description: String::new(),
+            parent_index,
+        }
+    }
+
+    #[test]
+    fn validation_flags_missing_paths_and_dangling_parents() {
+        let data = SearchIndexData {
+            crate_name: "demo".to_string(),
+            version: "0.1.0".to_string(),
+            items: vec![
+                item("ok", "demo", Some(0)),
+                item("orphan", "", None),
+                item("dangling", "demo", Some(5)),
+            ],
+            paths: vec!["demo::Thing".to_string()],
+        };
+
+        let result = validate_search_index(&data);
+        assert!(!result.is_valid);
+        assert_eq!(result.stats.total_checked, 3);
+        assert_eq!(result.stats.invalid_items, 2);
+        assert_eq!(result.stats.broken_links, 1);
+        assert_eq!(result.stats.missing_docs, 3);
+        assert!(result.errors.iter().any(|e| e.contains("has no path")));
+        assert!(result.errors.iter().any(|e| e.contains("dangling")));
+    }
+
+    #[test]
+    fn tracker_reports_builds_searches_and_progress() {
+        let tracker = IndexHealthTracker::new();
+        let mut events = tracker.subscribe();
+        let started = Instant::now();
+
+        tracker.report_progress("rust:demo@0.1.0", BuildPhase::Starting, 0, 2, started);
+        assert_eq!(tracker.report(None, |_, _, _| None).in_progress.len(), 1);
+
+        let data = SearchIndexData {
+            crate_name: "demo".to_string(),
+            version: "0.1.0".to_string(),
+            items: vec![item("ok", "demo", None)],
+            paths: Vec::new(),
+        };
+        tracker.record_build(
+            "rust:demo@0.1.0",
+            BuiltIndex {
+                kind: IndexKind::RustSymbols,
+                name: "demo".to_string(),
+                version: Some("0.1.0".to_string()),
+                source: None,
+                item_count: 1,
+                size_bytes: 10,
+                data_timestamp: None,
+                validation: validate_search_index(&data),
+            },
+            Duration::from_millis(5),
+        );
+        tracker.report_progress("rust:demo@0.1.0", BuildPhase::Complete, 2, 2, started);
+        tracker.record_search("rust:demo@0.1.0", Duration::from_millis(4));
+        tracker.record_cache_hit("rust:demo@0.1.0");
+
+        let healthy = tracker.report(None, |_, _, _| None);
+        assert_eq!(healthy.status, HealthLevel::Healthy);
+        assert!(healthy.in_progress.is_empty());
+        let report = &healthy.indexes[0];
+        assert_eq!(report.performance.total_searches, 1);
+        assert_eq!(report.performance.cache_hits, 1);
+        assert_eq!(report.performance.index_time_ms, 5);
+
+        let stale = tracker.report(Some(IndexKind::RustSymbols), |_, _, _| {
+            Some("older than TTL".to_string())
+        });
+        assert_eq!(stale.status, HealthLevel::Warning);
+        assert!(stale.indexes[0].stale);
+        assert!(tracker
+            .report(Some(IndexKind::PythonSemantic), |_, _, _| None)
+            .indexes
+            .is_empty());
+
+        assert_eq!(
+            events.try_recv().unwrap().progress.phase,
+            BuildPhase::Starting
+        );
+        assert!(events.try_recv().unwrap().progress.phase.is_complete());
+    }
+
+    #[tokio::test]
+    async fn progress_events_carry_the_triggering_request() {
+        let tracker = IndexHealthTracker::new();
+        let mut events = tracker.subscribe();
+        let started = Instant::now();
+        let request_id = next_request_id();
+
+        tracker.report_progress("python:a", BuildPhase::Starting, 0, 0, started);
+        attribute_builds(request_id, async {
+            tracker.report_progress("python:b", BuildPhase::Starting, 0, 0, started);
+            let inherited = current_request();
+            std::thread::scope(|scope| {
+                scope.spawn(|| {
+                    in_request(inherited, || {
+                        tracker.report_progress("python:c", BuildPhase::Starting, 0, 0, started)
+                    })
+                });
+            });
+        })
+        .await;
+
+        assert_eq!(events.try_recv().unwrap().request_id, None);
+        assert_eq!(events.try_recv().unwrap().request_id, Some(request_id));
+        assert_eq!(events.try_recv().unwrap().request_id, Some(request_id));
+    }
+}
@@ -0,0 +1,275 @@
+//! Lightweight readers for project dependency manifests.
+//!
+//! Only the fields needed to locate documentation are extracted; the parsers
+//! are line-based and tolerate unknown keys so new lockfile revisions keep
+//! working without a full TOML dependency.
+
+use anyhow::{anyhow, Context, Result};
+use std::path::{Path, PathBuf};
+
+use crate::doc_engine::types::BatchIndexTarget;
+
+/// A locked dependency (name and exact version).
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LockedPackage {
+    pub name: String,
+    pub version: String,
+}
+
+/// Find the nearest `Cargo.lock` at or above `start`.
+pub fn find_cargo_lock(start: &Path) -> Option<PathBuf> {
+    start
+        .ancestors()
+        .map(|dir| dir.join("Cargo.lock"))
+        .find(|candidate| candidate.is_file())
+}
+
+/// Read registry packages from a `Cargo.lock` file.
+pub fn read_cargo_lock(path: &Path) -> Result<Vec<LockedPackage>> {
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    Ok(parse_cargo_lock(&content))
+}
+
+/// Parse registry packages from `Cargo.lock` content.
+///
+/// Workspace members and path/git dependencies (no `registry+`/`sparse+`
+/// source) are skipped since docs.rs does not host them. The result is
+/// sorted and de-duplicated.
+pub fn parse_cargo_lock(content: &str) -> Vec<LockedPackage> {
+    #[derive(Default)]
+    struct Pending {
+        name: Option<String>,
+        version: Option<String>,
+        from_registry: bool,
+    }
+
+    fn flush(pending: &mut Pending, out: &mut Vec<LockedPackage>) {
+        let done = std::mem::take(pending);
+        if let (Some(name), Some(version), true) = (done.name, done.version, done.from_registry) {
+            out.push(LockedPackage { name, version });
+        }
+    }
+
+    let mut packages = Vec::new();
+    let mut pending = Pending::default();
+    let mut in_package = false;
+
+    for line in content.lines() {
+        let line = line.trim();
+        if line.starts_with('[') {
+            if in_package {
+                flush(&mut pending, &mut packages);
+            }
+            in_package = line == "[[package]]";
+            continue;
+        }
+        if !in_package {
+            continue;
+        }
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        let value = value.trim().trim_matches('"').to_string();
+        match key.trim() {
+            "name" => pending.name = Some(value),
+            "version" => pending.version = Some(value),
+            "source" => {
+                pending.from_registry =
+                    value.starts_with("registry+") || value.starts_with("sparse+")
+            }
+            _ => {}
+        }
+    }
+    if in_package {
+        flush(&mut pending, &mut packages);
+    }
+
+    packages.sort();
+    packages.dedup();
+    packages
+}
+
+/// Read package names from a pip `requirements.txt` file.
+pub fn read_requirements(path: &Path) -> Result<Vec<String>> {
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    Ok(parse_requirements(&content))
+}
+
+/// Parse package names from `requirements.txt` content.
+///
+/// Version specifiers, extras and environment markers are dropped. Options
+/// (`-r`, `-e`, `--index-url`, ...) and direct URL references are skipped.
+/// The result is sorted and de-duplicated.
+pub fn parse_requirements(content: &str) -> Vec<String> {
+    let mut names: Vec<String> = content
+        .lines()
+        .map(|line| line.split(" #").next().unwrap_or_default().trim())
+        .filter(|line| !line.is_empty() && !line.starts_with('#') && !line.starts_with('-'))
+        .filter(|line| !line.contains("://"))
+        .filter_map(|line| {
+            let end = line
+                .find(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.')))
+                .unwrap_or(line.len());
+            let name = &line[..end];
+            (!name.is_empty()).then(|| name.to_string())
+        })
+        .collect();
+    names.sort();
+    names.dedup();
+    names
+}
+
+/// Read dependency names from a `package.json` file.
+pub fn read_package_json(path: &Path) -> Result<Vec<String>> {
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    parse_package_json(&content).with_context(|| format!("Failed to parse {}", path.display()))
+}
+
+/// Parse dependency names from `package.json` content.
+///
+/// Names are collected from `dependencies`, `devDependencies`,
+/// `peerDependencies` and `optionalDependencies`, sorted and de-duplicated.
+pub fn parse_package_json(content: &str) -> Result<Vec<String>> {
+    const SECTIONS: &[&str] = &[
+        "dependencies",
+        "devDependencies",
+        "peerDependencies",
+        "optionalDependencies",
+    ];
+
+    let manifest: serde_json::Value = serde_json::from_str(content)?;
+    let mut names: Vec<String> = SECTIONS
+        .iter()
+        .filter_map(|section| manifest.get(section)?.as_object())
+        .flat_map(|deps| deps.keys().cloned())
+        .collect();
+    names.sort();
+    names.dedup();
+    Ok(names)
+}
+
+/// Read batch index targets from a dependency manifest.
+///
+/// `Cargo.lock` yields Rust crates at their locked versions,
+/// `requirements*.txt` yields Python packages and `package.json` yields Node
+/// packages.
+pub fn batch_targets(manifest: &Path) -> Result<Vec<BatchIndexTarget>> {
+    let file_name = manifest
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or_default();
+
+    let target = |language: &str, name: String, version: Option<String>| BatchIndexTarget {
+        language: language.to_string(),
+        name,
+        version,
+    };
+
+    if file_name == "Cargo.lock" {
+        Ok(read_cargo_lock(manifest)?
+            .into_iter()
+            .map(|pkg| target("rust", pkg.name, Some(pkg.version)))
+            .collect())
+    } else if file_name == "package.json" {
+        Ok(read_package_json(manifest)?
+            .into_iter()
+            .map(|name| target("node", name, None))
+            .collect())
+    } else if file_name.starts_with("requirements") && file_name.ends_with(".txt") {
+        Ok(read_requirements(manifest)?
+            .into_iter()
+            .map(|name| target("python", name, None))
+            .collect())
+    } else {
+        Err(anyhow!(
+            "Unsupported manifest '{}': expected Cargo.lock, requirements.txt or package.json",
+            manifest.display()
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_registry_packages_only() {
+        let lock = r#"
+version = 4
+
+[[package]]
+name = "my-app"
+version = "0.1.0"
+dependencies = [
+ "serde",
+]
+
+[[package]]
+name = "serde"
+version = "1.0.210"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+checksum = "abc"
+
+[[package]]
+name = "local-git"
+version = "0.2.0"
+source = "git+https://example.com/repo#deadbeef"
+
+[[package]]
+name = "anyhow"
+version = "1.0.98"
+source = "sparse+https://index.crates.io/"
+"#;
+        let packages = parse_cargo_lock(lock);
+        assert_eq!(
+            packages,
+            vec![
+                LockedPackage {
+                    name: "anyhow".into(),
+                    version: "1.0.98".into()
+                },
+                LockedPackage {
+                    name: "serde".into(),
+                    version: "1.0.210".into()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_requirement_names() {
+        let requirements = r#"
+# pinned deps
+requests==2.32.3
+Flask[async]>=3.0 ; python_version >= "3.9"
+numpy  # latest
+-r dev-requirements.txt
+--index-url https://pypi.org/simple
+-e ./local-package
+git+https://github.com/org/repo.git
+requests~=2.31
+"#;
+        assert_eq!(
+            parse_requirements(requirements),
+            vec!["Flask", "numpy", "requests"]
+        );
+    }
+
+    #[test]
+    fn parses_package_json_dependency_sections() {
+        let manifest = r#"{
+            "name": "web",
+            "dependencies": { "react": "^18.0.0", "lodash": "4.17.21" },
+            "devDependencies": { "@types/react": "^18.0.0", "lodash": "*" },
+            "scripts": { "build": "tsc" }
+        }"#;
+        assert_eq!(
+            parse_package_json(manifest).unwrap(),
+            vec!["@types/react", "lodash", "react"]
+        );
+        assert!(parse_package_json("not json").is_err());
+    }
+}
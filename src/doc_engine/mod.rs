@@ -5,19 +5,28 @@
 //! It also supports fetching source code from local environments for Python and Node.js.
 
 use crate::{
+    doc_engine::node_semantic::NodeSemanticIndex,
     doc_engine::python_semantic::PythonSemanticIndex,
-    index_core::{IndexCore, SymbolIndex, TraitImplIndex},
+    doc_engine::rust_semantic::RustSemanticIndex,
+    index_core::{
+        BuildPhase, CompletionCandidate, CompletionIndex, IndexCore, SymbolIndex, TraitImplIndex,
+    },
 };
 use anyhow::{Context, Result};
 use lru::LruCache;
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::{
+    collections::HashMap,
     num::NonZeroUsize,
     path::{Path, PathBuf},
     sync::Arc,
+    time::{Duration, Instant},
+};
+use tokio::{
+    fs,
+    sync::{broadcast, Mutex},
 };
-use tokio::{fs, sync::Mutex};
 use tracing::{info, warn};
 
 use crate::doc_engine::processors::traits::{ImplementationContext, LanguageProcessor};
@@ -25,11 +34,26 @@ use crate::doc_engine::processors::traits::{ImplementationContext, LanguageProce
 pub mod cache;
 pub mod fetcher;
 pub mod finder;
+pub mod health;
 pub mod local;
+pub mod lockfile;
+pub mod node_analyzer;
+pub mod node_metadata;
+pub mod node_resolve;
+pub mod node_semantic;
+pub mod node_typings;
 pub mod processors;
 pub mod python_analyzer;
+pub mod python_docstring;
+pub mod python_exports;
+pub mod python_inheritance;
+pub mod python_metadata;
 pub mod python_semantic;
+pub mod rust_semantic;
+pub mod rustdoc_index;
 pub mod scraper;
+#[cfg(test)]
+mod test_support;
 pub mod types;
 
 use crate::doc_engine::types::*;
@@ -48,6 +72,10 @@ fn convert_source_location(
     })
 }
 
+/// Minimum time between two staleness checks of the same cached semantic
+/// index.
+const STALENESS_CHECK_INTERVAL: Duration = Duration::from_secs(5);
+
 /// Configuration options for the documentation engine.
 #[derive(Debug, Clone, Default)]
 pub struct DocEngineOptions {
@@ -64,8 +92,13 @@ pub struct DocEngine {
     memory_cache: Arc<Mutex<LruCache<String, Arc<CrateDocumentation>>>>,
     version_cache: Arc<Mutex<LruCache<String, String>>>,
     python_semantic_cache: Arc<Mutex<LruCache<String, Arc<PythonSemanticIndex>>>>,
+    rust_semantic_cache: Arc<Mutex<LruCache<String, Arc<RustSemanticIndex>>>>,
+    node_semantic_cache: Arc<Mutex<LruCache<String, Arc<NodeSemanticIndex>>>>,
+    health: Arc<health::IndexHealthTracker>,
+    /// When each cached semantic index was last checked for staleness
+    stale_checks: Arc<std::sync::Mutex<HashMap<String, Instant>>>,
     pub python_processor: Arc<processors::python::PythonProcessor>,
-    node_processor: Arc<processors::node::NodeProcessor>,
+    pub node_processor: Arc<processors::node::NodeProcessor>,
     rust_processor: Arc<processors::rust::RustProcessor>,
     working_dir: Option<PathBuf>,
 }
@@ -93,6 +126,12 @@ impl DocEngine {
         let version_cache = Arc::new(Mutex::new(LruCache::new(NonZeroUsize::new(1000).unwrap())));
         let python_semantic_cache =
             Arc::new(Mutex::new(LruCache::new(NonZeroUsize::new(32).unwrap())));
+        let rust_semantic_cache =
+            Arc::new(Mutex::new(LruCache::new(NonZeroUsize::new(16).unwrap())));
+        let node_semantic_cache =
+            Arc::new(Mutex::new(LruCache::new(NonZeroUsize::new(32).unwrap())));
+        let health = Arc::new(health::IndexHealthTracker::new());
+        let stale_checks = Arc::new(std::sync::Mutex::new(HashMap::new()));
         let python_processor = Arc::new(processors::python::PythonProcessor);
         let node_processor = Arc::new(processors::node::NodeProcessor);
         let rust_processor = Arc::new(processors::rust::RustProcessor);
@@ -107,6 +146,10 @@ impl DocEngine {
             memory_cache,
             version_cache,
             python_semantic_cache,
+            rust_semantic_cache,
+            node_semantic_cache,
+            health,
+            stale_checks,
             python_processor,
             node_processor,
             rust_processor,
@@ -312,10 +355,377 @@ impl DocEngine {
         version: Option<&str>,
     ) -> Result<Vec<SymbolSearchResult>> {
         let docs = self.ensure_crate_docs(crate_name, version).await?;
-        docs.search_symbols(query, kinds, limit)
+        let started = Instant::now();
+        let results = docs.search_symbols(query, kinds, limit)?;
+        self.health.record_search(
+            &rust_index_id(&docs.crate_name, &docs.version),
+            started.elapsed(),
+        );
+        Ok(results)
+    }
+
+    /// Search symbols across many crates and merge the hits into one ranking.
+    ///
+    /// With `scope` unset, the project's Cargo.lock (found from `context_path`
+    /// upwards) is used when present, otherwise every cached crate index. Crates
+    /// without a cached index are skipped unless `fetch_missing` is set, in which
+    /// case their indexes are fetched from docs.rs with bounded concurrency.
+    ///
+    /// Each crate is scored by its own index, so raw scores depend on that
+    /// crate's size and term statistics. Scores are divided by the crate's
+    /// best score before merging: every crate's strongest hit competes at
+    /// 1.0, with shorter paths first on ties.
+    pub async fn search_symbols_global(
+        &self,
+        query: &str,
+        kinds: Option<&[String]>,
+        limit: u32,
+        scope: Option<SymbolSearchScope>,
+        context_path: Option<&str>,
+        fetch_missing: bool,
+    ) -> Result<GlobalSymbolSearchResponse> {
+        use futures::stream::{self, StreamExt};
+
+        const MAX_CONCURRENT_FETCHES: usize = 8;
+
+        let mut diagnostics = Vec::new();
+        let lockfile = lockfile::find_cargo_lock(&self.resolve_context_dir(context_path));
+        let scope = scope.unwrap_or(if lockfile.is_some() {
+            SymbolSearchScope::Lockfile
+        } else {
+            SymbolSearchScope::Cache
+        });
+
+        let crates: Vec<(String, String)> = match scope {
+            SymbolSearchScope::Lockfile => {
+                let path = lockfile.ok_or_else(|| {
+                    anyhow::anyhow!("No Cargo.lock found at or above the context path")
+                })?;
+                diagnostics.push(format!("Using {}", path.display()));
+                lockfile::read_cargo_lock(&path)?
+                    .into_iter()
+                    .map(|pkg| (pkg.name, pkg.version))
+                    .collect()
+            }
+            SymbolSearchScope::Cache => {
+                // Keep only the newest cached version of each crate
+                let mut latest: std::collections::BTreeMap<String, String> =
+                    std::collections::BTreeMap::new();
+                for (name, version) in self.cache.list_crate_indexes()? {
+                    let newer = match latest.get(&name) {
+                        Some(existing) => match (
+                            semver::Version::parse(&version),
+                            semver::Version::parse(existing),
+                        ) {
+                            (Ok(candidate), Ok(current)) => candidate > current,
+                            _ => version > *existing,
+                        },
+                        None => true,
+                    };
+                    if newer {
+                        latest.insert(name, version);
+                    }
+                }
+                latest.into_iter().collect()
+            }
+        };
+
+        let loaded: Vec<_> = stream::iter(crates)
+            .map(|(name, version)| async move {
+                let docs = match self.load_cached_crate_docs(&name, &version).await {
+                    Ok(Some(docs)) => Ok(Some(docs)),
+                    Ok(None) if fetch_missing => self
+                        .ensure_crate_docs(&name, Some(&version))
+                        .await
+                        .map(Some),
+                    other => other,
+                };
+                (name, version, docs)
+            })
+            .buffer_unordered(MAX_CONCURRENT_FETCHES)
+            .collect()
+            .await;
+
+        let mut results = Vec::new();
+        let mut crates_searched = 0;
+        let mut crates_skipped = Vec::new();
+        for (name, version, docs) in loaded {
+            match docs {
+                Ok(Some(docs)) => {
+                    crates_searched += 1;
+                    let started = Instant::now();
+                    let symbols = docs.search_symbols(query, kinds, limit)?;
+                    self.health
+                        .record_search(&rust_index_id(&name, &version), started.elapsed());
+                    let top_score = symbols
+                        .iter()
+                        .map(|symbol| symbol.score)
+                        .fold(0.0_f32, f32::max);
+                    for mut symbol in symbols {
+                        if top_score > 0.0 {
+                            symbol.score /= top_score;
+                        }
+                        results.push(CrateSymbolSearchResult {
+                            crate_name: name.clone(),
+                            crate_version: version.clone(),
+                            symbol,
+                        });
+                    }
+                }
+                Ok(None) => crates_skipped.push(format!("{name}@{version}")),
+                Err(e) => {
+                    diagnostics.push(format!("{name}@{version}: {e}"));
+                    crates_skipped.push(format!("{name}@{version}"));
+                }
+            }
+        }
+
+        if !crates_skipped.is_empty() && !fetch_missing {
+            diagnostics.push(format!(
+                "{} crate(s) have no cached index; enable fetch_missing to index them from docs.rs",
+                crates_skipped.len()
+            ));
+        }
+        crates_skipped.sort();
+
+        results.sort_by(|a, b| {
+            b.symbol
+                .score
+                .partial_cmp(&a.symbol.score)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| a.symbol.path.len().cmp(&b.symbol.path.len()))
+                .then_with(|| a.crate_name.cmp(&b.crate_name))
+                .then_with(|| a.symbol.path.cmp(&b.symbol.path))
+        });
+        results.truncate(limit as usize);
+
+        Ok(GlobalSymbolSearchResponse {
+            scope,
+            results,
+            crates_searched,
+            crates_skipped,
+            diagnostics,
+        })
+    }
+
+    /// Pre-index every dependency listed in a manifest.
+    ///
+    /// See [`lockfile::batch_targets`] for the supported manifests. Python and
+    /// Node packages are resolved relative to `context_path`, defaulting to the
+    /// directory containing the manifest.
+    pub async fn index_manifest(
+        &self,
+        manifest: &Path,
+        concurrency: usize,
+        context_path: Option<&str>,
+    ) -> Result<BatchIndexReport> {
+        let targets = lockfile::batch_targets(manifest)?;
+        let manifest_dir = manifest
+            .parent()
+            .filter(|dir| !dir.as_os_str().is_empty())
+            .map(|dir| dir.to_string_lossy().into_owned());
+        let context_path = context_path.or(manifest_dir.as_deref());
+
+        let mut report = self.index_batch(targets, concurrency, context_path).await;
+        report.source = Some(manifest.display().to_string());
+        Ok(report)
+    }
+
+    /// Build indexes for many crates and packages with bounded concurrency.
+    ///
+    /// Rust crates get their symbol index fetched (or loaded from cache),
+    /// Python packages get a semantic index and Node packages are resolved on
+    /// disk. Failures are reported per entry and never abort the batch;
+    /// entries are returned in input order.
+    pub async fn index_batch(
+        &self,
+        targets: Vec<BatchIndexTarget>,
+        concurrency: usize,
+        context_path: Option<&str>,
+    ) -> BatchIndexReport {
+        use futures::stream::{self, StreamExt};
+
+        let started = Instant::now();
+        let entries: Vec<BatchIndexEntryResult> = stream::iter(targets)
+            .map(|target| self.index_batch_target(target, context_path))
+            .buffered(concurrency.max(1))
+            .collect()
+            .await;
+
+        let succeeded = entries.iter().filter(|entry| entry.success).count();
+        BatchIndexReport {
+            source: None,
+            total: entries.len(),
+            succeeded,
+            failed: entries.len() - succeeded,
+            duration_ms: started.elapsed().as_millis() as u64,
+            entries,
+        }
+    }
+
+    async fn index_batch_target(
+        &self,
+        target: BatchIndexTarget,
+        context_path: Option<&str>,
+    ) -> BatchIndexEntryResult {
+        let started = Instant::now();
+        let mut entry = BatchIndexEntryResult {
+            language: target.language.clone(),
+            name: target.name.clone(),
+            version: target.version.clone(),
+            success: false,
+            item_count: None,
+            location: None,
+            duration_ms: 0,
+            error: None,
+        };
+
+        let outcome: Result<()> = match target.language.as_str() {
+            "rust" => self
+                .ensure_crate_docs(&target.name, target.version.as_deref())
+                .await
+                .map(|docs| {
+                    entry.version = Some(docs.version.clone());
+                    entry.item_count = Some(docs.search_index_data.items.len());
+                }),
+            "python" => self
+                .index_python_distribution(&target.name, context_path)
+                .await
+                .map(|(item_count, location)| {
+                    entry.item_count = Some(item_count);
+                    entry.location = location;
+                }),
+            "node" => self
+                .node_semantic_index(&target.name, context_path)
+                .await
+                .map(|index| {
+                    entry.item_count = Some(index.len());
+                    entry.location = Some(index.package_root().display().to_string());
+                }),
+            other => Err(anyhow::anyhow!("Unsupported language '{other}'")),
+        };
+
+        entry.duration_ms = started.elapsed().as_millis() as u64;
+        match outcome {
+            Ok(()) => entry.success = true,
+            Err(e) => {
+                warn!(
+                    "Batch indexing {} package '{}' failed: {e}",
+                    target.language, target.name
+                );
+                entry.error = Some(e.to_string());
+            }
+        }
+        entry
     }
 
-    /// Perform semantic search within a local package (currently Python support).
+    /// Build the semantic indexes of every package a Python distribution
+    /// installs (`Pillow` installs `PIL`, `PyYAML` installs `yaml`). Returns
+    /// the total item count and the location of the first package.
+    ///
+    /// Packages that cannot be indexed (a stray `tests` package, a module
+    /// shipped only as a compiled extension) are logged and skipped; the
+    /// distribution fails only when none of them index.
+    async fn index_python_distribution(
+        &self,
+        distribution: &str,
+        context_path: Option<&str>,
+    ) -> Result<(usize, Option<String>)> {
+        let context_dir = self.resolve_context_dir(context_path);
+        let name = distribution.to_string();
+        let modules = tokio::task::spawn_blocking(move || {
+            let installed = finder::find_python_site_packages(Some(&context_dir))
+                .ok()
+                .and_then(|site| python_metadata::find_dist_info(&site, &name))
+                .map(|dist_info| python_metadata::import_names(&dist_info))
+                .unwrap_or_default();
+            let public: Vec<String> = installed
+                .iter()
+                .filter(|module| !module.starts_with('_'))
+                .cloned()
+                .collect();
+            match (public.is_empty(), installed.is_empty()) {
+                (false, _) => public,
+                (true, false) => installed,
+                // Not installed as a distribution: guess the import name
+                // (`python-dateutil` -> `python_dateutil`)
+                (true, true) => vec![name.to_lowercase().replace(['-', '.'], "_")],
+            }
+        })
+        .await
+        .map_err(|e| anyhow::anyhow!("Python distribution lookup failed: {e}"))?;
+
+        let mut item_count = 0;
+        let mut location = None;
+        let mut failures = Vec::new();
+        for module in modules {
+            match self.python_semantic_index(&module, context_path).await {
+                Ok(index) => {
+                    item_count += index.len();
+                    location.get_or_insert_with(|| index.package_root().display().to_string());
+                }
+                Err(e) => {
+                    warn!("Skipping Python package '{module}' of {distribution}: {e}");
+                    failures.push(format!("{module}: {e}"));
+                }
+            }
+        }
+        if location.is_none() {
+            return Err(anyhow::anyhow!(
+                "None of the packages installed by '{distribution}' could be indexed ({})",
+                failures.join("; ")
+            ));
+        }
+        Ok((item_count, location))
+    }
+
+    /// Complete a partially typed item path.
+    ///
+    /// Rust prefixes use `::` and are completed from the crate's rustdoc search
+    /// index (the crate defaults to the first path segment). Python prefixes use
+    /// `.` and are completed from the package's semantic index.
+    pub async fn complete_path(
+        &self,
+        language: &str,
+        prefix: &str,
+        package_name: Option<&str>,
+        version: Option<&str>,
+        limit: usize,
+        context_path: Option<&str>,
+    ) -> Result<Vec<CompletionCandidate>> {
+        let prefix = prefix.trim();
+        if limit == 0 {
+            return Ok(Vec::new());
+        }
+
+        match language {
+            "rust" => {
+                let crate_name = package_name
+                    .filter(|p| !p.is_empty())
+                    .or_else(|| prefix.split("::").next())
+                    .filter(|p| !p.is_empty())
+                    .ok_or_else(|| anyhow::anyhow!("Cannot infer crate name from '{prefix}'"))?;
+                let docs = self.ensure_crate_docs(crate_name, version).await?;
+                Ok(docs.complete_path(prefix, limit))
+            }
+            "python" => {
+                let package = package_name
+                    .filter(|p| !p.is_empty())
+                    .or_else(|| prefix.split('.').next())
+                    .filter(|p| !p.is_empty())
+                    .ok_or_else(|| anyhow::anyhow!("Cannot infer package name from '{prefix}'"))?;
+                let index = self.python_semantic_index(package, context_path).await?;
+                Ok(index.completion_index().complete(prefix, limit))
+            }
+            _ => Err(anyhow::anyhow!(
+                "Path completion is not implemented for language '{}'",
+                language
+            )),
+        }
+    }
+
+    /// Perform natural-language search within a Python package, Rust crate or
+    /// Node.js package.
     pub async fn semantic_search(
         &self,
         language: &str,
@@ -329,6 +739,14 @@ impl DocEngine {
                 self.semantic_search_python(package_name, query, limit, context_path)
                     .await
             }
+            "rust" => {
+                self.semantic_search_rust(package_name, query, limit, context_path)
+                    .await
+            }
+            "node" => {
+                self.semantic_search_node(package_name, query, limit, context_path)
+                    .await
+            }
             _ => Err(anyhow::anyhow!(
                 "Semantic search is not implemented for language '{}'",
                 language
@@ -347,6 +765,278 @@ impl DocEngine {
             return Ok(Vec::new());
         }
 
+        let index = self
+            .python_semantic_index(package_name, context_path)
+            .await?;
+        let started = Instant::now();
+        let results = index.search(query, limit);
+        self.health.record_search(
+            &python_index_id(package_name, index.package_root()),
+            started.elapsed(),
+        );
+        Ok(results)
+    }
+
+    /// Search several Python packages at once with a single merged ranking.
+    ///
+    /// `packages` holds import names; `*` stands for every package installed
+    /// in the environment of `context_path`. Packages that cannot be found or
+    /// indexed are skipped with a warning; the search fails only when none of
+    /// them could be indexed.
+    pub async fn semantic_search_python_packages(
+        &self,
+        packages: &[String],
+        query: &str,
+        limit: usize,
+        context_path: Option<&str>,
+    ) -> Result<Vec<SemanticSearchResult>> {
+        use futures::stream::{self, StreamExt};
+
+        const INDEX_CONCURRENCY: usize = 4;
+
+        if query.trim().is_empty() || limit == 0 {
+            return Ok(Vec::new());
+        }
+
+        let mut names: Vec<String> = Vec::new();
+        for package in packages.iter().map(|p| p.trim()).filter(|p| !p.is_empty()) {
+            if package == "*" {
+                let context_dir = self.resolve_context_dir(context_path);
+                let installed = tokio::task::spawn_blocking(move || {
+                    finder::find_python_site_packages(Some(&context_dir))
+                        .and_then(|site| finder::list_python_site_packages(&site))
+                })
+                .await
+                .map_err(|e| anyhow::anyhow!("Python environment scan failed: {e}"))??;
+                names.extend(installed);
+            } else {
+                names.push(package.to_string());
+            }
+        }
+        names.sort();
+        names.dedup();
+        if names.is_empty() {
+            return Err(anyhow::anyhow!("No Python packages to search"));
+        }
+
+        let indexed: Vec<(String, Result<Arc<PythonSemanticIndex>>)> = stream::iter(names)
+            .map(|name| async move {
+                let index = self.python_semantic_index(&name, context_path).await;
+                (name, index)
+            })
+            .buffered(INDEX_CONCURRENCY)
+            .collect()
+            .await;
+
+        let mut indexes = Vec::with_capacity(indexed.len());
+        let mut failures = Vec::new();
+        for (name, index) in indexed {
+            match index {
+                Ok(index) => indexes.push(index),
+                Err(e) => {
+                    warn!("Skipping Python package '{name}' in semantic search: {e}");
+                    failures.push(format!("{name}: {e}"));
+                }
+            }
+        }
+        if indexes.is_empty() {
+            return Err(anyhow::anyhow!(
+                "None of the requested Python packages could be indexed ({})",
+                failures.join("; ")
+            ));
+        }
+
+        let started = Instant::now();
+        let refs: Vec<&PythonSemanticIndex> = indexes.iter().map(Arc::as_ref).collect();
+        let results = PythonSemanticIndex::search_many(&refs, query, limit);
+        let elapsed = started.elapsed();
+        for index in &indexes {
+            self.health.record_search(
+                &python_index_id(index.package_name(), index.package_root()),
+                elapsed,
+            );
+        }
+        Ok(results)
+    }
+
+    async fn semantic_search_rust(
+        &self,
+        crate_name: &str,
+        query: &str,
+        limit: usize,
+        context_path: Option<&str>,
+    ) -> Result<Vec<SemanticSearchResult>> {
+        if query.trim().is_empty() || limit == 0 {
+            return Ok(Vec::new());
+        }
+
+        let index = self.rust_semantic_index(crate_name, context_path).await?;
+        let started = Instant::now();
+        let results = index.search(query, limit);
+        self.health.record_search(
+            &rust_index_id(crate_name, index.version()),
+            started.elapsed(),
+        );
+        Ok(results)
+    }
+
+    async fn semantic_search_node(
+        &self,
+        package_name: &str,
+        query: &str,
+        limit: usize,
+        context_path: Option<&str>,
+    ) -> Result<Vec<SemanticSearchResult>> {
+        if query.trim().is_empty() || limit == 0 {
+            return Ok(Vec::new());
+        }
+
+        let index = self.node_semantic_index(package_name, context_path).await?;
+        let started = Instant::now();
+        let results = index.search(query, limit);
+        self.health.record_search(
+            &node_index_id(package_name, index.package_root()),
+            started.elapsed(),
+        );
+        Ok(results)
+    }
+
+    /// Resolve a Node.js package and return its semantic index, rebuilding it
+    /// when the package's files changed since it was cached.
+    async fn node_semantic_index(
+        &self,
+        package_name: &str,
+        context_path: Option<&str>,
+    ) -> Result<Arc<NodeSemanticIndex>> {
+        let context_dir = self.resolve_context_dir(context_path);
+        let name = package_name.to_string();
+        let package_root = tokio::task::spawn_blocking(move || {
+            finder::find_node_package_path(&name, &context_dir)
+        })
+        .await
+        .map_err(|e| anyhow::anyhow!("Node package lookup failed: {e}"))??;
+
+        let cache_key = format!("{}::{}", package_name, package_root.to_string_lossy());
+        let index_id = node_index_id(package_name, &package_root);
+        let cached = self
+            .node_semantic_cache
+            .lock()
+            .await
+            .get(&cache_key)
+            .cloned();
+        if let Some(existing) = cached {
+            if self
+                .is_index_current(&index_id, &existing, NodeSemanticIndex::is_stale)
+                .await
+            {
+                self.health.record_cache_hit(&index_id);
+                return Ok(existing);
+            }
+        }
+
+        let name = package_name.to_string();
+        let health = Arc::clone(&self.health);
+        let request_id = health::current_request();
+        let build_id = index_id.clone();
+        let index = tokio::task::spawn_blocking(move || {
+            health::in_request(request_id, || {
+                build_node_semantic_index(&health, &build_id, &name, &package_root)
+            })
+        })
+        .await
+        .map_err(|e| anyhow::anyhow!("Node semantic index worker failed: {e}"))??;
+        let index = Arc::new(index);
+        self.node_semantic_cache
+            .lock()
+            .await
+            .put(cache_key, Arc::clone(&index));
+        self.mark_index_checked(&index_id);
+        Ok(index)
+    }
+
+    /// Build (or reuse) the semantic index for a Rust crate.
+    ///
+    /// A crate whose `Cargo.toml` lives in the context directory (or up to two
+    /// levels below it) is indexed from its sources on every call so edits are
+    /// picked up. Other crates are indexed from their rustdoc search index,
+    /// enriched with registry sources when present; if docs.rs is unreachable
+    /// the newest version in the local cargo registry is indexed instead.
+    async fn rust_semantic_index(
+        &self,
+        crate_name: &str,
+        context_path: Option<&str>,
+    ) -> Result<Arc<RustSemanticIndex>> {
+        let context_dir = self.resolve_context_dir(context_path);
+        let name = crate_name.to_string();
+        let local = tokio::task::spawn_blocking(move || find_local_rust_crate(&context_dir, &name))
+            .await
+            .map_err(|e| anyhow::anyhow!("Local crate lookup failed: {e}"))?;
+        if let Some((root, version)) = local {
+            let name = crate_name.to_string();
+            let index = tokio::task::spawn_blocking(move || {
+                RustSemanticIndex::from_sources(&name, &version, &root)
+            })
+            .await
+            .map_err(|e| anyhow::anyhow!("Rust semantic index worker failed: {e}"))??;
+            return Ok(Arc::new(index));
+        }
+
+        let docs = self.ensure_crate_docs(crate_name, None).await;
+        let version = match &docs {
+            Ok(docs) => docs.version.clone(),
+            Err(e) => {
+                let name = crate_name.to_string();
+                tokio::task::spawn_blocking(move || finder::find_latest_rust_crate_version(&name))
+                    .await
+                    .map_err(|e| anyhow::anyhow!("Local crate lookup failed: {e}"))?
+                    .ok()
+                    .flatten()
+                    .ok_or_else(|| {
+                        anyhow::anyhow!(
+                            "{e:#}; crate '{crate_name}' is also not in the local cargo registry"
+                        )
+                    })?
+            }
+        };
+
+        let cache_key = format!("{crate_name}@{version}");
+        if let Some(existing) = self.rust_semantic_cache.lock().await.get(&cache_key) {
+            return Ok(Arc::clone(existing));
+        }
+
+        let name = crate_name.to_string();
+        let index = tokio::task::spawn_blocking(move || {
+            let sources = finder::find_rust_crate_path(&name, &version).ok();
+            match docs {
+                Ok(docs) => Ok(RustSemanticIndex::from_search_index(
+                    &docs.search_index_data,
+                    sources.as_deref(),
+                )),
+                Err(_) => {
+                    let sources = sources.ok_or_else(|| {
+                        anyhow::anyhow!("Sources for {name}@{version} disappeared")
+                    })?;
+                    RustSemanticIndex::from_sources(&name, &version, &sources)
+                }
+            }
+        })
+        .await
+        .map_err(|e| anyhow::anyhow!("Rust semantic index worker failed: {e}"))??;
+
+        let index = Arc::new(index);
+        self.rust_semantic_cache
+            .lock()
+            .await
+            .put(cache_key, Arc::clone(&index));
+        Ok(index)
+    }
+
+    /// Resolve a Python package and return its (cached) semantic index.
+    async fn python_semantic_index(
+        &self,
+        package_name: &str,
+        context_path: Option<&str>,
+    ) -> Result<Arc<PythonSemanticIndex>> {
         let context_dir = context_path
             .map(|p| self.resolve_context_dir(Some(p)))
             .unwrap_or_else(|| self.default_context_dir());
@@ -360,27 +1050,90 @@ impl DocEngine {
             };
 
         let cache_key = format!("{}::{}", package_name, package_root.to_string_lossy());
-        let index = {
-            let mut cache = self.python_semantic_cache.lock().await;
-            if let Some(existing) = cache.get(&cache_key) {
-                Arc::clone(existing)
-            } else {
-                drop(cache);
-                let package_root_clone = package_root.clone();
-                let package_name_owned = package_name.to_string();
-                let index = tokio::task::spawn_blocking(move || {
-                    PythonSemanticIndex::build(&package_name_owned, &package_root_clone)
-                })
+        let index_id = python_index_id(package_name, &package_root);
+        let existing = self
+            .python_semantic_cache
+            .lock()
+            .await
+            .get(&cache_key)
+            .cloned();
+        if let Some(existing) = &existing {
+            if self
+                .is_index_current(&index_id, existing, PythonSemanticIndex::is_stale)
                 .await
-                .map_err(|e| anyhow::anyhow!("Python semantic index worker failed: {e}"))??;
-                let index = Arc::new(index);
-                let mut cache = self.python_semantic_cache.lock().await;
-                cache.put(cache_key.clone(), Arc::clone(&index));
-                index
+            {
+                self.health.record_cache_hit(&index_id);
+                return Ok(Arc::clone(existing));
             }
-        };
+        }
 
-        Ok(index.search(query, limit))
+        let package_name_owned = package_name.to_string();
+        let health = Arc::clone(&self.health);
+        let disk_cache = Arc::clone(&self.cache);
+        let request_id = health::current_request();
+        let build_id = index_id.clone();
+        let index = tokio::task::spawn_blocking(move || {
+            health::in_request(request_id, || {
+                build_python_semantic_index(
+                    &health,
+                    &disk_cache,
+                    existing.as_deref(),
+                    &build_id,
+                    &package_name_owned,
+                    &package_root,
+                    &context_dir,
+                )
+            })
+        })
+        .await
+        .map_err(|e| anyhow::anyhow!("Python semantic index worker failed: {e}"))??;
+        let index = Arc::new(index);
+        self.python_semantic_cache
+            .lock()
+            .await
+            .put(cache_key, Arc::clone(&index));
+        self.mark_index_checked(&index_id);
+        Ok(index)
+    }
+
+    /// Whether a cached index still matches the files it was built from.
+    ///
+    /// Checking walks the package on a blocking thread, so it runs at most
+    /// once per [`STALENESS_CHECK_INTERVAL`] for each index; in between the
+    /// index is assumed current.
+    async fn is_index_current<T: Send + Sync + 'static>(
+        &self,
+        index_id: &str,
+        index: &Arc<T>,
+        is_stale: fn(&T) -> bool,
+    ) -> bool {
+        {
+            let mut checked = self.stale_checks.lock().unwrap();
+            if checked
+                .get(index_id)
+                .is_some_and(|at| at.elapsed() < STALENESS_CHECK_INTERVAL)
+            {
+                return true;
+            }
+            // Concurrent lookups rely on this check instead of starting their own
+            checked.insert(index_id.to_string(), Instant::now());
+        }
+        let index = Arc::clone(index);
+        let current = tokio::task::spawn_blocking(move || !is_stale(&index))
+            .await
+            .unwrap_or(false);
+        if !current {
+            self.stale_checks.lock().unwrap().remove(index_id);
+        }
+        current
+    }
+
+    /// Record that an index was just built from the current files.
+    fn mark_index_checked(&self, index_id: &str) {
+        self.stale_checks
+            .lock()
+            .unwrap()
+            .insert(index_id.to_string(), Instant::now());
     }
 
     async fn find_python_package_via_finder(
@@ -1019,7 +1772,7 @@ impl DocEngine {
                         let module_path: &str = caps.name("mod").unwrap().as_str();
                         let what_raw = caps.name("what").unwrap().as_str().trim();
                         let (file_path, is_dir) =
-                            Self::node_module_to_file(&package_root, module_path);
+                            Self::node_module_to_file(&package_root, &params.package, module_path);
                         if what_raw.starts_with('{') && what_raw.ends_with('}') {
                             // Named imports
                             for sym in what_raw
@@ -1143,8 +1896,11 @@ impl DocEngine {
                         let re2 = &simple_import_re;
                         if let Some(caps) = re2.captures(&raw) {
                             let module_path = caps.name("mod").unwrap().as_str();
-                            let (file_path, is_dir) =
-                                Self::node_module_to_file(&package_root, module_path);
+                            let (file_path, is_dir) = Self::node_module_to_file(
+                                &package_root,
+                                &params.package,
+                                module_path,
+                            );
                             if let Some(fp) = file_path {
                                 resolution.resolved.push(ImportSymbolLocation {
                                     symbol: module_path.to_string(),
@@ -1338,35 +2094,25 @@ impl DocEngine {
         Ok(None)
     }
 
-    /// Resolve a Node (ESM) module path to a concrete file (js/ts) or directory index.
+    /// Resolve a Node module specifier to a concrete file through the
+    /// package's `exports`, `module`/`main` fields or by path.
     /// Returns (file_path, is_directory_index).
     fn node_module_to_file(
         package_root: &std::path::Path,
+        package_name: &str,
         module_path: &str,
     ) -> (Option<std::path::PathBuf>, bool) {
-        // Strip possible leading ./ or /
-        let rel = module_path.trim_start_matches("./").trim_start_matches('/');
-        let base = package_root.join(rel);
-        if base.is_file() {
-            return (Some(base), false);
-        }
-        // Try explicit extensions
-        for ext in &["js", "ts", "mjs", "cjs"] {
-            let candidate = base.with_extension(ext);
-            if candidate.is_file() {
-                return (Some(candidate), false);
-            }
-        }
-        // Try directory index
-        if base.is_dir() {
-            for ix in &["index.ts", "index.js", "index.mjs", "index.cjs"] {
-                let candidate = base.join(ix);
-                if candidate.is_file() {
-                    return (Some(candidate), true);
-                }
-            }
-        }
-        (None, false)
+        use crate::doc_engine::node_resolve::{
+            resolve_package_module, IMPORT_CONDITIONS, REQUIRE_CONDITIONS,
+        };
+        [IMPORT_CONDITIONS, REQUIRE_CONDITIONS]
+            .into_iter()
+            .find_map(|conditions| {
+                resolve_package_module(package_root, package_name, module_path, conditions)
+            })
+            .map_or((None, false), |resolved| {
+                (Some(resolved.path), resolved.directory_index)
+            })
     }
 
     /// Heuristic Node symbol search for exported entities.
@@ -1425,26 +2171,26 @@ impl DocEngine {
         &self,
         language: &str,
         package_name: &str,
-        item_path: &str, // Format: "path/to/file.py#my_function"
+        item_path: &str, // Format: "path/to/file.py#my_function" (or "pkg.module.my_function" for Python)
         context_path: Option<&str>,
     ) -> Result<ImplementationContext> {
+        let context_dir = self.resolve_context_dir(context_path);
+        if language == "python" {
+            let (relative_path, item_name) = self
+                .python_processor
+                .resolve_item_path(package_name, &context_dir, item_path)
+                .await?;
+            return self
+                .python_processor
+                .get_implementation_context(package_name, &context_dir, &relative_path, &item_name)
+                .await;
+        }
+
         let (relative_path, item_name) = item_path
             .split_once('#')
             .context("Invalid item_path format. Expected 'path/to/file#item_name'")?;
 
-        let context_dir = self.resolve_context_dir(context_path);
-
         match language {
-            "python" => {
-                self.python_processor
-                    .get_implementation_context(
-                        package_name,
-                        &context_dir,
-                        relative_path,
-                        item_name,
-                    )
-                    .await
-            }
             "node" => {
                 self.node_processor
                     .get_implementation_context(
@@ -1473,6 +2219,51 @@ impl DocEngine {
         }
     }
 
+    /// Metadata of an installed Python distribution, found by distribution
+    /// name (`Pillow`) or by a package it installs (`PIL`).
+    pub async fn python_package_info(
+        &self,
+        name: &str,
+        context_path: Option<&str>,
+    ) -> Result<PythonPackageInfo> {
+        let context_dir = self.resolve_context_dir(context_path);
+        let name = name.to_string();
+        tokio::task::spawn_blocking(move || {
+            let mut site_dirs: Vec<PathBuf> = finder::find_python_site_packages(Some(&context_dir))
+                .into_iter()
+                .collect();
+            if let Ok(package_root) =
+                finder::find_python_package_path_with_context(&name, Some(&context_dir))
+            {
+                site_dirs.extend(package_root.parent().map(Path::to_path_buf));
+            }
+            let dist_info = site_dirs
+                .iter()
+                .find_map(|site_dir| python_metadata::find_dist_info(site_dir, &name))
+                .with_context(|| {
+                    format!("No installed Python distribution named or providing '{name}'")
+                })?;
+            python_metadata::read_package_info(&dist_info)
+        })
+        .await?
+    }
+
+    /// Manifest of an installed Node.js package, with every other copy
+    /// installed in nested `node_modules`.
+    pub async fn node_package_info(
+        &self,
+        name: &str,
+        context_path: Option<&str>,
+    ) -> Result<NodePackageInfo> {
+        let context_dir = self.resolve_context_dir(context_path);
+        let name = name.to_string();
+        tokio::task::spawn_blocking(move || {
+            let package_root = finder::find_node_package_path(&name, &context_dir)?;
+            node_metadata::read_package_info(&package_root)
+        })
+        .await?
+    }
+
     /// Ensure crate documentation is available and indexed
     async fn ensure_crate_docs(
         &self,
@@ -1485,24 +2276,22 @@ impl DocEngine {
         {
             let mut cache = self.memory_cache.lock().await;
             if let Some(docs) = cache.get(&cache_key) {
+                self.health
+                    .record_cache_hit(&rust_index_id(&docs.crate_name, &docs.version));
                 return Ok(Arc::clone(docs));
             }
         }
 
-        // Get crate information
-        let crate_info = self.fetcher.crate_info(crate_name).await?;
+        // Resolve the latest version only when none was requested
         let target_version = if let Some(v) = version {
             v.to_string()
         } else {
-            crate_info.latest_version
+            self.fetcher.crate_info(crate_name).await?.latest_version
         };
 
-        let _cache_key_versioned = format!("{crate_name}@{target_version}");
-
         // Check if we have cached search index data
         if let Some(search_data) = self.cache.get_crate_index(crate_name, &target_version)? {
-            let docs = CrateDocumentation::new_from_search_index(search_data, &self.index).await?;
-            let docs = Arc::new(docs);
+            let docs = self.build_crate_docs(search_data, Instant::now()).await?;
 
             // Update memory cache
             {
@@ -1519,15 +2308,31 @@ impl DocEngine {
             crate_name, target_version
         );
 
-        let search_data = self.fetch_search_index(crate_name, &target_version).await?;
-        let docs =
-            CrateDocumentation::new_from_search_index(search_data.clone(), &self.index).await?;
+        let started = Instant::now();
+        let index_id = rust_index_id(crate_name, &target_version);
+        self.health
+            .report_progress(&index_id, BuildPhase::Starting, 0, 0, started);
+        self.health
+            .report_progress(&index_id, BuildPhase::ParsingRustdoc, 0, 0, started);
+        let search_data = match self.fetch_search_index(crate_name, &target_version).await {
+            Ok(data) => data,
+            Err(e) => {
+                self.health.report_progress(
+                    &index_id,
+                    BuildPhase::Failed(e.to_string()),
+                    0,
+                    0,
+                    started,
+                );
+                return Err(e);
+            }
+        };
 
         // Cache the search index data
         self.cache
             .store_crate_index(crate_name, &target_version, &search_data)?;
 
-        let docs = Arc::new(docs);
+        let docs = self.build_crate_docs(search_data, started).await?;
 
         // Update memory cache
         {
@@ -1538,6 +2343,92 @@ impl DocEngine {
         Ok(docs)
     }
 
+    /// Build in-memory indexes for a crate's search data, publishing build
+    /// progress and registering the result with the health tracker.
+    async fn build_crate_docs(
+        &self,
+        search_data: SearchIndexData,
+        started: Instant,
+    ) -> Result<Arc<CrateDocumentation>> {
+        let crate_name = search_data.crate_name.clone();
+        let version = search_data.version.clone();
+        let index_id = rust_index_id(&crate_name, &version);
+        let total = search_data.items.len();
+
+        self.health.report_progress(
+            &index_id,
+            BuildPhase::BuildingSearchIndex,
+            0,
+            total,
+            started,
+        );
+        let validation = health::validate_search_index(&search_data);
+        let size_bytes = bincode::serialized_size(&search_data).unwrap_or(0);
+
+        let docs = match CrateDocumentation::new_from_search_index(search_data, &self.index).await {
+            Ok(docs) => docs,
+            Err(e) => {
+                self.health.report_progress(
+                    &index_id,
+                    BuildPhase::Failed(e.to_string()),
+                    0,
+                    total,
+                    started,
+                );
+                return Err(e);
+            }
+        };
+
+        self.health
+            .report_progress(&index_id, BuildPhase::Complete, total, total, started);
+        let source = self.cache.crate_index_path(&crate_name, &version);
+        let data_timestamp = std::fs::metadata(&source).and_then(|m| m.modified()).ok();
+        self.health.record_build(
+            &index_id,
+            health::BuiltIndex {
+                kind: health::IndexKind::RustSymbols,
+                name: crate_name,
+                version: Some(version),
+                source: source.is_file().then_some(source),
+                item_count: total,
+                size_bytes,
+                data_timestamp,
+                validation,
+            },
+            started.elapsed(),
+        );
+
+        Ok(Arc::new(docs))
+    }
+
+    /// Load crate documentation from the memory or disk cache without
+    /// touching the network. Returns `None` when no index is cached.
+    async fn load_cached_crate_docs(
+        &self,
+        crate_name: &str,
+        version: &str,
+    ) -> Result<Option<Arc<CrateDocumentation>>> {
+        let cache_key = format!("{crate_name}@{version}");
+        {
+            let mut cache = self.memory_cache.lock().await;
+            if let Some(docs) = cache.get(&cache_key) {
+                self.health
+                    .record_cache_hit(&rust_index_id(crate_name, version));
+                return Ok(Some(Arc::clone(docs)));
+            }
+        }
+
+        let Some(search_data) = self.cache.get_crate_index(crate_name, version)? else {
+            return Ok(None);
+        };
+        let docs = self.build_crate_docs(search_data, Instant::now()).await?;
+        {
+            let mut cache = self.memory_cache.lock().await;
+            cache.put(cache_key, Arc::clone(&docs));
+        }
+        Ok(Some(docs))
+    }
+
     /// Clear all cache entries
     pub async fn clear_all_cache(&self) -> Result<CacheOperationResult> {
         {
@@ -1552,14 +2443,101 @@ impl DocEngine {
             let mut python = self.python_semantic_cache.lock().await;
             python.clear();
         }
+        {
+            let mut rust = self.rust_semantic_cache.lock().await;
+            rust.clear();
+        }
+        {
+            let mut node = self.node_semantic_cache.lock().await;
+            node.clear();
+        }
+        self.health.remove_matching(|_| true);
         self.cache.clear_all()
     }
 
     /// Clear cache entries for a specific crate
     pub async fn clear_crate_cache(&self, crate_name: &str) -> Result<CacheOperationResult> {
+        {
+            let prefix = format!("{crate_name}@");
+            let mut mem = self.memory_cache.lock().await;
+            let keys: Vec<String> = mem
+                .iter()
+                .filter(|(key, _)| key.starts_with(&prefix))
+                .map(|(key, _)| key.clone())
+                .collect();
+            for key in keys {
+                mem.pop(&key);
+            }
+            let mut rust = self.rust_semantic_cache.lock().await;
+            let keys: Vec<String> = rust
+                .iter()
+                .filter(|(key, _)| key.starts_with(&prefix))
+                .map(|(key, _)| key.clone())
+                .collect();
+            for key in keys {
+                rust.pop(&key);
+            }
+        }
+        let id_prefix = rust_index_id(crate_name, "");
+        self.health.remove_matching(|id| id.starts_with(&id_prefix));
         self.cache.clear_crate(crate_name)
     }
 
+    /// Report size, build duration, staleness and validation results for
+    /// every index built by this engine, optionally filtered by language.
+    pub async fn index_health(
+        &self,
+        language: Option<&str>,
+    ) -> Result<health::IndexHealthResponse> {
+        let kind = match language {
+            None => None,
+            Some("rust") => Some(health::IndexKind::RustSymbols),
+            Some("python") => Some(health::IndexKind::PythonSemantic),
+            Some("node") => Some(health::IndexKind::NodeSemantic),
+            Some(other) => {
+                return Err(anyhow::anyhow!(
+                    "Index health is not tracked for language '{}'",
+                    other
+                ))
+            }
+        };
+        let rust_ttl = Duration::from_secs(self.cache.config().entry_ttl_hours * 3600);
+        let tracker = Arc::clone(&self.health);
+
+        tokio::task::spawn_blocking(move || {
+            tracker.report(kind, |info, built_at, data_age| match info.kind {
+                health::IndexKind::RustSymbols => (data_age > rust_ttl).then(|| {
+                    format!(
+                        "Cached search index is {}h old (TTL {}h)",
+                        data_age.as_secs() / 3600,
+                        rust_ttl.as_secs() / 3600
+                    )
+                }),
+                health::IndexKind::PythonSemantic => {
+                    let root = info.source.as_deref()?;
+                    let changed = python_semantic::files_modified_since(root, built_at);
+                    (changed > 0).then(|| {
+                        format!("{changed} source file(s) changed since the index was built")
+                    })
+                }
+                health::IndexKind::NodeSemantic => {
+                    let root = info.source.as_deref()?;
+                    let changed = node_semantic::files_modified_since(root, built_at);
+                    (changed > 0).then(|| {
+                        format!("{changed} source file(s) changed since the index was built")
+                    })
+                }
+            })
+        })
+        .await
+        .map_err(|e| anyhow::anyhow!("Index health worker failed: {e}"))
+    }
+
+    /// Subscribe to progress events published while indexes are built.
+    pub fn subscribe_build_progress(&self) -> broadcast::Receiver<health::IndexBuildEvent> {
+        self.health.subscribe()
+    }
+
     /// Get cache statistics
     pub async fn get_cache_stats(&self) -> Result<CacheStatistics> {
         self.cache.get_enhanced_stats()
@@ -1577,6 +2555,193 @@ impl DocEngine {
     }
 }
 
+/// Health-tracker id for a crate's symbol index
+fn rust_index_id(crate_name: &str, version: &str) -> String {
+    format!("rust:{crate_name}@{version}")
+}
+
+/// Find a crate named `crate_name` among the manifests in `context_dir`
+/// and up to two directory levels below it, returning its root and version.
+fn find_local_rust_crate(context_dir: &Path, crate_name: &str) -> Option<(PathBuf, String)> {
+    let wanted = crate_name.replace('-', "_");
+    walkdir::WalkDir::new(context_dir)
+        .max_depth(3)
+        .into_iter()
+        .filter_entry(|entry| {
+            let name = entry.file_name().to_string_lossy();
+            entry.depth() == 0 || !(name.starts_with('.') || name == "target")
+        })
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_name() == "Cargo.toml")
+        .filter_map(|entry| {
+            let root = entry.path().parent()?.to_path_buf();
+            let (name, version) = rust_semantic::local_crate_manifest(&root).ok()?;
+            (name.replace('-', "_") == wanted).then_some((root, version))
+        })
+        .next()
+}
+
+/// Health-tracker id for a Python package's semantic index
+fn python_index_id(package_name: &str, package_root: &Path) -> String {
+    format!("python:{package_name}@{}", package_root.display())
+}
+
+/// Health-tracker id for a Node.js package's semantic index
+fn node_index_id(package_name: &str, package_root: &Path) -> String {
+    format!("node:{package_name}@{}", package_root.display())
+}
+
+/// Build a Node.js semantic index on a blocking thread and register it for
+/// health reports.
+fn build_node_semantic_index(
+    health: &health::IndexHealthTracker,
+    index_id: &str,
+    package_name: &str,
+    package_root: &Path,
+) -> Result<NodeSemanticIndex> {
+    let started = Instant::now();
+    health.report_progress(index_id, BuildPhase::Starting, 0, 0, started);
+    let index = match NodeSemanticIndex::build(package_name, package_root) {
+        Ok(index) => index,
+        Err(e) => {
+            health.report_progress(index_id, BuildPhase::Failed(e.to_string()), 0, 0, started);
+            return Err(e);
+        }
+    };
+
+    health.report_progress(
+        index_id,
+        BuildPhase::Finalizing,
+        index.len(),
+        index.len(),
+        started,
+    );
+    let validation = index.validate();
+    health.report_progress(
+        index_id,
+        BuildPhase::Complete,
+        index.len(),
+        index.len(),
+        started,
+    );
+    health.record_build(
+        index_id,
+        health::BuiltIndex {
+            kind: health::IndexKind::NodeSemantic,
+            name: package_name.to_string(),
+            version: index.package_version().map(str::to_string),
+            source: Some(package_root.to_path_buf()),
+            item_count: index.len(),
+            size_bytes: index.approximate_size_bytes(),
+            data_timestamp: None,
+            validation,
+        },
+        started.elapsed(),
+    );
+    Ok(index)
+}
+
+/// Build a Python semantic index on a blocking thread, streaming progress
+/// every few files and registering the finished index for health reports.
+///
+/// An index that is already in memory (`previous`) or persisted for the
+/// same package root and version is refreshed in place, re-parsing only
+/// files whose content hash changed; the result is written back to the disk
+/// cache whenever it differs.
+fn build_python_semantic_index(
+    health: &health::IndexHealthTracker,
+    disk_cache: &cache::Cache,
+    previous: Option<&PythonSemanticIndex>,
+    index_id: &str,
+    package_name: &str,
+    package_root: &Path,
+    context_dir: &Path,
+) -> Result<PythonSemanticIndex> {
+    const PROGRESS_EVERY_FILES: usize = 25;
+
+    let started = Instant::now();
+    health.report_progress(index_id, BuildPhase::Starting, 0, 0, started);
+    let on_progress = |done: usize, total: usize| {
+        if done.is_multiple_of(PROGRESS_EVERY_FILES) || done == total {
+            health.report_progress(
+                index_id,
+                BuildPhase::BuildingSearchIndex,
+                done,
+                total,
+                started,
+            );
+        }
+    };
+    let built = match previous {
+        Some(previous) => previous
+            .refreshed(on_progress)
+            .map(|(index, refresh)| (index, Some(refresh))),
+        None => {
+            let persisted = disk_cache
+                .get_python_index(package_name, package_root)
+                .unwrap_or_else(|e| {
+                    warn!("Ignoring unreadable Python index cache for {package_name}: {e}");
+                    None
+                });
+            PythonSemanticIndex::load_or_build(
+                package_name,
+                package_root,
+                Some(context_dir),
+                persisted.as_deref(),
+                on_progress,
+            )
+        }
+    };
+
+    let index = match built {
+        Ok((index, refresh)) => {
+            if refresh.is_none_or(|refresh| refresh.needs_persist()) {
+                if let Err(e) = index.to_bytes().and_then(|bytes| {
+                    disk_cache.store_python_index(package_name, package_root, &bytes)
+                }) {
+                    warn!("Failed to persist Python index for {package_name}: {e}");
+                }
+            }
+            index
+        }
+        Err(e) => {
+            health.report_progress(index_id, BuildPhase::Failed(e.to_string()), 0, 0, started);
+            return Err(e);
+        }
+    };
+
+    health.report_progress(
+        index_id,
+        BuildPhase::Finalizing,
+        index.len(),
+        index.len(),
+        started,
+    );
+    let validation = index.validate();
+    health.report_progress(
+        index_id,
+        BuildPhase::Complete,
+        index.len(),
+        index.len(),
+        started,
+    );
+    health.record_build(
+        index_id,
+        health::BuiltIndex {
+            kind: health::IndexKind::PythonSemantic,
+            name: package_name.to_string(),
+            version: index.package_version().map(str::to_string),
+            source: Some(package_root.to_path_buf()),
+            item_count: index.len(),
+            size_bytes: index.approximate_size_bytes(),
+            data_timestamp: None,
+            validation,
+        },
+        started.elapsed(),
+    );
+    Ok(index)
+}
+
 /// Documentation for a specific crate
 #[derive(Debug, Serialize, Deserialize)]
 pub struct CrateDocumentation {
@@ -1587,6 +2752,8 @@ pub struct CrateDocumentation {
     trait_impl_index: TraitImplIndex,
     #[serde(skip)]
     symbol_index: Option<SymbolIndex>,
+    #[serde(skip)]
+    completion_index: CompletionIndex,
 }
 
 impl CrateDocumentation {
@@ -1617,6 +2784,7 @@ impl CrateDocumentation {
         let trait_impl_index = TraitImplIndex::from_search_index(&index_core_search_data)?;
         let symbol_index =
             Some(SymbolIndex::from_search_index(&index_core_search_data, index_core).await?);
+        let completion_index = CompletionIndex::from_search_index(&index_core_search_data);
 
         Ok(Self {
             crate_name: search_index_data.crate_name.clone(),
@@ -1624,9 +2792,15 @@ impl CrateDocumentation {
             search_index_data,
             trait_impl_index,
             symbol_index,
+            completion_index,
         })
     }
 
+    /// Complete a partially typed item path within this crate
+    pub fn complete_path(&self, prefix: &str, limit: usize) -> Vec<CompletionCandidate> {
+        self.completion_index.complete(prefix, limit)
+    }
+
     /// Get documentation for a specific item (now uses on-demand scraping)
     pub async fn get_item_doc(&self, path: &str) -> Result<ItemDoc> {
         // Use scraper to fetch item documentation on-demand
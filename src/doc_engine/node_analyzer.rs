@@ -0,0 +1,611 @@
+//! JavaScript and TypeScript code analysis using tree-sitter.
+//!
+//! The Node.js counterpart of `python_analyzer`: class methods with their
+//! modifiers, TypeScript parameter and return types and JSDoc, and regex
+//! search over a package's sources.
+
+use anyhow::{Context, Result};
+use rayon::prelude::*;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::path::Path;
+use tree_sitter::{Node, Parser};
+
+use crate::doc_engine::node_resolve::source_language;
+use crate::doc_engine::node_semantic::{clean_jsdoc, node_source_files};
+use crate::doc_engine::python_analyzer::{SearchMode, SearchResult};
+
+const MAX_SIGNATURE_CHARS: usize = 300;
+
+/// Information about a single method of a JavaScript or TypeScript class.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NodeMethodInfo {
+    pub name: String,
+    pub signature: String,
+    pub jsdoc: Option<String>,
+    pub parameters: Vec<NodeParameter>,
+    /// TypeScript return type annotation
+    pub return_type: Option<String>,
+    pub line_start: usize,
+    pub line_end: usize,
+    pub is_static: bool,
+    pub is_getter: bool,
+    pub is_setter: bool,
+    pub is_async: bool,
+    pub is_generator: bool,
+    pub is_abstract: bool,
+    /// `#name`, or declared `private` in TypeScript
+    pub is_private: bool,
+    /// TypeScript `public`/`protected`/`private` modifier
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub accessibility: Option<String>,
+}
+
+/// A parameter of a JavaScript or TypeScript method.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NodeParameter {
+    /// Name, or the destructuring pattern as written
+    pub name: String,
+    #[serde(rename = "type")]
+    pub type_name: Option<String>,
+    pub default: Option<String>,
+    pub optional: bool,
+    pub rest: bool,
+}
+
+/// Extract the methods of a class (or TypeScript interface) using
+/// tree-sitter. Without `include_private`, `#private`, TypeScript `private`
+/// and `_`-prefixed methods are skipped.
+pub fn extract_class_methods(
+    source_code: &str,
+    file_path: &Path,
+    class_name: &str,
+    include_private: bool,
+) -> Result<Vec<NodeMethodInfo>> {
+    let language = source_language(file_path).context("Not a JavaScript or TypeScript file")?;
+    let mut parser = Parser::new();
+    parser
+        .set_language(&language)
+        .context("Error loading tree-sitter grammar for Node.js language")?;
+    let tree = parser
+        .parse(source_code, None)
+        .context("Failed to parse JavaScript/TypeScript source")?;
+    let source = source_code.as_bytes();
+
+    let class_node = find_class_node(tree.root_node(), class_name, source)
+        .with_context(|| format!("Class '{class_name}' not found"))?;
+    let body = class_node
+        .child_by_field_name("body")
+        .with_context(|| format!("Class '{class_name}' has no body"))?;
+
+    let mut methods: Vec<NodeMethodInfo> = body
+        .named_children(&mut body.walk())
+        .filter(|member| {
+            matches!(
+                member.kind(),
+                "method_definition" | "method_signature" | "abstract_method_signature"
+            )
+        })
+        .filter_map(|member| method_info(member, source))
+        .filter(|method| include_private || !(method.is_private || method.name.starts_with('_')))
+        .collect();
+    // Overload signatures followed by their implementation describe one method
+    let mut seen = HashSet::new();
+    methods.reverse();
+    methods.retain(|method| {
+        seen.insert((
+            method.name.clone(),
+            method.is_static,
+            method.is_getter,
+            method.is_setter,
+        ))
+    });
+    methods.reverse();
+    Ok(methods)
+}
+
+/// Extract a single method of a class.
+pub fn extract_specific_method(
+    source_code: &str,
+    file_path: &Path,
+    class_name: &str,
+    method_name: &str,
+) -> Result<NodeMethodInfo> {
+    extract_class_methods(source_code, file_path, class_name, true)?
+        .into_iter()
+        .find(|method| method.name == method_name)
+        .with_context(|| format!("Method '{method_name}' not found in class '{class_name}'"))
+}
+
+/// Complete methods extracted from JavaScript with their declarations in
+/// the typings: typed parameters, return types and signatures replace the
+/// untyped ones, missing JSDoc is taken from the typings and methods only
+/// declared there are appended.
+pub fn merge_typed_methods(methods: &mut Vec<NodeMethodInfo>, typed_methods: Vec<NodeMethodInfo>) {
+    for typed in typed_methods {
+        let existing = methods.iter_mut().find(|method| {
+            method.name == typed.name
+                && method.is_static == typed.is_static
+                && method.is_getter == typed.is_getter
+                && method.is_setter == typed.is_setter
+        });
+        match existing {
+            Some(method) => {
+                method.signature = typed.signature;
+                method.parameters = typed.parameters;
+                method.return_type = typed.return_type;
+                method.accessibility = method.accessibility.take().or(typed.accessibility);
+                method.jsdoc = method.jsdoc.take().or(typed.jsdoc);
+            }
+            None => methods.push(typed),
+        }
+    }
+}
+
+/// Search the JavaScript and TypeScript files of a package.
+pub fn search_package(
+    package_path: &Path,
+    pattern: &str,
+    search_mode: SearchMode,
+    limit: usize,
+) -> Result<Vec<SearchResult>> {
+    let regex = Regex::new(pattern).context("Invalid regex pattern")?;
+    let mut files = node_source_files(package_path);
+    files.sort();
+
+    let file_results: Vec<Vec<SearchResult>> = files
+        .par_iter()
+        .filter_map(|path| search_file(path, &regex, search_mode).ok())
+        .collect();
+    Ok(file_results.into_iter().flatten().take(limit).collect())
+}
+
+fn search_file(
+    file_path: &Path,
+    pattern: &Regex,
+    search_mode: SearchMode,
+) -> Result<Vec<SearchResult>> {
+    let source_code = std::fs::read_to_string(file_path)?;
+    let language = source_language(file_path).context("Not a JavaScript or TypeScript file")?;
+    let mut parser = Parser::new();
+    parser.set_language(&language)?;
+    let tree = parser
+        .parse(&source_code, None)
+        .context("Failed to parse JavaScript/TypeScript source")?;
+
+    let mut results = Vec::new();
+    search_node(
+        tree.root_node(),
+        source_code.as_bytes(),
+        pattern,
+        search_mode,
+        file_path,
+        None,
+        &mut results,
+    );
+    Ok(results)
+}
+
+fn search_node(
+    node: Node,
+    source: &[u8],
+    pattern: &Regex,
+    search_mode: SearchMode,
+    file_path: &Path,
+    current_class: Option<&str>,
+    results: &mut Vec<SearchResult>,
+) {
+    let text = |node: Node| node.utf8_text(source).unwrap_or_default().to_string();
+    let (item_type, name_node, value) = match node.kind() {
+        "class_declaration" | "abstract_class_declaration" | "class" | "interface_declaration" => {
+            ("class", node.child_by_field_name("name"), node)
+        }
+        "function_declaration" | "generator_function_declaration" | "function_signature" => {
+            ("function", node.child_by_field_name("name"), node)
+        }
+        "method_definition" | "method_signature" | "abstract_method_signature" => {
+            ("method", node.child_by_field_name("name"), node)
+        }
+        // `const parse = (input) => ...`, `const Client = class { ... }`
+        "variable_declarator" => match node.child_by_field_name("value") {
+            Some(value) if value.kind() == "class" => {
+                ("class", node.child_by_field_name("name"), value)
+            }
+            Some(value)
+                if matches!(
+                    value.kind(),
+                    "arrow_function" | "function_expression" | "function"
+                ) =>
+            {
+                ("function", node.child_by_field_name("name"), value)
+            }
+            _ => ("", None, node),
+        },
+        _ => ("", None, node),
+    };
+
+    let Some(name_node) = name_node else {
+        for child in node.named_children(&mut node.walk()) {
+            search_node(
+                child,
+                source,
+                pattern,
+                search_mode,
+                file_path,
+                current_class,
+                results,
+            );
+        }
+        return;
+    };
+    let name = text(name_node);
+    let signature = (item_type != "class").then(|| signature(value, source));
+    let jsdoc = jsdoc(node, source);
+    let matched = match search_mode {
+        // Classes match by name only; their members are searched below
+        _ if item_type == "class" => {
+            matches!(search_mode, SearchMode::Name | SearchMode::FullText)
+                && pattern.is_match(&name)
+        }
+        SearchMode::Name => pattern.is_match(&name),
+        SearchMode::Signature => signature.as_ref().is_some_and(|sig| pattern.is_match(sig)),
+        SearchMode::Docstring => jsdoc.as_ref().is_some_and(|doc| pattern.is_match(doc)),
+        SearchMode::FullText => pattern.is_match(&text(node)),
+    };
+    if matched {
+        results.push(SearchResult {
+            file_path: file_path.to_path_buf(),
+            item_type: item_type.to_string(),
+            item_name: name.clone(),
+            class_name: (item_type == "method")
+                .then(|| current_class.map(str::to_string))
+                .flatten(),
+            signature,
+            docstring: jsdoc,
+            line_number: node.start_position().row + 1,
+        });
+    }
+
+    if item_type == "class" {
+        if let Some(body) = value.child_by_field_name("body") {
+            for member in body.named_children(&mut body.walk()) {
+                search_node(
+                    member,
+                    source,
+                    pattern,
+                    search_mode,
+                    file_path,
+                    Some(&name),
+                    results,
+                );
+            }
+        }
+    }
+}
+
+/// Class, abstract class, class expression bound to a name, or interface
+/// called `class_name`.
+fn find_class_node<'a>(node: Node<'a>, class_name: &str, source: &[u8]) -> Option<Node<'a>> {
+    let name_is = |node: Node| {
+        node.child_by_field_name("name")
+            .and_then(|name| name.utf8_text(source).ok())
+            == Some(class_name)
+    };
+    match node.kind() {
+        "class_declaration" | "abstract_class_declaration" | "class" | "interface_declaration"
+            if name_is(node) =>
+        {
+            return Some(node);
+        }
+        "variable_declarator" if name_is(node) => {
+            if let Some(value) = node
+                .child_by_field_name("value")
+                .filter(|value| value.kind() == "class")
+            {
+                return Some(value);
+            }
+        }
+        _ => {}
+    }
+    node.named_children(&mut node.walk())
+        .find_map(|child| find_class_node(child, class_name, source))
+}
+
+fn method_info(node: Node, source: &[u8]) -> Option<NodeMethodInfo> {
+    let text = |node: Node| node.utf8_text(source).unwrap_or_default().to_string();
+    let name_node = node.child_by_field_name("name")?;
+    let name = text(name_node);
+
+    let mut info = NodeMethodInfo {
+        signature: signature(node, source),
+        jsdoc: jsdoc(node, source),
+        parameters: node
+            .child_by_field_name("parameters")
+            .map(|parameters| {
+                parameters
+                    .named_children(&mut parameters.walk())
+                    .filter(|parameter| parameter.kind() != "comment")
+                    .map(|parameter| parameter_info(parameter, source))
+                    .collect()
+            })
+            .unwrap_or_default(),
+        return_type: node
+            .child_by_field_name("return_type")
+            .map(|annotation| type_text(annotation, source)),
+        line_start: node.start_position().row + 1,
+        line_end: node.end_position().row + 1,
+        is_static: false,
+        is_getter: false,
+        is_setter: false,
+        is_async: false,
+        is_generator: false,
+        is_abstract: node.kind() == "abstract_method_signature",
+        is_private: name_node.kind() == "private_property_identifier",
+        accessibility: None,
+        name,
+    };
+    // Modifiers precede the name
+    for child in node.children(&mut node.walk()) {
+        if child.id() == name_node.id() {
+            break;
+        }
+        match child.kind() {
+            "static" => info.is_static = true,
+            "get" => info.is_getter = true,
+            "set" => info.is_setter = true,
+            "async" => info.is_async = true,
+            "*" => info.is_generator = true,
+            "abstract" => info.is_abstract = true,
+            "accessibility_modifier" => {
+                let accessibility = text(child);
+                info.is_private |= accessibility == "private";
+                info.accessibility = Some(accessibility);
+            }
+            _ => {}
+        }
+    }
+    Some(info)
+}
+
+fn parameter_info(node: Node, source: &[u8]) -> NodeParameter {
+    let text = |node: Node| node.utf8_text(source).unwrap_or_default().to_string();
+    match node.kind() {
+        // TypeScript: `name: T = value`, `name?: T`, `...rest: T[]`
+        "required_parameter" | "optional_parameter" => {
+            let pattern = node.child_by_field_name("pattern");
+            let rest = pattern.is_some_and(|pattern| pattern.kind() == "rest_pattern");
+            let name = pattern
+                .map(|pattern| text(pattern).trim_start_matches("...").to_string())
+                .unwrap_or_default();
+            NodeParameter {
+                name,
+                type_name: node
+                    .child_by_field_name("type")
+                    .map(|annotation| type_text(annotation, source)),
+                default: node.child_by_field_name("value").map(text),
+                optional: node.kind() == "optional_parameter"
+                    || node.child_by_field_name("value").is_some(),
+                rest,
+            }
+        }
+        // JavaScript: `name = value`
+        "assignment_pattern" => NodeParameter {
+            name: node
+                .child_by_field_name("left")
+                .map(text)
+                .unwrap_or_default(),
+            type_name: None,
+            default: node.child_by_field_name("right").map(text),
+            optional: true,
+            rest: false,
+        },
+        "rest_pattern" => NodeParameter {
+            name: text(node).trim_start_matches("...").to_string(),
+            type_name: None,
+            default: None,
+            optional: true,
+            rest: true,
+        },
+        _ => NodeParameter {
+            name: text(node),
+            type_name: None,
+            default: None,
+            optional: false,
+            rest: false,
+        },
+    }
+}
+
+/// A `type_annotation` without its leading colon.
+fn type_text(annotation: Node, source: &[u8]) -> String {
+    let text = annotation.utf8_text(source).unwrap_or_default();
+    text.trim_start_matches(':').trim().to_string()
+}
+
+/// Declaration text up to the body, on a single line.
+fn signature(node: Node, source: &[u8]) -> String {
+    let end = node
+        .child_by_field_name("body")
+        .map_or(node.end_byte(), |body| body.start_byte());
+    let text = std::str::from_utf8(&source[node.start_byte()..end]).unwrap_or_default();
+    let mut signature = text
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .trim_end_matches(['{', ';', ' '])
+        .trim_end_matches("=>")
+        .trim_end()
+        .to_string();
+    if signature.chars().count() > MAX_SIGNATURE_CHARS {
+        signature = signature.chars().take(MAX_SIGNATURE_CHARS).collect();
+        signature.push('…');
+    }
+    signature
+}
+
+/// The `/** ... */` comment preceding a declaration or the statement
+/// exporting it.
+fn jsdoc(node: Node, source: &[u8]) -> Option<String> {
+    let mut anchor = node;
+    while let Some(parent) = anchor.parent().filter(|parent| {
+        matches!(
+            parent.kind(),
+            "export_statement" | "lexical_declaration" | "variable_declaration"
+        )
+    }) {
+        anchor = parent;
+    }
+    let raw = anchor
+        .prev_sibling()
+        .filter(|prev| prev.kind() == "comment")?
+        .utf8_text(source)
+        .ok()?;
+    if !raw.starts_with("/**") {
+        return None;
+    }
+    let cleaned = clean_jsdoc(raw);
+    (!cleaned.is_empty()).then_some(cleaned)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = r#"
+export abstract class Client<T> {
+    #secret = 1;
+
+    /** Create a client. */
+    constructor(private readonly base: string, retries = 3) {}
+
+    /**
+     * Send a request.
+     * @param path Request path
+     */
+    async request(path: string, init?: RequestInit, ...rest: unknown[]): Promise<T> {
+        return this.#send(path);
+    }
+
+    get url(): string { return this.base; }
+    set url(value: string) {}
+
+    static create<T>(base: string): Client<T>;
+    static create<T>(base: URL): Client<T>;
+    static create<T>(base: string | URL): Client<T> { return null!; }
+
+    *entries() {}
+    #send(path) {}
+    protected _log(message: string): void {}
+    abstract close(): void;
+}
+"#;
+
+    #[test]
+    fn extracts_typescript_methods_with_flags_and_types() {
+        let methods =
+            extract_class_methods(SOURCE, Path::new("client.ts"), "Client", false).unwrap();
+        let names: Vec<&str> = methods.iter().map(|m| m.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec![
+                "constructor",
+                "request",
+                "url",
+                "url",
+                "create",
+                "entries",
+                "close"
+            ]
+        );
+
+        let request = &methods[1];
+        assert!(request.is_async);
+        assert_eq!(request.return_type.as_deref(), Some("Promise<T>"));
+        assert_eq!(
+            request.jsdoc.as_deref(),
+            Some("Send a request.\n@param path Request path")
+        );
+        assert_eq!(
+            request.parameters,
+            vec![
+                NodeParameter {
+                    name: "path".into(),
+                    type_name: Some("string".into()),
+                    default: None,
+                    optional: false,
+                    rest: false,
+                },
+                NodeParameter {
+                    name: "init".into(),
+                    type_name: Some("RequestInit".into()),
+                    default: None,
+                    optional: true,
+                    rest: false,
+                },
+                NodeParameter {
+                    name: "rest".into(),
+                    type_name: Some("unknown[]".into()),
+                    default: None,
+                    optional: false,
+                    rest: true,
+                },
+            ]
+        );
+        assert!(methods[2].is_getter && methods[3].is_setter);
+        assert!(methods[4].is_static);
+        assert_eq!(
+            methods[4].parameters[0].type_name.as_deref(),
+            Some("string | URL")
+        );
+        assert!(methods[5].is_generator);
+        assert!(methods[6].is_abstract);
+
+        let all = extract_class_methods(SOURCE, Path::new("client.ts"), "Client", true).unwrap();
+        let send = all.iter().find(|m| m.name == "#send").unwrap();
+        assert!(send.is_private);
+        let log = all.iter().find(|m| m.name == "_log").unwrap();
+        assert_eq!(log.accessibility.as_deref(), Some("protected"));
+    }
+
+    #[test]
+    fn merges_typings_into_javascript_methods() {
+        let js = "class Queue {\n  /** Add a job. */\n  push(job, priority = 0) {}\n}\nmodule.exports = Queue;\n";
+        let dts = "export declare class Queue {\n  push(job: Job, priority?: number): void;\n  /** Number of jobs. */\n  get size(): number;\n}\n";
+        let mut methods = extract_class_methods(js, Path::new("queue.js"), "Queue", false).unwrap();
+        assert_eq!(methods[0].parameters[1].default.as_deref(), Some("0"));
+        let typed = extract_class_methods(dts, Path::new("queue.d.ts"), "Queue", false).unwrap();
+        merge_typed_methods(&mut methods, typed);
+
+        assert_eq!(methods.len(), 2);
+        assert_eq!(methods[0].return_type.as_deref(), Some("void"));
+        assert_eq!(methods[0].jsdoc.as_deref(), Some("Add a job."));
+        assert_eq!(methods[0].parameters[0].type_name.as_deref(), Some("Job"));
+        assert!(methods[1].is_getter);
+        assert_eq!(methods[1].jsdoc.as_deref(), Some("Number of jobs."));
+    }
+
+    #[test]
+    fn searches_package_sources() {
+        let temp = tempfile::tempdir().unwrap();
+        std::fs::write(temp.path().join("client.ts"), SOURCE).unwrap();
+        std::fs::write(
+            temp.path().join("util.js"),
+            "/** Retry an operation. */\nexport const retry = async (op, attempts) => op();\nfunction helper() { return fetch('/x'); }\n",
+        )
+        .unwrap();
+
+        let by_name = search_package(temp.path(), "^re", SearchMode::Name, 10).unwrap();
+        let names: Vec<(&str, &str)> = by_name
+            .iter()
+            .map(|r| (r.item_type.as_str(), r.item_name.as_str()))
+            .collect();
+        assert_eq!(names, vec![("method", "request"), ("function", "retry")]);
+        assert_eq!(by_name[0].class_name.as_deref(), Some("Client"));
+
+        let by_doc = search_package(temp.path(), "Retry", SearchMode::Docstring, 10).unwrap();
+        assert_eq!(by_doc.len(), 1);
+        let by_text = search_package(temp.path(), r"fetch\(", SearchMode::FullText, 10).unwrap();
+        assert_eq!(by_text[0].item_name, "helper");
+    }
+}
@@ -0,0 +1,263 @@
+//! Metadata of installed Node.js packages (`package.json`).
+//!
+//! Besides the manifest of the package itself, every other copy installed
+//! in nested `node_modules` directories is reported, since dependency
+//! version conflicts leave several versions of one package side by side.
+
+use anyhow::{Context, Result};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::doc_engine::types::{NodeInstalledCopy, NodePackageInfo};
+
+/// Nesting limit for `node_modules` directories (and guard against symlink
+/// cycles).
+const MAX_NESTING: usize = 8;
+
+/// Read the `package.json` of the package at `package_root` and look for
+/// other installed copies of it.
+pub fn read_package_info(package_root: &Path) -> Result<NodePackageInfo> {
+    let manifest_path = package_root.join("package.json");
+    let manifest = fs::read_to_string(&manifest_path)
+        .with_context(|| format!("Failed to read {}", manifest_path.display()))?;
+    let json: Value = serde_json::from_str(&manifest)
+        .with_context(|| format!("Failed to parse {}", manifest_path.display()))?;
+
+    let string = |key: &str| json.get(key).and_then(Value::as_str).map(str::to_string);
+    let name = string("name").unwrap_or_else(|| {
+        package_root
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default()
+    });
+    // The top-most `node_modules` is the project's own: the nearest one is
+    // a pnpm store entry or the parent of a nested copy.
+    let installed_versions = package_root
+        .ancestors()
+        .filter(|dir| dir.file_name().is_some_and(|name| name == "node_modules"))
+        .last()
+        .map(|node_modules| installed_copies(node_modules, &name))
+        .unwrap_or_default();
+
+    Ok(NodePackageInfo {
+        version: string("version").unwrap_or_default(),
+        description: string("description"),
+        license: license(&json),
+        main: string("main"),
+        module: string("module"),
+        types: string("types").or_else(|| string("typings")),
+        exports: json.get("exports").cloned(),
+        dependencies: string_map(json.get("dependencies")),
+        peer_dependencies: string_map(json.get("peerDependencies")),
+        optional_peer_dependencies: json
+            .get("peerDependenciesMeta")
+            .and_then(Value::as_object)
+            .map(|meta| {
+                meta.iter()
+                    .filter(|(_, flags)| flags.get("optional") == Some(&Value::Bool(true)))
+                    .map(|(dependency, _)| dependency.clone())
+                    .collect()
+            })
+            .unwrap_or_default(),
+        engines: string_map(json.get("engines")),
+        bin: bin_entries(&name, json.get("bin")),
+        package_path: package_root.to_string_lossy().into_owned(),
+        installed_versions,
+        name,
+    })
+}
+
+/// `license` as an SPDX string, or the legacy `{ "type": "MIT" }` form.
+fn license(json: &Value) -> Option<String> {
+    match json.get("license")? {
+        Value::String(license) => Some(license.clone()),
+        Value::Object(license) => license.get("type")?.as_str().map(str::to_string),
+        _ => None,
+    }
+}
+
+fn string_map(value: Option<&Value>) -> BTreeMap<String, String> {
+    value
+        .and_then(Value::as_object)
+        .map(|object| {
+            object
+                .iter()
+                .filter_map(|(key, value)| Some((key.clone(), value.as_str()?.to_string())))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// `bin` entries by command name. A single path installs a command named
+/// after the package, without its scope.
+fn bin_entries(package_name: &str, bin: Option<&Value>) -> BTreeMap<String, String> {
+    match bin {
+        Some(Value::String(path)) => {
+            let command = package_name.rsplit('/').next().unwrap_or(package_name);
+            BTreeMap::from([(command.to_string(), path.clone())])
+        }
+        other => string_map(other),
+    }
+}
+
+/// Every copy of `package_name` under a `node_modules` directory, including
+/// nested `node_modules` and pnpm's `.pnpm` store.
+pub fn installed_copies(node_modules: &Path, package_name: &str) -> Vec<NodeInstalledCopy> {
+    let mut directories = Vec::new();
+    collect_copies(node_modules, package_name, 0, &mut directories);
+
+    let mut seen = HashSet::new();
+    let mut copies: Vec<NodeInstalledCopy> = directories
+        .into_iter()
+        .filter(|dir| seen.insert(dir.canonicalize().unwrap_or_else(|_| dir.clone())))
+        .map(|dir| {
+            let version = fs::read_to_string(dir.join("package.json"))
+                .ok()
+                .and_then(|manifest| serde_json::from_str::<Value>(&manifest).ok())
+                .and_then(|json| json.get("version")?.as_str().map(str::to_string))
+                .unwrap_or_default();
+            NodeInstalledCopy {
+                version,
+                path: dir.to_string_lossy().into_owned(),
+            }
+        })
+        .collect();
+    copies.sort_by(|a, b| a.path.cmp(&b.path));
+    copies
+}
+
+fn collect_copies(
+    node_modules: &Path,
+    package_name: &str,
+    depth: usize,
+    copies: &mut Vec<PathBuf>,
+) {
+    if depth > MAX_NESTING {
+        return;
+    }
+    let Ok(entries) = fs::read_dir(node_modules) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let name = entry.file_name().to_string_lossy().into_owned();
+        if !path.is_dir() || name == ".bin" {
+            continue;
+        }
+        // `.pnpm/<name>@<version>/node_modules/<name>`
+        if name == ".pnpm" {
+            for store_entry in fs::read_dir(&path).into_iter().flatten().flatten() {
+                collect_copies(
+                    &store_entry.path().join("node_modules"),
+                    package_name,
+                    depth + 1,
+                    copies,
+                );
+            }
+            continue;
+        }
+        let packages: Vec<(String, PathBuf)> = if name.starts_with('@') {
+            fs::read_dir(&path)
+                .into_iter()
+                .flatten()
+                .flatten()
+                .map(|scoped| {
+                    let scoped_name = scoped.file_name().to_string_lossy().into_owned();
+                    (format!("{name}/{scoped_name}"), scoped.path())
+                })
+                .collect()
+        } else {
+            vec![(name, path)]
+        };
+        for (name, dir) in packages {
+            if name == package_name && dir.join("package.json").is_file() {
+                copies.push(dir.clone());
+            }
+            collect_copies(&dir.join("node_modules"), package_name, depth + 1, copies);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::doc_engine::test_support::write;
+
+    #[test]
+    fn reads_manifest_and_nested_copies() {
+        let temp = tempfile::tempdir().unwrap();
+        let node_modules = temp.path().join("node_modules");
+        write(
+            &node_modules,
+            "@acme/cli/package.json",
+            r#"{
+                "name": "@acme/cli",
+                "version": "2.0.0",
+                "description": "Acme command line",
+                "license": { "type": "MIT" },
+                "main": "lib/index.js",
+                "typings": "lib/index.d.ts",
+                "exports": { ".": "./lib/index.js" },
+                "bin": "bin/cli.js",
+                "dependencies": { "semver": "^7.0.0" },
+                "peerDependencies": { "typescript": ">=5", "react": "*" },
+                "peerDependenciesMeta": { "react": { "optional": true } },
+                "engines": { "node": ">=18" }
+            }"#,
+        );
+        write(
+            &node_modules,
+            "semver/package.json",
+            r#"{ "version": "7.6.0" }"#,
+        );
+        write(
+            &node_modules,
+            "@acme/cli/node_modules/semver/package.json",
+            r#"{ "version": "6.3.1" }"#,
+        );
+        write(
+            &node_modules,
+            "legacy/node_modules/semver/package.json",
+            r#"{ "version": "5.7.2" }"#,
+        );
+
+        let info = read_package_info(&node_modules.join("@acme/cli")).unwrap();
+        assert_eq!(info.name, "@acme/cli");
+        assert_eq!(info.version, "2.0.0");
+        assert_eq!(info.license.as_deref(), Some("MIT"));
+        assert_eq!(info.types.as_deref(), Some("lib/index.d.ts"));
+        assert_eq!(
+            info.exports,
+            Some(serde_json::json!({ ".": "./lib/index.js" }))
+        );
+        assert_eq!(info.bin.get("cli").map(String::as_str), Some("bin/cli.js"));
+        assert_eq!(info.peer_dependencies.len(), 2);
+        assert_eq!(info.optional_peer_dependencies, vec!["react"]);
+        assert_eq!(info.engines.get("node").map(String::as_str), Some(">=18"));
+        assert_eq!(info.installed_versions.len(), 1);
+
+        // Resolved from inside the pnpm store or a nested copy, the copies of
+        // the whole project are still reported.
+        write(
+            &node_modules,
+            ".pnpm/semver@7.5.4/node_modules/semver/package.json",
+            r#"{ "name": "semver", "version": "7.5.4" }"#,
+        );
+        for nested in [
+            ".pnpm/semver@7.5.4/node_modules/semver",
+            "legacy/node_modules/semver",
+        ] {
+            let info = read_package_info(&node_modules.join(nested)).unwrap();
+            assert_eq!(info.installed_versions.len(), 4, "{nested}");
+        }
+
+        let mut versions: Vec<String> = installed_copies(&node_modules, "semver")
+            .into_iter()
+            .map(|copy| copy.version)
+            .collect();
+        versions.sort();
+        assert_eq!(versions, vec!["5.7.2", "6.3.1", "7.5.4", "7.6.0"]);
+    }
+}
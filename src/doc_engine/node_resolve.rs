@@ -0,0 +1,486 @@
+//! Resolution of module specifiers inside an installed Node.js package.
+//!
+//! Follows Node's package resolution: the `exports` map of `package.json`
+//! (subpath keys, `./*` patterns and nested `import`/`require`/`types`/
+//! `default` conditions, matched in key order) takes precedence. Packages
+//! without `exports` resolve through `types`/`typings`, `typesVersions`,
+//! `module` and `main`, and finally by file path with extension and
+//! `index.*` probing.
+
+use serde_json::Value;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tree_sitter::Language;
+
+/// Conditions of an ES module `import`.
+pub const IMPORT_CONDITIONS: &[&str] = &["import", "module", "node"];
+/// Conditions of a CommonJS `require`.
+pub const REQUIRE_CONDITIONS: &[&str] = &["require", "node"];
+/// Conditions used by TypeScript to find declaration files.
+pub const TYPES_CONDITIONS: &[&str] = &["types", "import", "node"];
+
+const SOURCE_EXTENSIONS: &[&str] = &["js", "ts", "mjs", "cjs", "mts", "cts", "jsx", "tsx"];
+const DECLARATION_EXTENSIONS: &[&str] = &["d.ts", "d.mts", "d.cts"];
+
+/// A module file a specifier resolved to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResolvedModule {
+    pub path: PathBuf,
+    /// Resolved to the `index.*` file of a directory
+    pub directory_index: bool,
+}
+
+/// Tree-sitter grammar of a JavaScript or TypeScript file, keyed by
+/// extension.
+pub fn source_language(path: &Path) -> Option<Language> {
+    match source_extension(path)? {
+        "js" | "mjs" | "cjs" | "jsx" => Some(tree_sitter_javascript::LANGUAGE.into()),
+        "ts" | "mts" | "cts" => Some(tree_sitter_typescript::LANGUAGE_TYPESCRIPT.into()),
+        "tsx" => Some(tree_sitter_typescript::LANGUAGE_TSX.into()),
+        _ => None,
+    }
+}
+
+/// Language name of a JavaScript or TypeScript file: `"javascript"` or
+/// `"typescript"`.
+pub fn source_language_name(path: &Path) -> Option<&'static str> {
+    match source_extension(path)? {
+        "js" | "mjs" | "cjs" | "jsx" => Some("javascript"),
+        "ts" | "mts" | "cts" | "tsx" => Some("typescript"),
+        _ => None,
+    }
+}
+
+fn source_extension(path: &Path) -> Option<&str> {
+    path.file_name()?.to_str()?.rsplit('.').next()
+}
+
+/// Subpath of `specifier` within package `package_name`: `"."` for the
+/// package itself, `"./sub"` for `pkg/sub`, `./sub` or `sub`.
+pub fn package_subpath(package_name: &str, specifier: &str) -> String {
+    if specifier == package_name || specifier == "." || specifier.is_empty() {
+        return ".".to_string();
+    }
+    let rest = specifier
+        .strip_prefix(package_name)
+        .and_then(|rest| rest.strip_prefix('/'))
+        .unwrap_or_else(|| specifier.trim_start_matches("./").trim_start_matches('/'));
+    format!("./{rest}")
+}
+
+/// Resolve `specifier` (`pkg`, `pkg/sub` or a package-relative `./sub`)
+/// inside the package at `package_root` under `conditions`; `default` always
+/// applies.
+///
+/// Subpaths the `exports` map excludes (`null` targets) do not resolve.
+/// Package-relative specifiers that `exports` doesn't mention still fall back
+/// to a file lookup, so internal modules can be inspected.
+pub fn resolve_package_module(
+    package_root: &Path,
+    package_name: &str,
+    specifier: &str,
+    conditions: &[&str],
+) -> Option<ResolvedModule> {
+    let manifest = read_manifest(package_root);
+    let subpath = package_subpath(package_name, specifier);
+
+    if let Some(exports) = manifest.get("exports").filter(|exports| !exports.is_null()) {
+        match exports_resolve(package_root, &subpath, exports, conditions) {
+            Some(ExportsMatch::Target(path)) => {
+                return Some(ResolvedModule {
+                    path,
+                    directory_index: false,
+                })
+            }
+            Some(ExportsMatch::Excluded) => return None,
+            None if !specifier.starts_with("./") => return None,
+            None => {}
+        }
+    }
+
+    let wants_types = conditions.contains(&"types");
+    if subpath == "." {
+        let mut fields = Vec::new();
+        if wants_types {
+            fields.extend(["types", "typings"]);
+        }
+        if conditions.contains(&"import") || conditions.contains(&"module") {
+            fields.push("module");
+        }
+        fields.push("main");
+        for field in fields {
+            let Some(entry) = manifest.get(field).and_then(Value::as_str) else {
+                continue;
+            };
+            if let Some(resolved) = resolve_file(package_root, entry, wants_types) {
+                return Some(resolved);
+            }
+        }
+        return resolve_file(package_root, "index", wants_types);
+    }
+
+    let relative = subpath.trim_start_matches("./");
+    if wants_types {
+        if let Some(resolved) = types_versions_resolve(package_root, &manifest, relative) {
+            return Some(resolved);
+        }
+    }
+    resolve_file(package_root, relative, wants_types)
+}
+
+fn read_manifest(package_root: &Path) -> Value {
+    fs::read_to_string(package_root.join("package.json"))
+        .ok()
+        .and_then(|manifest| serde_json::from_str(&manifest).ok())
+        .unwrap_or(Value::Null)
+}
+
+enum ExportsMatch {
+    Target(PathBuf),
+    /// Matched a key whose target is `null` or has no applicable condition
+    Excluded,
+}
+
+/// `PACKAGE_EXPORTS_RESOLVE`: match `subpath` against the keys of the
+/// `exports` map. `None` when no key matches.
+fn exports_resolve(
+    package_root: &Path,
+    subpath: &str,
+    exports: &Value,
+    conditions: &[&str],
+) -> Option<ExportsMatch> {
+    // A string, array or conditions object is shorthand for `{ ".": ... }`
+    let subpath_keys = exports
+        .as_object()
+        .filter(|map| map.keys().next().is_some_and(|key| key.starts_with('.')));
+    let Some(map) = subpath_keys else {
+        return (subpath == ".").then(|| {
+            resolve_target(package_root, exports, "", conditions)
+                .map_or(ExportsMatch::Excluded, ExportsMatch::Target)
+        });
+    };
+
+    let (target, pattern_match) = match map.get(subpath).filter(|_| !subpath.contains('*')) {
+        Some(target) => (target, String::new()),
+        None => best_pattern_match(map, subpath)?,
+    };
+    Some(
+        resolve_target(package_root, target, &pattern_match, conditions)
+            .map_or(ExportsMatch::Excluded, ExportsMatch::Target),
+    )
+}
+
+/// The `./*` pattern (or legacy `./dir/` folder mapping) matching `subpath`
+/// with the longest prefix, and the text its `*` stands for.
+fn best_pattern_match<'a>(
+    map: &'a serde_json::Map<String, Value>,
+    subpath: &str,
+) -> Option<(&'a Value, String)> {
+    let mut best: Option<(&str, &Value, String)> = None;
+    for (key, target) in map {
+        let candidate = match key.split_once('*') {
+            Some((prefix, suffix)) if !suffix.contains('*') => {
+                let matches = subpath.len() >= key.len()
+                    && subpath.starts_with(prefix)
+                    && subpath.ends_with(suffix);
+                matches.then(|| subpath[prefix.len()..subpath.len() - suffix.len()].to_string())
+            }
+            None if key.ends_with('/') => subpath
+                .strip_prefix(key.as_str())
+                .map(|rest| rest.to_string()),
+            _ => None,
+        };
+        let Some(pattern_match) = candidate else {
+            continue;
+        };
+        if best
+            .as_ref()
+            .is_none_or(|(best_key, _, _)| pattern_key_compare(key, best_key))
+        {
+            best = Some((key, target, pattern_match));
+        }
+    }
+    best.map(|(_, target, pattern_match)| (target, pattern_match))
+}
+
+/// `PATTERN_KEY_COMPARE`: whether `key` is more specific than `best`.
+fn pattern_key_compare(key: &str, best: &str) -> bool {
+    let base_length = |key: &str| key.find('*').map_or(key.len(), |star| star + 1);
+    match base_length(key).cmp(&base_length(best)) {
+        std::cmp::Ordering::Greater => true,
+        std::cmp::Ordering::Less => false,
+        std::cmp::Ordering::Equal => key.len() > best.len(),
+    }
+}
+
+/// `PACKAGE_TARGET_RESOLVE` for one target: a `./` path (with `*`
+/// substituted), an array of fallbacks or a conditions object.
+fn resolve_target(
+    package_root: &Path,
+    target: &Value,
+    pattern_match: &str,
+    conditions: &[&str],
+) -> Option<PathBuf> {
+    match target {
+        Value::String(target) => {
+            let relative = target.strip_prefix("./")?;
+            let relative = if target.ends_with('/') {
+                format!("{relative}{pattern_match}")
+            } else {
+                relative.replace('*', pattern_match)
+            };
+            if relative
+                .split('/')
+                .any(|segment| segment == ".." || segment == "node_modules")
+            {
+                return None;
+            }
+            let path = package_root.join(relative);
+            path.is_file().then_some(path)
+        }
+        Value::Array(targets) => targets
+            .iter()
+            .find_map(|target| resolve_target(package_root, target, pattern_match, conditions)),
+        Value::Object(by_condition) => by_condition
+            .iter()
+            .filter(|(condition, _)| {
+                condition.as_str() == "default" || conditions.contains(&condition.as_str())
+            })
+            .find_map(|(_, target)| {
+                resolve_target(package_root, target, pattern_match, conditions)
+            }),
+        _ => None,
+    }
+}
+
+/// Map a subpath through `typesVersions`. TypeScript picks the first range
+/// matching its own version; without one to compare, the first is used.
+fn types_versions_resolve(
+    package_root: &Path,
+    manifest: &Value,
+    relative: &str,
+) -> Option<ResolvedModule> {
+    let paths = manifest
+        .get("typesVersions")?
+        .as_object()?
+        .values()
+        .next()?
+        .as_object()?;
+    let mut best: Option<(&str, &Value, &str)> = None;
+    for (key, targets) in paths {
+        let captured = match key.split_once('*') {
+            Some((prefix, suffix)) => relative
+                .strip_prefix(prefix)
+                .and_then(|rest| rest.strip_suffix(suffix)),
+            None => (key == relative).then_some(""),
+        };
+        let Some(captured) = captured else {
+            continue;
+        };
+        if best
+            .as_ref()
+            .is_none_or(|(best_key, _, _)| pattern_key_compare(key, best_key))
+        {
+            best = Some((key, targets, captured));
+        }
+    }
+    let (_, targets, captured) = best?;
+    let targets = match targets {
+        Value::Array(targets) => targets.iter().collect(),
+        target => vec![target],
+    };
+    targets
+        .into_iter()
+        .filter_map(Value::as_str)
+        .find_map(|target| resolve_file(package_root, &target.replace('*', captured), true))
+}
+
+/// Legacy CommonJS file resolution: the exact file, then with a source (or
+/// declaration) extension, then a directory's `package.json` `main` or
+/// `index.*`.
+fn resolve_file(package_root: &Path, relative: &str, wants_types: bool) -> Option<ResolvedModule> {
+    let base = package_root.join(relative.trim_start_matches("./").trim_start_matches('/'));
+    let mut extensions: Vec<&str> = Vec::new();
+    if wants_types {
+        extensions.extend(DECLARATION_EXTENSIONS);
+    }
+    extensions.extend(SOURCE_EXTENSIONS);
+
+    let with_extension = |base: &Path| {
+        if wants_types {
+            // `lib/index.js` has its declarations next to it in `lib/index.d.ts`
+            let stem = base.with_extension("");
+            if let Some(declaration) = DECLARATION_EXTENSIONS
+                .iter()
+                .map(|ext| stem.with_extension(ext))
+                .find(|candidate| candidate.is_file())
+            {
+                return Some(declaration);
+            }
+        }
+        if base.is_file() {
+            return Some(base.to_path_buf());
+        }
+        extensions.iter().find_map(|ext| {
+            let mut candidate = base.as_os_str().to_owned();
+            candidate.push(".");
+            candidate.push(ext);
+            let candidate = PathBuf::from(candidate);
+            candidate.is_file().then_some(candidate)
+        })
+    };
+
+    if let Some(path) = with_extension(&base) {
+        return Some(ResolvedModule {
+            path,
+            directory_index: false,
+        });
+    }
+    if !base.is_dir() {
+        return None;
+    }
+    let nested_main = read_manifest(&base)
+        .get("main")
+        .and_then(Value::as_str)
+        .and_then(|main| with_extension(&base.join(main)));
+    nested_main
+        .or_else(|| with_extension(&base.join("index")))
+        .map(|path| ResolvedModule {
+            path,
+            directory_index: true,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::doc_engine::test_support::write;
+
+    fn resolve(root: &Path, specifier: &str, conditions: &[&str]) -> Option<String> {
+        resolve_package_module(root, "pkg", specifier, conditions).map(|resolved| {
+            resolved
+                .path
+                .strip_prefix(root)
+                .unwrap()
+                .to_string_lossy()
+                .replace('\\', "/")
+        })
+    }
+
+    #[test]
+    fn resolves_exports_conditions_and_patterns() {
+        let temp = tempfile::tempdir().unwrap();
+        let root = temp.path();
+        write(
+            root,
+            "package.json",
+            r#"{
+                "name": "pkg",
+                "main": "legacy.js",
+                "exports": {
+                    ".": {
+                        "types": "./dist/index.d.ts",
+                        "import": "./dist/index.mjs",
+                        "require": "./dist/index.cjs"
+                    },
+                    "./utils/*": "./dist/utils/*.js",
+                    "./utils/internal/*": null,
+                    "./features/*.js": { "default": "./dist/features/*.js" },
+                    "./package.json": "./package.json"
+                }
+            }"#,
+        );
+        for file in [
+            "legacy.js",
+            "dist/index.d.ts",
+            "dist/index.mjs",
+            "dist/index.cjs",
+            "dist/utils/format.js",
+            "dist/utils/internal/secret.js",
+            "dist/features/a.js",
+            "dist/hidden.js",
+        ] {
+            write(root, file, "");
+        }
+
+        assert_eq!(
+            resolve(root, "pkg", IMPORT_CONDITIONS).as_deref(),
+            Some("dist/index.mjs")
+        );
+        assert_eq!(
+            resolve(root, "pkg", REQUIRE_CONDITIONS).as_deref(),
+            Some("dist/index.cjs")
+        );
+        assert_eq!(
+            resolve(root, "pkg", TYPES_CONDITIONS).as_deref(),
+            Some("dist/index.d.ts")
+        );
+        assert_eq!(
+            resolve(root, "pkg/utils/format", IMPORT_CONDITIONS).as_deref(),
+            Some("dist/utils/format.js")
+        );
+        assert_eq!(
+            resolve(root, "pkg/features/a.js", IMPORT_CONDITIONS).as_deref(),
+            Some("dist/features/a.js")
+        );
+        // Excluded by the more specific null pattern
+        assert_eq!(
+            resolve(root, "pkg/utils/internal/secret", IMPORT_CONDITIONS),
+            None
+        );
+        // Not exported, but reachable as a package-relative path
+        assert_eq!(resolve(root, "pkg/dist/hidden", IMPORT_CONDITIONS), None);
+        assert_eq!(
+            resolve(root, "./dist/hidden", IMPORT_CONDITIONS).as_deref(),
+            Some("dist/hidden.js")
+        );
+    }
+
+    #[test]
+    fn resolves_without_exports() {
+        let temp = tempfile::tempdir().unwrap();
+        let root = temp.path();
+        write(
+            root,
+            "package.json",
+            r#"{
+                "name": "pkg",
+                "main": "lib/index",
+                "module": "es/index.js",
+                "typesVersions": { ">=4.2": { "*": ["types/*"] } }
+            }"#,
+        );
+        for file in [
+            "lib/index.js",
+            "es/index.js",
+            "lib/parse/index.js",
+            "types/parse.d.ts",
+        ] {
+            write(root, file, "");
+        }
+
+        assert_eq!(
+            resolve(root, "pkg", REQUIRE_CONDITIONS).as_deref(),
+            Some("lib/index.js")
+        );
+        assert_eq!(
+            resolve(root, "pkg", IMPORT_CONDITIONS).as_deref(),
+            Some("es/index.js")
+        );
+        let directory =
+            resolve_package_module(root, "pkg", "pkg/lib/parse", REQUIRE_CONDITIONS).unwrap();
+        assert!(directory.directory_index);
+        assert_eq!(
+            resolve(root, "pkg/parse", TYPES_CONDITIONS).as_deref(),
+            Some("types/parse.d.ts")
+        );
+    }
+
+    #[test]
+    fn subpath_of_specifier() {
+        assert_eq!(package_subpath("@scope/pkg", "@scope/pkg"), ".");
+        assert_eq!(package_subpath("@scope/pkg", "@scope/pkg/sub"), "./sub");
+        assert_eq!(package_subpath("pkg", "./lib/a.js"), "./lib/a.js");
+        assert_eq!(package_subpath("pkg", "lib/a"), "./lib/a");
+    }
+}
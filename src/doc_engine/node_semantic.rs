@@ -0,0 +1,1048 @@
+//! Semantic search over the exports of a Node.js package.
+//!
+//! JavaScript and TypeScript sources as well as `.d.ts` typings are parsed
+//! with tree-sitter. Exported functions and classes (ES module `export`s,
+//! CommonJS `module.exports`/`exports.x` assignments and ambient
+//! declarations) become entries together with the methods of exported
+//! classes and interfaces. JSDoc comments play the role of Python docstrings
+//! and ranking uses the same BM25F scoring as the other semantic indexes.
+
+use anyhow::{anyhow, Result};
+use regex::Regex;
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    fs,
+    path::{Path, PathBuf},
+    sync::OnceLock,
+    time::SystemTime,
+};
+use tree_sitter::{Node, Parser};
+use walkdir::WalkDir;
+
+use crate::doc_engine::health::ValidationCollector;
+use crate::doc_engine::node_resolve::source_language;
+use crate::index_core::{
+    identifier_tokens, text_tokens, Bm25Corpus, Bm25Field, FieldTerms, ValidationResult,
+};
+use crate::shared_types::SemanticSearchResult;
+
+const MAX_SNIPPET_LINES: usize = 6;
+const MAX_DOC_PREVIEW_CHARS: usize = 200;
+const MAX_SIGNATURE_CHARS: usize = 300;
+/// Larger files are almost always bundled or minified builds.
+const MAX_SOURCE_BYTES: u64 = 512 * 1024;
+
+/// Directories that never hold a package's public API.
+const SKIPPED_DIRS: &[&str] = &[
+    "node_modules",
+    "test",
+    "tests",
+    "__tests__",
+    "__mocks__",
+    "example",
+    "examples",
+    "coverage",
+    "benchmark",
+    "benchmarks",
+    "fixtures",
+    "docs",
+];
+
+/// Leading output/source directories ignored when matching an implementation
+/// file with its typings (`src/client.ts` and `dist/client.d.ts`).
+const LAYOUT_DIRS: &[&str] = &[
+    "src", "dist", "lib", "build", "esm", "cjs", "commonjs", "es", "types", "typings",
+];
+
+/// Semantic index for a Node.js package.
+#[derive(Debug)]
+pub struct NodeSemanticIndex {
+    package_name: String,
+    package_root: PathBuf,
+    package_version: Option<String>,
+    /// Modification time of every indexed file, for staleness checks
+    files: BTreeMap<PathBuf, Option<SystemTime>>,
+    entries: Vec<NodeSemanticEntry>,
+    corpus: Bm25Corpus,
+}
+
+#[derive(Debug)]
+struct NodeSemanticEntry {
+    name: String,
+    qualified_path: String,
+    module_path: String,
+    kind: &'static str,
+    file_path: PathBuf,
+    line: u32,
+    doc_preview: Option<String>,
+    signature: Option<String>,
+    source_preview: Option<String>,
+    terms: FieldTerms,
+}
+
+/// An exported declaration before implementation and typings are merged.
+#[derive(Debug, Clone)]
+struct RawEntry {
+    /// `Name` or `Class.method`
+    item_path: String,
+    name: String,
+    module_path: String,
+    kind: &'static str,
+    file_path: PathBuf,
+    line: u32,
+    doc: Option<String>,
+    signature: Option<String>,
+    source_preview: Option<String>,
+    from_typings: bool,
+}
+
+impl NodeSemanticIndex {
+    /// Build the semantic index for a package by parsing its sources.
+    pub fn build(package_name: &str, package_root: &Path) -> Result<Self> {
+        let files: BTreeMap<PathBuf, Option<SystemTime>> = node_source_files(package_root)
+            .into_iter()
+            .map(|path| {
+                let modified = modified_time(&path);
+                (path, modified)
+            })
+            .collect();
+
+        let mut raw = Vec::new();
+        for path in files.keys() {
+            let Ok(source) = fs::read_to_string(path) else {
+                continue;
+            };
+            let module_path = module_path(package_name, package_root, path);
+            raw.extend(extract_entries(&source, path, &module_path));
+        }
+
+        let entries: Vec<NodeSemanticEntry> = merge_typings(raw)
+            .into_iter()
+            .map(NodeSemanticEntry::new)
+            .collect();
+        if entries.is_empty() {
+            return Err(anyhow!(
+                "No exported functions or classes discovered for package '{}' in {}",
+                package_name,
+                package_root.display()
+            ));
+        }
+
+        let corpus = Bm25Corpus::from_documents(entries.iter().map(|entry| &entry.terms));
+        Ok(Self {
+            package_name: package_name.to_string(),
+            package_root: package_root.to_path_buf(),
+            package_version: package_json_version(package_root),
+            files,
+            entries,
+            corpus,
+        })
+    }
+
+    /// Whether any source file was added, removed or modified since the
+    /// index was built.
+    pub fn is_stale(&self) -> bool {
+        let current = node_source_files(&self.package_root);
+        current.len() != self.files.len()
+            || current.iter().any(|path| {
+                self.files
+                    .get(path)
+                    .is_none_or(|modified| modified_time(path) != *modified)
+            })
+    }
+
+    /// Execute a semantic search query, returning ranked results.
+    pub fn search(&self, query: &str, limit: usize) -> Vec<SemanticSearchResult> {
+        if query.trim().is_empty() || limit == 0 {
+            return Vec::new();
+        }
+
+        self.corpus
+            .rank(
+                query,
+                self.entries.iter(),
+                |entry| &entry.terms,
+                |a, b| {
+                    a.module_path
+                        .cmp(&b.module_path)
+                        .then_with(|| a.qualified_path.cmp(&b.qualified_path))
+                },
+            )
+            .into_iter()
+            .take(limit)
+            .map(|(score, entry)| SemanticSearchResult {
+                language: "node".to_string(),
+                package: self.package_name.clone(),
+                module_path: entry.module_path.clone(),
+                item_name: entry.name.clone(),
+                qualified_path: entry.qualified_path.clone(),
+                alternate_paths: Vec::new(),
+                kind: entry.kind.to_string(),
+                file: entry.file_path.to_string_lossy().into_owned(),
+                line: entry.line,
+                score,
+                doc_preview: entry.doc_preview.clone(),
+                structured_docstring: None,
+                signature: entry.signature.clone(),
+                source_preview: entry.source_preview.clone(),
+            })
+            .collect()
+    }
+
+    /// Name of the indexed package.
+    pub fn package_name(&self) -> &str {
+        &self.package_name
+    }
+
+    /// Root directory of the indexed package.
+    pub fn package_root(&self) -> &Path {
+        &self.package_root
+    }
+
+    /// `version` from the package's `package.json`, when present.
+    pub fn package_version(&self) -> Option<&str> {
+        self.package_version.as_deref()
+    }
+
+    /// Number of indexed items.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the index holds no items.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Rough in-memory size of the index in bytes.
+    pub fn approximate_size_bytes(&self) -> u64 {
+        let entry_bytes: usize = self
+            .entries
+            .iter()
+            .map(|entry| {
+                entry.name.len()
+                    + entry.qualified_path.len()
+                    + entry.module_path.len()
+                    + entry.file_path.as_os_str().len()
+                    + entry.doc_preview.as_ref().map_or(0, String::len)
+                    + entry.signature.as_ref().map_or(0, String::len)
+                    + entry.source_preview.as_ref().map_or(0, String::len)
+                    + entry.terms.approximate_size_bytes()
+            })
+            .sum();
+        (entry_bytes + self.corpus.approximate_size_bytes()) as u64
+    }
+
+    /// Validate indexed entries.
+    ///
+    /// Entries whose source file disappeared count as broken links and
+    /// duplicate qualified paths are reported as warnings.
+    pub fn validate(&self) -> ValidationResult {
+        let mut collector = ValidationCollector::default();
+        let mut seen = HashSet::new();
+
+        for entry in &self.entries {
+            collector.stats.total_checked += 1;
+            let mut valid = true;
+
+            if !entry.file_path.is_file() {
+                collector.error(format!(
+                    "Source file for '{}' no longer exists: {}",
+                    entry.qualified_path,
+                    entry.file_path.display()
+                ));
+                collector.stats.broken_links += 1;
+                valid = false;
+            }
+            if entry.doc_preview.is_none() {
+                collector.stats.missing_docs += 1;
+            }
+            if valid && !seen.insert(entry.qualified_path.as_str()) {
+                collector.warning(format!("Duplicate export '{}'", entry.qualified_path));
+            }
+            if !valid {
+                collector.stats.invalid_items += 1;
+            }
+        }
+
+        collector.finish()
+    }
+}
+
+/// Count JavaScript and TypeScript files under `root` modified after `since`.
+pub fn files_modified_since(root: &Path, since: SystemTime) -> usize {
+    node_source_files(root)
+        .iter()
+        .filter_map(|path| modified_time(path))
+        .filter(|modified| *modified > since)
+        .count()
+}
+
+impl NodeSemanticEntry {
+    fn new(raw: RawEntry) -> Self {
+        let mut terms = FieldTerms::default();
+        terms.add(Bm25Field::Name, identifier_tokens(&raw.name));
+        let container = raw
+            .item_path
+            .strip_suffix(raw.name.as_str())
+            .unwrap_or_default();
+        let path_tokens = raw
+            .module_path
+            .split(['/', '-', '@'])
+            .chain(std::iter::once(container))
+            .flat_map(identifier_tokens)
+            .collect();
+        terms.add(Bm25Field::Path, path_tokens);
+        if let Some(signature) = &raw.signature {
+            terms.add(Bm25Field::Signature, text_tokens(signature));
+        }
+        if let Some(doc) = &raw.doc {
+            terms.add(Bm25Field::Doc, text_tokens(&strip_jsdoc_tags(doc)));
+        }
+
+        Self {
+            doc_preview: raw.doc.as_deref().map(doc_preview),
+            qualified_path: format!("{}#{}", raw.module_path, raw.item_path),
+            name: raw.name,
+            module_path: raw.module_path,
+            kind: raw.kind,
+            file_path: raw.file_path,
+            line: raw.line,
+            signature: raw.signature,
+            source_preview: raw.source_preview,
+            terms,
+        }
+    }
+}
+
+/// Combine each implementation with its typings declaration.
+///
+/// The implementation keeps its location and source preview; the typings
+/// contribute their (typed) signature and, when the implementation is
+/// undocumented, their JSDoc. Declarations without an implementation (a
+/// typings-only package) are kept as they are.
+fn merge_typings(raw: Vec<RawEntry>) -> Vec<RawEntry> {
+    let key = |entry: &RawEntry| (layout_neutral(&entry.module_path), entry.item_path.clone());
+    let mut typings: HashMap<(String, String), RawEntry> = HashMap::new();
+    let mut implementations = Vec::new();
+    for entry in raw {
+        if entry.from_typings {
+            typings.entry(key(&entry)).or_insert(entry);
+        } else {
+            implementations.push(entry);
+        }
+    }
+
+    let mut seen = HashSet::new();
+    let mut merged: Vec<RawEntry> = implementations
+        .into_iter()
+        .filter(|entry| seen.insert(key(entry)))
+        .map(|mut entry| {
+            if let Some(declared) = typings.remove(&key(&entry)) {
+                entry.signature = declared.signature.or(entry.signature);
+                entry.doc = entry.doc.or(declared.doc);
+            }
+            entry
+        })
+        .collect();
+    let mut declarations: Vec<RawEntry> = typings.into_values().collect();
+    declarations.sort_by(|a, b| {
+        a.file_path
+            .cmp(&b.file_path)
+            .then_with(|| a.line.cmp(&b.line))
+    });
+    merged.extend(declarations);
+    merged
+}
+
+/// Module path with build and source layout directories removed.
+fn layout_neutral(module_path: &str) -> String {
+    module_path
+        .split('/')
+        .filter(|segment| !LAYOUT_DIRS.contains(segment))
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+pub(crate) fn node_source_files(root: &Path) -> Vec<PathBuf> {
+    WalkDir::new(root)
+        .follow_links(true)
+        .into_iter()
+        .filter_entry(|entry| {
+            let name = entry.file_name().to_string_lossy();
+            entry.depth() == 0
+                || !(name.starts_with('.')
+                    || entry.file_type().is_dir() && SKIPPED_DIRS.contains(&name.as_ref()))
+        })
+        .filter_map(|e| e.ok())
+        .filter(|entry| entry.file_type().is_file())
+        .filter(|entry| {
+            let name = entry.file_name().to_string_lossy();
+            source_language(entry.path()).is_some()
+                && !name.contains(".min.")
+                && !name.contains(".test.")
+                && !name.contains(".spec.")
+                && entry
+                    .metadata()
+                    .is_ok_and(|meta| meta.len() <= MAX_SOURCE_BYTES)
+        })
+        .map(|entry| entry.into_path())
+        .collect()
+}
+
+fn modified_time(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).ok()?.modified().ok()
+}
+
+pub(crate) fn is_typings_file(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| {
+            name.ends_with(".d.ts") || name.ends_with(".d.mts") || name.ends_with(".d.cts")
+        })
+}
+
+/// Import-style module path of a file: `pkg/lib/client` for
+/// `lib/client.js`, with `index` files standing for their directory.
+fn module_path(package_name: &str, package_root: &Path, file: &Path) -> String {
+    let relative = file.strip_prefix(package_root).unwrap_or(file);
+    let mut segments: Vec<String> = relative
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy().into_owned())
+        .collect();
+    if let Some(last) = segments.last_mut() {
+        let stem = last.split('.').next().unwrap_or_default().to_string();
+        *last = stem;
+    }
+    if segments.last().is_some_and(|s| s == "index") {
+        segments.pop();
+    }
+    std::iter::once(package_name.to_string())
+        .chain(segments)
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+fn package_json_version(package_root: &Path) -> Option<String> {
+    let manifest = fs::read_to_string(package_root.join("package.json")).ok()?;
+    let json: serde_json::Value = serde_json::from_str(&manifest).ok()?;
+    json.get("version")?.as_str().map(str::to_string)
+}
+
+/// Names a module exports without declaring them in an `export` statement:
+/// `export { a, b as c }`, `export default a`, TypeScript's `export = a` and
+/// CommonJS `module.exports = a` / `module.exports = { a }` /
+/// `exports.c = a`. Maps local names to the names they are exported as.
+fn exported_bindings(root: Node, source: &[u8]) -> HashMap<String, String> {
+    let mut bindings = HashMap::new();
+    let text = |node: Node| node.utf8_text(source).unwrap_or_default().to_string();
+
+    for statement in root.named_children(&mut root.walk()) {
+        match statement.kind() {
+            "export_statement" if statement.child_by_field_name("source").is_none() => {
+                if let Some(value) = statement
+                    .child_by_field_name("value")
+                    .filter(|value| value.kind() == "identifier")
+                {
+                    bindings.insert(text(value), text(value));
+                }
+                for child in statement.named_children(&mut statement.walk()) {
+                    match child.kind() {
+                        "export_clause" => {
+                            for spec in child.named_children(&mut child.walk()) {
+                                let Some(local) = spec.child_by_field_name("name") else {
+                                    continue;
+                                };
+                                let exported = spec.child_by_field_name("alias").unwrap_or(local);
+                                bindings.insert(text(local), text(exported));
+                            }
+                        }
+                        // `export = Client;`
+                        "identifier" if statement.child_by_field_name("value").is_none() => {
+                            bindings.insert(text(child), text(child));
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            "expression_statement" => {
+                let Some(assignment) = statement
+                    .named_child(0)
+                    .filter(|node| node.kind() == "assignment_expression")
+                else {
+                    continue;
+                };
+                let (Some(left), Some(right)) = (
+                    assignment.child_by_field_name("left"),
+                    assignment.child_by_field_name("right"),
+                ) else {
+                    continue;
+                };
+                let target = text(left);
+                if target == "module.exports" {
+                    match right.kind() {
+                        "identifier" => {
+                            bindings.insert(text(right), text(right));
+                        }
+                        "object" => {
+                            for property in right.named_children(&mut right.walk()) {
+                                match property.kind() {
+                                    "shorthand_property_identifier" => {
+                                        bindings.insert(text(property), text(property));
+                                    }
+                                    "pair" => {
+                                        let key = property.child_by_field_name("key");
+                                        let value = property
+                                            .child_by_field_name("value")
+                                            .filter(|value| value.kind() == "identifier");
+                                        if let (Some(key), Some(value)) = (key, value) {
+                                            bindings.insert(text(value), text(key));
+                                        }
+                                    }
+                                    _ => {}
+                                }
+                            }
+                        }
+                        _ => {}
+                    }
+                } else if let Some(name) = commonjs_export_name(&target) {
+                    if right.kind() == "identifier" {
+                        bindings.insert(text(right), name.to_string());
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    bindings
+}
+
+/// `foo` for `exports.foo` and `module.exports.foo`.
+fn commonjs_export_name(target: &str) -> Option<&str> {
+    target
+        .strip_prefix("module.exports.")
+        .or_else(|| target.strip_prefix("exports."))
+        .filter(|name| !name.contains('.'))
+}
+
+struct Extractor<'a> {
+    source: &'a str,
+    lines: Vec<&'a str>,
+    file_path: &'a Path,
+    module_path: &'a str,
+    from_typings: bool,
+    bindings: HashMap<String, String>,
+    entries: Vec<RawEntry>,
+}
+
+fn extract_entries(source: &str, file_path: &Path, module_path: &str) -> Vec<RawEntry> {
+    let Some(language) = source_language(file_path) else {
+        return Vec::new();
+    };
+    let mut parser = Parser::new();
+    if parser.set_language(&language).is_err() {
+        return Vec::new();
+    }
+    let Some(tree) = parser.parse(source, None) else {
+        return Vec::new();
+    };
+    let root = tree.root_node();
+
+    let from_typings = is_typings_file(file_path);
+    let mut extractor = Extractor {
+        source,
+        lines: source.lines().collect(),
+        file_path,
+        module_path,
+        from_typings,
+        bindings: exported_bindings(root, source.as_bytes()),
+        entries: Vec::new(),
+    };
+    // A declaration file without any `export` is a global script whose
+    // declarations are all visible to consumers.
+    let global_typings = from_typings
+        && !root
+            .named_children(&mut root.walk())
+            .any(|statement| statement.kind() == "export_statement");
+    extractor.visit_statements(root, global_typings);
+    extractor.entries
+}
+
+impl Extractor<'_> {
+    fn text(&self, node: Node) -> &str {
+        node.utf8_text(self.source.as_bytes()).unwrap_or_default()
+    }
+
+    /// Walk the statements of a program or namespace body.
+    fn visit_statements(&mut self, parent: Node, all_exported: bool) {
+        for statement in parent.named_children(&mut parent.walk()) {
+            match statement.kind() {
+                "export_statement" => {
+                    if let Some(declaration) = statement.child_by_field_name("declaration") {
+                        self.visit_declaration(declaration, statement, None, true);
+                    } else if let Some(value) = statement
+                        .child_by_field_name("value")
+                        .filter(|value| value.kind() != "identifier")
+                    {
+                        // `export default function () {}`
+                        self.visit_declaration(value, statement, Some("default"), true);
+                    }
+                }
+                "expression_statement" => self.visit_commonjs_assignment(statement),
+                _ => self.visit_declaration(statement, statement, None, all_exported),
+            }
+        }
+    }
+
+    /// Index a declaration when it is exported, either directly (`exported`)
+    /// or through a binding collected by [`exported_bindings`].
+    fn visit_declaration(
+        &mut self,
+        node: Node,
+        anchor: Node,
+        default_name: Option<&str>,
+        exported: bool,
+    ) {
+        match node.kind() {
+            "function_declaration"
+            | "generator_function_declaration"
+            | "function_signature"
+            | "function_expression"
+            | "function"
+            | "arrow_function" => {
+                if let Some(name) = self.exported_name(node, default_name, exported) {
+                    self.push(node, anchor, &name, name.clone(), "function");
+                }
+            }
+            "class_declaration" | "abstract_class_declaration" | "class" => {
+                if let Some(name) = self.exported_name(node, default_name, exported) {
+                    self.push(node, anchor, &name, name.clone(), "class");
+                    self.visit_members(node, &name);
+                }
+            }
+            "interface_declaration" if exported => {
+                if let Some(name) = self.exported_name(node, None, true) {
+                    self.push(node, anchor, &name, name.clone(), "interface");
+                    self.visit_members(node, &name);
+                }
+            }
+            "lexical_declaration" | "variable_declaration" => {
+                for declarator in node.named_children(&mut node.walk()) {
+                    let value = declarator.child_by_field_name("value");
+                    let Some(value) = value.filter(|value| {
+                        matches!(
+                            value.kind(),
+                            "arrow_function" | "function_expression" | "function" | "class"
+                        )
+                    }) else {
+                        continue;
+                    };
+                    let Some(name) = self.exported_name(declarator, None, exported) else {
+                        continue;
+                    };
+                    let kind = if value.kind() == "class" {
+                        "class"
+                    } else {
+                        "function"
+                    };
+                    self.push(value, anchor, &name, name.clone(), kind);
+                    if kind == "class" {
+                        self.visit_members(value, &name);
+                    }
+                }
+            }
+            // `declare function f(): void;`, `declare module "x" { ... }`
+            "ambient_declaration" => {
+                for child in node.named_children(&mut node.walk()) {
+                    match child.kind() {
+                        "module" | "internal_module" => {
+                            if let Some(body) = child.child_by_field_name("body") {
+                                self.visit_statements(body, true);
+                            }
+                        }
+                        _ => self.visit_declaration(child, anchor, default_name, exported),
+                    }
+                }
+            }
+            "internal_module" | "module" if exported => {
+                if let Some(body) = node.child_by_field_name("body") {
+                    self.visit_statements(body, true);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Public name of a declaration, or `None` when it is not exported.
+    fn exported_name(
+        &self,
+        node: Node,
+        default_name: Option<&str>,
+        exported: bool,
+    ) -> Option<String> {
+        let local = node
+            .child_by_field_name("name")
+            .map(|name| self.text(name).to_string());
+        if let Some(public) = local.as_ref().and_then(|name| self.bindings.get(name)) {
+            return Some(public.clone());
+        }
+        if !exported {
+            return None;
+        }
+        local
+            .or_else(|| default_name.map(str::to_string))
+            .filter(|name| !name.is_empty())
+    }
+
+    /// `exports.parse = function (...) {}` and
+    /// `module.exports = class Client {}` style exports.
+    fn visit_commonjs_assignment(&mut self, statement: Node) {
+        let Some(assignment) = statement
+            .named_child(0)
+            .filter(|node| node.kind() == "assignment_expression")
+        else {
+            return;
+        };
+        let (Some(left), Some(right)) = (
+            assignment.child_by_field_name("left"),
+            assignment.child_by_field_name("right"),
+        ) else {
+            return;
+        };
+        let target = self.text(left).to_string();
+        let name = if target == "module.exports" {
+            right
+                .child_by_field_name("name")
+                .map(|name| self.text(name).to_string())
+                .unwrap_or_else(|| "default".to_string())
+        } else if let Some(name) = commonjs_export_name(&target) {
+            name.to_string()
+        } else {
+            return;
+        };
+
+        match right.kind() {
+            "function_expression" | "function" | "arrow_function" => {
+                self.push(right, statement, &name, name.clone(), "function");
+            }
+            "class" => {
+                self.push(right, statement, &name, name.clone(), "class");
+                self.visit_members(right, &name);
+            }
+            _ => {}
+        }
+    }
+
+    /// Methods of a class or interface; private members are skipped.
+    fn visit_members(&mut self, container: Node, container_name: &str) {
+        let Some(body) = container.child_by_field_name("body") else {
+            return;
+        };
+        for member in body.named_children(&mut body.walk()) {
+            if !matches!(
+                member.kind(),
+                "method_definition" | "method_signature" | "abstract_method_signature"
+            ) {
+                continue;
+            }
+            let Some(name_node) = member.child_by_field_name("name") else {
+                continue;
+            };
+            let private = name_node.kind() == "private_property_identifier"
+                || member.named_children(&mut member.walk()).any(|child| {
+                    child.kind() == "accessibility_modifier" && self.text(child) == "private"
+                });
+            let name = self.text(name_node).to_string();
+            if private || name == "constructor" {
+                continue;
+            }
+            let item_path = format!("{container_name}.{name}");
+            self.push(member, member, &name, item_path, "method");
+        }
+    }
+
+    fn push(
+        &mut self,
+        node: Node,
+        anchor: Node,
+        name: &str,
+        item_path: String,
+        kind: &'static str,
+    ) {
+        // Overloads share one entry
+        if self
+            .entries
+            .iter()
+            .any(|entry| entry.item_path == item_path)
+        {
+            return;
+        }
+        let start_row = anchor.start_position().row;
+        self.entries.push(RawEntry {
+            item_path,
+            name: name.to_string(),
+            module_path: self.module_path.to_string(),
+            kind,
+            file_path: self.file_path.to_path_buf(),
+            line: (start_row + 1) as u32,
+            doc: self.jsdoc(anchor),
+            signature: self.signature(node, anchor),
+            source_preview: (!self.from_typings).then(|| self.snippet(anchor)).flatten(),
+            from_typings: self.from_typings,
+        });
+    }
+
+    /// The `/** ... */` comment directly preceding `anchor`.
+    fn jsdoc(&self, anchor: Node) -> Option<String> {
+        let comment = anchor
+            .prev_sibling()
+            .filter(|prev| prev.kind() == "comment")?;
+        let raw = self.text(comment);
+        if !raw.starts_with("/**") {
+            return None;
+        }
+        let cleaned = clean_jsdoc(raw);
+        (!cleaned.is_empty()).then_some(cleaned)
+    }
+
+    /// Declaration text up to the body, on a single line.
+    fn signature(&self, node: Node, anchor: Node) -> Option<String> {
+        let end = node
+            .child_by_field_name("body")
+            .map_or(node.end_byte(), |body| body.start_byte());
+        let text = self.source.get(anchor.start_byte()..end)?;
+        let mut signature = text.split_whitespace().collect::<Vec<_>>().join(" ");
+        signature = signature
+            .trim_end_matches(['{', ';', ' '])
+            .trim_end_matches("=>")
+            .trim_end()
+            .to_string();
+        if signature.chars().count() > MAX_SIGNATURE_CHARS {
+            signature = signature.chars().take(MAX_SIGNATURE_CHARS).collect();
+            signature.push('…');
+        }
+        (!signature.is_empty()).then_some(signature)
+    }
+
+    fn snippet(&self, anchor: Node) -> Option<String> {
+        let start = anchor.start_position().row;
+        let end = anchor.end_position().row.min(start + MAX_SNIPPET_LINES - 1);
+        let snippet = self.lines.get(start..=end)?.join("\n");
+        (!snippet.trim().is_empty()).then_some(snippet)
+    }
+}
+
+/// JSDoc text without the comment delimiters and leading asterisks.
+pub(crate) fn clean_jsdoc(raw: &str) -> String {
+    let inner = raw.trim().trim_start_matches("/**").trim_end_matches("*/");
+    inner
+        .lines()
+        .map(|line| {
+            let line = line.trim();
+            line.strip_prefix('*').unwrap_or(line).trim()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim()
+        .to_string()
+}
+
+/// Drop `@param`-style tag names and `{Type}` braces, keeping the prose and
+/// type names around them.
+fn strip_jsdoc_tags(doc: &str) -> String {
+    static TAGS: OnceLock<Regex> = OnceLock::new();
+    let tags = TAGS.get_or_init(|| Regex::new(r"@[A-Za-z]+").unwrap());
+    tags.replace_all(doc, " ").into_owned()
+}
+
+/// Description paragraph of a JSDoc comment, before the first tag.
+fn doc_preview(doc: &str) -> String {
+    let description = doc
+        .lines()
+        .take_while(|line| !line.starts_with('@'))
+        .collect::<Vec<_>>()
+        .join(" ");
+    let description = description.split_whitespace().collect::<Vec<_>>().join(" ");
+    let description = if description.is_empty() {
+        doc.split_whitespace().collect::<Vec<_>>().join(" ")
+    } else {
+        description
+    };
+    if description.chars().count() <= MAX_DOC_PREVIEW_CHARS {
+        return description;
+    }
+    let mut preview: String = description.chars().take(MAX_DOC_PREVIEW_CHARS).collect();
+    preview.push('…');
+    preview
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::doc_engine::test_support::write;
+    use tempfile::tempdir;
+
+    #[test]
+    fn indexes_esm_commonjs_and_typings_exports() {
+        let tmp = tempdir().unwrap();
+        let root = tmp.path().join("httpkit");
+        write(
+            &root,
+            "package.json",
+            r#"{ "name": "httpkit", "version": "2.1.0", "main": "lib/index.js" }"#,
+        );
+        write(
+            &root,
+            "lib/index.js",
+            r#"
+/**
+ * Send an HTTP request and resolve with the parsed response.
+ * @param {string} url Address to fetch
+ * @returns {Promise<Response>}
+ */
+function request(url, options) {
+  return fetch(url, options);
+}
+
+function internalHelper() {}
+
+/** Retry a failing operation with exponential backoff. */
+exports.retry = function retry(operation, attempts) {
+  return operation();
+};
+
+class Client {
+  /** Close every pooled connection. */
+  close() {}
+
+  #reset() {}
+}
+
+module.exports = { request, Client };
+"#,
+        );
+        write(
+            &root,
+            "lib/index.d.ts",
+            r#"
+export declare function request(url: string, options?: RequestInit): Promise<Response>;
+export declare class Client {
+  close(): void;
+}
+"#,
+        );
+        write(
+            &root,
+            "src/cookies.ts",
+            r#"
+/** Parse a Cookie header into a name/value map. */
+export function parseCookies(header: string): Record<string, string> {
+  return {};
+}
+
+export default class CookieJar {
+  /** Store a cookie for later requests. */
+  public set(name: string, value: string): void {}
+  private evict(): void {}
+}
+"#,
+        );
+        write(
+            &root,
+            "test/index.test.js",
+            "export function shouldNotBeIndexed() {}",
+        );
+
+        let index = NodeSemanticIndex::build("httpkit", &root).unwrap();
+        assert_eq!(index.package_version(), Some("2.1.0"));
+        let paths: Vec<&str> = index
+            .entries
+            .iter()
+            .map(|entry| entry.qualified_path.as_str())
+            .collect();
+        for expected in [
+            "httpkit/lib#request",
+            "httpkit/lib#retry",
+            "httpkit/lib#Client",
+            "httpkit/lib#Client.close",
+            "httpkit/src/cookies#parseCookies",
+            "httpkit/src/cookies#CookieJar",
+            "httpkit/src/cookies#CookieJar.set",
+        ] {
+            assert!(paths.contains(&expected), "missing {expected} in {paths:?}");
+        }
+        assert!(!paths.iter().any(|p| p.contains("internalHelper")));
+        assert!(!paths
+            .iter()
+            .any(|p| p.contains("reset") || p.contains("evict")));
+        assert!(!paths.iter().any(|p| p.contains("shouldNotBeIndexed")));
+        assert_eq!(
+            paths
+                .iter()
+                .filter(|p| **p == "httpkit/lib#request")
+                .count(),
+            1,
+            "typings merge into the implementation entry"
+        );
+
+        let results = index.search("make an http request", 1);
+        assert_eq!(results[0].qualified_path, "httpkit/lib#request");
+        assert_eq!(results[0].language, "node");
+        assert!(results[0].file.ends_with("index.js"));
+        assert_eq!(
+            results[0].signature.as_deref(),
+            Some("export declare function request(url: string, options?: RequestInit): Promise<Response>")
+        );
+        assert_eq!(
+            results[0].doc_preview.as_deref(),
+            Some("Send an HTTP request and resolve with the parsed response.")
+        );
+
+        let cookies = index.search("parse cookie header", 1);
+        assert_eq!(
+            cookies[0].qualified_path,
+            "httpkit/src/cookies#parseCookies"
+        );
+
+        let backoff = index.search("retry with backoff", 1);
+        assert_eq!(backoff[0].qualified_path, "httpkit/lib#retry");
+    }
+
+    #[test]
+    fn indexes_typings_only_packages_and_detects_changes() {
+        let tmp = tempdir().unwrap();
+        let root = tmp.path().join("@types/semverish");
+        write(
+            &root,
+            "index.d.ts",
+            r#"
+declare namespace semverish {
+  /** Return the greater of two version strings. */
+  function max(a: string, b: string): string;
+}
+
+interface Range {
+  /** Test whether a version satisfies the range. */
+  test(version: string): boolean;
+}
+"#,
+        );
+
+        let index = NodeSemanticIndex::build("@types/semverish", &root).unwrap();
+        let results = index.search("does version satisfy range", 1);
+        assert_eq!(results[0].qualified_path, "@types/semverish#Range.test");
+        assert_eq!(results[0].kind, "method");
+        assert!(index
+            .search("greater version", 1)
+            .iter()
+            .any(|r| r.item_name == "max"));
+        assert!(!index.is_stale());
+
+        write(
+            &root,
+            "extra.d.ts",
+            "export declare function extra(): void;",
+        );
+        assert!(index.is_stale());
+    }
+}
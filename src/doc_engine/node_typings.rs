@@ -0,0 +1,317 @@
+//! TypeScript declarations (`.d.ts`) of installed Node.js packages.
+//!
+//! Typings are looked up next to the implementation file, through the
+//! package's `types`/`typings` fields and `types` export conditions, and in
+//! the DefinitelyTyped package `@types/<name>` when the package ships none.
+
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tree_sitter::{Language, Node, Parser};
+
+use crate::doc_engine::finder;
+use crate::doc_engine::node_resolve::{
+    package_subpath, resolve_package_module, source_language, TYPES_CONDITIONS,
+};
+use crate::doc_engine::node_semantic::{clean_jsdoc, is_typings_file};
+use crate::doc_engine::processors::traits::TypingsDeclaration;
+
+/// Declaration kinds that can be looked up by name.
+const DECLARATION_KINDS: &[&str] = &[
+    "function_signature",
+    "function_declaration",
+    "class_declaration",
+    "abstract_class_declaration",
+    "interface_declaration",
+    "type_alias_declaration",
+    "enum_declaration",
+    "variable_declarator",
+    "method_signature",
+    "method_definition",
+    "abstract_method_signature",
+    "property_signature",
+    "public_field_definition",
+    "internal_module",
+    "module",
+];
+
+/// Name of the DefinitelyTyped package for `package_name`:
+/// `@types/lodash`, or `@types/babel__core` for `@babel/core`.
+pub fn types_package_name(package_name: &str) -> String {
+    match package_name
+        .strip_prefix('@')
+        .and_then(|scoped| scoped.split_once('/'))
+    {
+        Some((scope, name)) => format!("@types/{scope}__{name}"),
+        None => format!("@types/{package_name}"),
+    }
+}
+
+/// Declaration files that may declare the items of `module_file`, most
+/// specific first.
+pub fn typings_candidates(
+    package_root: &Path,
+    package_name: &str,
+    module_file: &Path,
+    module_specifier: &str,
+    context_path: &Path,
+) -> Vec<PathBuf> {
+    if is_typings_file(module_file) {
+        return Vec::new();
+    }
+    let subpath = package_subpath(package_name, module_specifier);
+    let mut candidates = Vec::new();
+
+    // `lib/client.js` declared by `lib/client.d.ts`
+    let stem = module_file.with_extension("");
+    candidates.extend(
+        ["d.ts", "d.mts", "d.cts"]
+            .iter()
+            .map(|extension| stem.with_extension(extension)),
+    );
+    let mut typed_packages = vec![(package_root.to_path_buf(), package_name.to_string())];
+    let types_name = types_package_name(package_name);
+    if let Some(types_root) = types_package_root(package_root, &types_name, context_path) {
+        typed_packages.push((types_root, types_name));
+    }
+    for (root, name) in typed_packages {
+        for specifier in [subpath.as_str(), "."] {
+            if let Some(resolved) =
+                resolve_package_module(&root, &name, specifier, TYPES_CONDITIONS)
+            {
+                candidates.push(resolved.path);
+            }
+        }
+    }
+
+    let mut seen = HashSet::new();
+    candidates
+        .into_iter()
+        .filter(|path| is_typings_file(path) && path.is_file())
+        .filter(|path| seen.insert(path.clone()))
+        .collect()
+}
+
+/// Root of the `@types` package, in a `node_modules` directory above the
+/// package or wherever the finder locates it.
+fn types_package_root(
+    package_root: &Path,
+    types_name: &str,
+    context_path: &Path,
+) -> Option<PathBuf> {
+    package_root
+        .ancestors()
+        .filter(|dir| dir.file_name().is_some_and(|name| name == "node_modules"))
+        .map(|node_modules| node_modules.join(types_name))
+        .find(|root| root.join("package.json").is_file())
+        .or_else(|| finder::find_node_package_path(types_name, context_path).ok())
+}
+
+/// Declaration of `item_name` in the first candidate file declaring it.
+pub fn find_declaration(candidates: &[PathBuf], item_name: &str) -> Option<TypingsDeclaration> {
+    candidates.iter().find_map(|path| {
+        let language = source_language(path)?;
+        let source = fs::read_to_string(path).ok()?;
+        let (declaration, documentation) = extract_declaration(&source, &language, item_name)?;
+        Some(TypingsDeclaration {
+            file_path: path.to_string_lossy().into_owned(),
+            declaration,
+            documentation,
+        })
+    })
+}
+
+/// Text of every declaration of `item_name` (overloads included) and the
+/// TSDoc comment of the first one. `Class.member` looks the member up
+/// inside its class, interface or namespace.
+pub fn extract_declaration(
+    source: &str,
+    language: &Language,
+    item_name: &str,
+) -> Option<(String, Option<String>)> {
+    let mut parser = Parser::new();
+    parser.set_language(language).ok()?;
+    let tree = parser.parse(source, None)?;
+    let bytes = source.as_bytes();
+
+    let mut scope = tree.root_node();
+    let mut segments: Vec<&str> = item_name.split('.').collect();
+    let name = segments.pop()?;
+    for segment in segments {
+        scope = find_declarations(scope, segment, bytes)
+            .into_iter()
+            .next()?;
+    }
+    let declarations = find_declarations(scope, name, bytes);
+    let first = *declarations.first()?;
+
+    let text = declarations
+        .iter()
+        .map(|&node| anchor(node).utf8_text(bytes).unwrap_or_default())
+        .collect::<Vec<_>>()
+        .join("\n");
+    let documentation = anchor(first)
+        .prev_sibling()
+        .filter(|prev| prev.kind() == "comment")
+        .and_then(|comment| comment.utf8_text(bytes).ok())
+        .filter(|raw| raw.starts_with("/**"))
+        .map(clean_jsdoc)
+        .filter(|doc| !doc.is_empty());
+    Some((text, documentation))
+}
+
+/// Declarations named `name` below `scope`, in source order. The search
+/// stops descending at the first level that has a match, so overloads are
+/// collected but same-named members of other declarations are not.
+fn find_declarations<'a>(scope: Node<'a>, name: &str, source: &[u8]) -> Vec<Node<'a>> {
+    let mut level = vec![scope];
+    while !level.is_empty() {
+        let children: Vec<Node<'a>> = level
+            .iter()
+            .flat_map(|node| node.named_children(&mut node.walk()).collect::<Vec<_>>())
+            .collect();
+        let matches: Vec<Node<'a>> = children
+            .iter()
+            .copied()
+            .filter(|child| {
+                DECLARATION_KINDS.contains(&child.kind())
+                    && child
+                        .child_by_field_name("name")
+                        .and_then(|name_node| name_node.utf8_text(source).ok())
+                        .is_some_and(|declared| declared.trim_matches(['"', '\'']) == name)
+            })
+            .collect();
+        if !matches.is_empty() {
+            return matches;
+        }
+        level = children;
+    }
+    Vec::new()
+}
+
+/// The statement carrying a declaration's modifiers and comment:
+/// `export declare function f(): void;` for `f`.
+fn anchor(node: Node) -> Node {
+    let mut anchor = node;
+    while let Some(parent) = anchor.parent() {
+        if matches!(
+            parent.kind(),
+            "export_statement"
+                | "ambient_declaration"
+                | "lexical_declaration"
+                | "variable_declaration"
+        ) {
+            anchor = parent;
+        } else {
+            break;
+        }
+    }
+    anchor
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::doc_engine::test_support::write;
+
+    #[test]
+    fn extracts_overloads_members_and_tsdoc() {
+        let source = r#"
+/**
+ * Parse a version.
+ * @param input Version string
+ */
+export declare function parse(input: string): Version | null;
+export declare function parse(input: Version): Version;
+
+export declare class Version {
+    /** Compare with another version. */
+    compare(other: Version): -1 | 0 | 1;
+    major: number;
+}
+"#;
+        let language = source_language(Path::new("index.d.ts")).unwrap();
+        let (declaration, documentation) = extract_declaration(source, &language, "parse").unwrap();
+        assert_eq!(declaration.lines().count(), 2);
+        assert!(declaration.starts_with("export declare function parse(input: string)"));
+        assert_eq!(
+            documentation.as_deref(),
+            Some("Parse a version.\n@param input Version string")
+        );
+
+        let (member, documentation) =
+            extract_declaration(source, &language, "Version.compare").unwrap();
+        assert_eq!(member, "compare(other: Version): -1 | 0 | 1");
+        assert_eq!(
+            documentation.as_deref(),
+            Some("Compare with another version.")
+        );
+        assert!(extract_declaration(source, &language, "missing").is_none());
+    }
+
+    #[test]
+    fn finds_sibling_and_definitely_typed_declarations() {
+        let temp = tempfile::tempdir().unwrap();
+        let node_modules = temp.path().join("node_modules");
+        write(
+            &node_modules,
+            "plain/package.json",
+            r#"{ "name": "plain", "main": "lib/index.js" }"#,
+        );
+        write(
+            &node_modules,
+            "plain/lib/index.js",
+            "exports.run = function run() {};",
+        );
+        write(
+            &node_modules,
+            "@types/plain/package.json",
+            r#"{ "name": "@types/plain", "types": "index.d.ts" }"#,
+        );
+        write(
+            &node_modules,
+            "@types/plain/index.d.ts",
+            "/** Run it. */\nexport function run(): void;\n",
+        );
+        write(
+            &node_modules,
+            "typed/package.json",
+            r#"{ "name": "typed", "main": "dist/index.js" }"#,
+        );
+        write(&node_modules, "typed/dist/index.js", "");
+        write(
+            &node_modules,
+            "typed/dist/index.d.ts",
+            "export const answer: 42;\n",
+        );
+
+        let plain = node_modules.join("plain");
+        let candidates = typings_candidates(
+            &plain,
+            "plain",
+            &plain.join("lib/index.js"),
+            "lib/index.js",
+            temp.path(),
+        );
+        assert_eq!(
+            candidates,
+            vec![node_modules.join("@types/plain/index.d.ts")]
+        );
+        let declaration = find_declaration(&candidates, "run").unwrap();
+        assert_eq!(declaration.declaration, "export function run(): void;");
+        assert_eq!(declaration.documentation.as_deref(), Some("Run it."));
+
+        let typed = node_modules.join("typed");
+        let candidates = typings_candidates(
+            &typed,
+            "typed",
+            &typed.join("dist/index.js"),
+            ".",
+            temp.path(),
+        );
+        let declaration = find_declaration(&candidates, "answer").unwrap();
+        assert_eq!(declaration.declaration, "export const answer: 42;");
+
+        assert_eq!(types_package_name("@babel/core"), "@types/babel__core");
+    }
+}
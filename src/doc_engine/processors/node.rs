@@ -1,28 +1,25 @@
 use super::traits::{ImplementationContext, LanguageProcessor};
 use crate::doc_engine::finder;
-use anyhow::{Context, Result};
+use crate::doc_engine::node_analyzer::{self, NodeMethodInfo};
+use crate::doc_engine::node_resolve::{
+    resolve_package_module, source_language, source_language_name, IMPORT_CONDITIONS,
+    REQUIRE_CONDITIONS,
+};
+use crate::doc_engine::node_typings::{find_declaration, typings_candidates};
+use crate::doc_engine::python_analyzer::{SearchMode, SearchResult};
+use anyhow::{anyhow, Context, Result};
 use async_trait::async_trait;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use tokio::fs;
-use tree_sitter::Parser;
+use tree_sitter::{Language, Parser};
 
 #[derive(Debug)]
 pub struct NodeProcessor;
 
-#[derive(Clone, Copy)]
-enum JsTsLanguage {
-    JavaScript,
-    TypeScript,
-}
-
-fn extract_item_by_name(source_code: &str, item_name: &str, lang: JsTsLanguage) -> Result<String> {
-    let language = match lang {
-        JsTsLanguage::JavaScript => tree_sitter_javascript::LANGUAGE.into(),
-        JsTsLanguage::TypeScript => tree_sitter_typescript::LANGUAGE_TYPESCRIPT.into(),
-    };
+fn extract_item_by_name(source_code: &str, item_name: &str, language: &Language) -> Result<String> {
     let mut parser = Parser::new();
     parser
-        .set_language(&language)
+        .set_language(language)
         .context("Error loading tree-sitter grammar for Node.js language")?;
 
     let tree = parser.parse(source_code, None).unwrap();
@@ -85,13 +82,13 @@ fn extract_item_by_name(source_code: &str, item_name: &str, lang: JsTsLanguage)
         .context(format!("Item '{item_name}' not found in source code."))
 }
 
-fn extract_jsdoc_comment(source_code: &str, item_name: &str, lang: JsTsLanguage) -> Option<String> {
-    let language = match lang {
-        JsTsLanguage::JavaScript => tree_sitter_javascript::LANGUAGE.into(),
-        JsTsLanguage::TypeScript => tree_sitter_typescript::LANGUAGE_TYPESCRIPT.into(),
-    };
+fn extract_jsdoc_comment(
+    source_code: &str,
+    item_name: &str,
+    language: &Language,
+) -> Option<String> {
     let mut parser = Parser::new();
-    if parser.set_language(&language).is_err() {
+    if parser.set_language(language).is_err() {
         return None;
     }
 
@@ -150,6 +147,26 @@ fn extract_jsdoc_comment(source_code: &str, item_name: &str, lang: JsTsLanguage)
     find_jsdoc(tree.root_node(), item_name, source_code.as_bytes())
 }
 
+/// File for the path part of an item path: a file inside the package, or a
+/// module specifier (`pkg`, `pkg/sub`, `./sub`) resolved like an `import`.
+fn resolve_module_file(
+    package_root: &Path,
+    package_name: &str,
+    relative_path: &str,
+) -> Result<PathBuf> {
+    let file_path = package_root.join(relative_path);
+    if file_path.is_file() {
+        return Ok(file_path);
+    }
+    [IMPORT_CONDITIONS, REQUIRE_CONDITIONS]
+        .into_iter()
+        .find_map(|conditions| {
+            resolve_package_module(package_root, package_name, relative_path, conditions)
+        })
+        .map(|resolved| resolved.path)
+        .with_context(|| format!("Module '{relative_path}' not found in package '{package_name}'"))
+}
+
 #[async_trait]
 impl LanguageProcessor for NodeProcessor {
     async fn get_implementation_context(
@@ -160,29 +177,139 @@ impl LanguageProcessor for NodeProcessor {
         item_name: &str,
     ) -> Result<ImplementationContext> {
         let package_root = finder::find_node_package_path(package_name, context_path)?;
-        let file_path = package_root.join(relative_path);
+        let file_path = resolve_module_file(&package_root, package_name, relative_path)?;
         let source_code = fs::read_to_string(&file_path).await?;
 
-        let lang_type = if relative_path.ends_with(".ts") || relative_path.ends_with(".tsx") {
-            JsTsLanguage::TypeScript
-        } else {
-            JsTsLanguage::JavaScript
+        let (Some(language), Some(language_name)) = (
+            source_language(&file_path),
+            source_language_name(&file_path),
+        ) else {
+            return Err(anyhow!(
+                "Not a JavaScript or TypeScript file: {}",
+                file_path.display()
+            ));
         };
 
-        let implementation = extract_item_by_name(&source_code, item_name, lang_type)?;
-        let documentation = extract_jsdoc_comment(&source_code, item_name, lang_type);
-        let language_name = match lang_type {
-            JsTsLanguage::TypeScript => "typescript",
-            JsTsLanguage::JavaScript => "javascript",
+        let candidates = typings_candidates(
+            &package_root,
+            package_name,
+            &file_path,
+            relative_path,
+            context_path,
+        );
+        let typings = find_declaration(&candidates, item_name);
+
+        match (
+            extract_item_by_name(&source_code, item_name, &language),
+            typings,
+        ) {
+            (Ok(implementation), typings) => {
+                let documentation = extract_jsdoc_comment(&source_code, item_name, &language)
+                    .or_else(|| typings.as_ref()?.documentation.clone());
+                Ok(ImplementationContext {
+                    file_path: file_path.to_string_lossy().into_owned(),
+                    item_name: item_name.to_string(),
+                    documentation,
+                    structured_docstring: None,
+                    implementation,
+                    language: language_name.to_string(),
+                    typings,
+                })
+            }
+            // Types and interfaces exist only in the typings
+            (Err(_), Some(typings)) => Ok(ImplementationContext {
+                file_path: typings.file_path.clone(),
+                item_name: item_name.to_string(),
+                documentation: typings.documentation.clone(),
+                structured_docstring: None,
+                implementation: typings.declaration.clone(),
+                language: "typescript".to_string(),
+                typings: Some(typings),
+            }),
+            (Err(e), None) => Err(e),
+        }
+    }
+}
+
+impl NodeProcessor {
+    /// Extract the methods of a class. Methods of a JavaScript class are
+    /// completed with the parameter and return types of its declaration in
+    /// the package typings.
+    pub async fn list_class_methods(
+        &self,
+        package_name: &str,
+        context_path: &Path,
+        relative_path: &str,
+        class_name: &str,
+        include_private: bool,
+    ) -> Result<Vec<NodeMethodInfo>> {
+        let package_root = finder::find_node_package_path(package_name, context_path)?;
+        let file_path = resolve_module_file(&package_root, package_name, relative_path)?;
+        let source_code = fs::read_to_string(&file_path).await?;
+
+        // The typings scan reads files synchronously
+        let root = package_root.clone();
+        let package = package_name.to_string();
+        let file = file_path.clone();
+        let relative = relative_path.to_string();
+        let context = context_path.to_path_buf();
+        let class = class_name.to_string();
+        let typed_methods = tokio::task::spawn_blocking(move || {
+            typings_candidates(&root, &package, &file, &relative, &context)
+                .into_iter()
+                .find_map(|typings| {
+                    let source = std::fs::read_to_string(&typings).ok()?;
+                    node_analyzer::extract_class_methods(&source, &typings, &class, include_private)
+                        .ok()
+                })
+        })
+        .await?;
+        match node_analyzer::extract_class_methods(
+            &source_code,
+            &file_path,
+            class_name,
+            include_private,
+        ) {
+            Ok(mut methods) => {
+                if let Some(typed_methods) = typed_methods {
+                    node_analyzer::merge_typed_methods(&mut methods, typed_methods);
+                }
+                Ok(methods)
+            }
+            Err(e) => typed_methods.ok_or(e),
         }
-        .to_string();
-
-        Ok(ImplementationContext {
-            file_path: file_path.to_string_lossy().into_owned(),
-            item_name: item_name.to_string(),
-            documentation,
-            implementation,
-            language: language_name,
+    }
+
+    /// Extract a specific method from a class.
+    pub async fn get_class_method(
+        &self,
+        package_name: &str,
+        context_path: &Path,
+        relative_path: &str,
+        class_name: &str,
+        method_name: &str,
+    ) -> Result<NodeMethodInfo> {
+        self.list_class_methods(package_name, context_path, relative_path, class_name, true)
+            .await?
+            .into_iter()
+            .find(|method| method.name == method_name)
+            .with_context(|| format!("Method '{method_name}' not found in class '{class_name}'"))
+    }
+
+    /// Search across the JavaScript and TypeScript files of a package.
+    pub async fn search_package(
+        &self,
+        package_name: &str,
+        context_path: &Path,
+        pattern: &str,
+        search_mode: SearchMode,
+        limit: usize,
+    ) -> Result<Vec<SearchResult>> {
+        let package_root = finder::find_node_package_path(package_name, context_path)?;
+        let pattern = pattern.to_string();
+        tokio::task::spawn_blocking(move || {
+            node_analyzer::search_package(&package_root, &pattern, search_mode, limit)
         })
+        .await?
     }
 }
@@ -1,43 +1,88 @@
-use super::traits::{ImplementationContext, LanguageProcessor};
+use super::traits::{ImplementationContext, LanguageProcessor, TypingsDeclaration};
 use crate::doc_engine::finder;
-use anyhow::{Context, Result};
+use crate::doc_engine::python_docstring::parse_docstring;
+use crate::doc_engine::python_exports::locate_import_path;
+use crate::doc_engine::python_inheritance::ClassHierarchy;
+use anyhow::{anyhow, Context, Result};
 use async_trait::async_trait;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use tree_sitter::Parser;
 
 #[derive(Debug)]
 pub struct PythonProcessor;
 
-fn extract_item_by_name(source_code: &str, item_name: &str) -> Result<String> {
-    let mut parser = Parser::new();
-    parser
-        .set_language(&tree_sitter_python::LANGUAGE.into())
-        .context("Error loading tree-sitter grammar for Python")?;
+/// A module's Python source and its `.pyi` stub, when either exists.
+struct ModuleFiles {
+    package_root: PathBuf,
+    source: Option<(PathBuf, String)>,
+    stub: Option<(PathBuf, String)>,
+}
 
-    let tree = parser.parse(source_code, None).unwrap();
+impl ModuleFiles {
+    /// Read the module at `relative_path`. Compiled extension modules
+    /// (`.so`, `.pyd`) and stub-only modules are read through their stub.
+    async fn read(package_name: &str, context_path: &Path, relative_path: &str) -> Result<Self> {
+        let package_root =
+            finder::find_python_package_path_with_context(package_name, Some(context_path))?;
+        let file_path = package_root.join(relative_path);
+        let is_python = file_path.extension().is_some_and(|ext| ext == "py");
+        let source = match is_python {
+            true if file_path.is_file() => Some((
+                file_path.clone(),
+                tokio::fs::read_to_string(&file_path).await?,
+            )),
+            _ => None,
+        };
+        let stub = match finder::find_python_stub(
+            &package_root,
+            package_name,
+            relative_path,
+            Some(context_path),
+        ) {
+            Some(stub_path) if stub_path != file_path || source.is_none() => {
+                let stub = tokio::fs::read_to_string(&stub_path).await?;
+                Some((stub_path, stub))
+            }
+            _ => None,
+        };
+        if source.is_none() && stub.is_none() {
+            return Err(anyhow!(
+                "No Python source or .pyi stub found for {}",
+                file_path.display()
+            ));
+        }
+        Ok(Self {
+            package_root,
+            source,
+            stub,
+        })
+    }
+}
 
+/// Node defining `item_name`; dotted names (`Class.method`) are looked up
+/// inside their parent.
+fn find_item_node<'a>(
+    root: tree_sitter::Node<'a>,
+    item_name: &str,
+    source: &'a [u8],
+) -> Option<tree_sitter::Node<'a>> {
     fn find_node<'a>(
         node: tree_sitter::Node<'a>,
         item_name: &str,
         source: &'a [u8],
     ) -> Option<tree_sitter::Node<'a>> {
-        // Check if this node has a name field that matches
-        if let Some(name_node) = node.child_by_field_name("name") {
-            if name_node.utf8_text(source).ok() == Some(item_name) {
-                return Some(node);
-            }
-        }
-
-        // For function definitions, class definitions, etc.
-        match node.kind() {
-            "function_definition" | "class_definition" => {
-                if let Some(name_node) = node.child_by_field_name("name") {
-                    if name_node.utf8_text(source).ok() == Some(item_name) {
-                        return Some(node);
-                    }
-                }
-            }
-            _ => {}
+        // Only definitions count: `from ._speedups import Engine` names
+        // `Engine` without defining it
+        let definition = match node.kind() {
+            "function_definition" | "class_definition" => Some(node),
+            "decorated_definition" => node.child_by_field_name("definition"),
+            _ => None,
+        };
+        let defines_item = definition
+            .and_then(|definition| definition.child_by_field_name("name"))
+            .is_some_and(|name_node| name_node.utf8_text(source).ok() == Some(item_name));
+        if defines_item {
+            return Some(node);
         }
 
         // Recursively search children
@@ -49,7 +94,24 @@ fn extract_item_by_name(source_code: &str, item_name: &str) -> Result<String> {
         None
     }
 
-    find_node(tree.root_node(), item_name, source_code.as_bytes())
+    let mut node = root;
+    for segment in item_name.split('.') {
+        node = node
+            .children(&mut node.walk())
+            .find_map(|child| find_node(child, segment, source))?;
+    }
+    Some(node)
+}
+
+fn extract_item_by_name(source_code: &str, item_name: &str) -> Result<String> {
+    let mut parser = Parser::new();
+    parser
+        .set_language(&tree_sitter_python::LANGUAGE.into())
+        .context("Error loading tree-sitter grammar for Python")?;
+
+    let tree = parser.parse(source_code, None).unwrap();
+
+    find_item_node(tree.root_node(), item_name, source_code.as_bytes())
         .map(|node| node.utf8_text(source_code.as_bytes()).unwrap().to_string())
         .context(format!("Item '{item_name}' not found in source code."))
 }
@@ -114,7 +176,15 @@ fn extract_docstring(source_code: &str, item_name: &str) -> Option<String> {
         None
     }
 
-    find_docstring(tree.root_node(), item_name, source_code.as_bytes())
+    // Members are searched for within their parent
+    let (scope, item_name) = match item_name.rsplit_once('.') {
+        Some((parent, member)) => (
+            find_item_node(tree.root_node(), parent, source_code.as_bytes())?,
+            member,
+        ),
+        None => (tree.root_node(), item_name),
+    };
+    find_docstring(scope, item_name, source_code.as_bytes())
 }
 
 #[async_trait]
@@ -126,26 +196,89 @@ impl LanguageProcessor for PythonProcessor {
         relative_path: &str,
         item_name: &str,
     ) -> Result<ImplementationContext> {
-        // Pure Rust tree-sitter based extraction
-        let package_root =
-            finder::find_python_package_path_with_context(package_name, Some(context_path))?;
-        let file_path = package_root.join(relative_path);
-        let source_code = tokio::fs::read_to_string(&file_path).await?;
-        let implementation = extract_item_by_name(&source_code, item_name)?;
-        let documentation = extract_docstring(&source_code, item_name);
+        // Pure Rust tree-sitter based extraction; the stub stands in for
+        // items the source does not define, such as compiled ones
+        let files = ModuleFiles::read(package_name, context_path, relative_path).await?;
+        let from_source = files.source.as_ref().and_then(|(path, source)| {
+            let implementation = extract_item_by_name(source, item_name).ok()?;
+            Some((path, implementation, extract_docstring(source, item_name)))
+        });
+        let (file_path, implementation, documentation, typings) = match from_source {
+            Some((path, implementation, documentation)) => {
+                // The stub's declaration carries the typed signature
+                let typings = files.stub.as_ref().and_then(|(stub_path, stub)| {
+                    Some(TypingsDeclaration {
+                        file_path: stub_path.to_string_lossy().into_owned(),
+                        declaration: extract_item_by_name(stub, item_name).ok()?,
+                        documentation: extract_docstring(stub, item_name),
+                    })
+                });
+                let documentation = documentation
+                    .or_else(|| typings.as_ref().and_then(|t| t.documentation.clone()));
+                (path, implementation, documentation, typings)
+            }
+            None => {
+                let (path, stub) = files
+                    .stub
+                    .as_ref()
+                    .with_context(|| format!("Item '{item_name}' not found in source code."))?;
+                let implementation = extract_item_by_name(stub, item_name)?;
+                (
+                    path,
+                    implementation,
+                    extract_docstring(stub, item_name),
+                    None,
+                )
+            }
+        };
 
+        let structured_docstring = documentation.as_deref().map(parse_docstring);
         Ok(ImplementationContext {
             file_path: file_path.to_string_lossy().into_owned(),
             item_name: item_name.to_string(),
             documentation,
+            structured_docstring,
             implementation,
             language: "python".to_string(),
+            typings,
         })
     }
 }
 
 impl PythonProcessor {
+    /// Split an item path into the module's relative path and the item
+    /// name. Besides `path/to/file.py#Item`, dotted import paths such as
+    /// `requests.Session` or `requests.adapters.HTTPAdapter.send` are
+    /// accepted and resolved through `__init__` re-exports.
+    pub async fn resolve_item_path(
+        &self,
+        package_name: &str,
+        context_path: &Path,
+        item_path: &str,
+    ) -> Result<(String, String)> {
+        if let Some((relative_path, item_name)) = item_path.split_once('#') {
+            return Ok((relative_path.to_string(), item_name.to_string()));
+        }
+        let package_root =
+            finder::find_python_package_path_with_context(package_name, Some(context_path))?;
+        let (package_name, item_path) = (package_name.to_string(), item_path.to_string());
+        let context_path = context_path.to_path_buf();
+        let location = tokio::task::spawn_blocking(move || {
+            locate_import_path(
+                &package_root,
+                &package_name,
+                &item_path,
+                Some(&context_path),
+            )
+        })
+        .await??;
+        Ok((location.relative_path, location.item_name))
+    }
+
     /// Extract all methods from a class.
+    ///
+    /// With `include_inherited`, methods of its base classes follow in MRO
+    /// order, each tagged with its defining class.
     pub async fn list_class_methods(
         &self,
         package_name: &str,
@@ -153,15 +286,83 @@ impl PythonProcessor {
         relative_path: &str,
         class_name: &str,
         include_private: bool,
+        include_inherited: bool,
     ) -> Result<Vec<crate::doc_engine::python_analyzer::MethodInfo>> {
         use crate::doc_engine::python_analyzer;
 
-        let package_root =
-            finder::find_python_package_path_with_context(package_name, Some(context_path))?;
-        let file_path = package_root.join(relative_path);
-        let source_code = tokio::fs::read_to_string(&file_path).await?;
+        let files = ModuleFiles::read(package_name, context_path, relative_path).await?;
+        let stub_methods = files.stub.as_ref().and_then(|(_, stub)| {
+            python_analyzer::extract_class_methods(stub, class_name, include_private).ok()
+        });
+        // A class only the stub defines is read from the stub, as in
+        // `get_implementation_context`
+        let from_source = files
+            .source
+            .as_ref()
+            .and_then(|(source_path, source_code)| {
+                let methods = python_analyzer::extract_class_methods(
+                    source_code,
+                    class_name,
+                    include_private,
+                )
+                .ok()?;
+                Some((source_path.clone(), methods))
+            });
+        let (class_file, mut methods) = match from_source {
+            Some((source_path, mut methods)) => {
+                if let Some(stub_methods) = stub_methods {
+                    python_analyzer::merge_stub_methods(&mut methods, stub_methods);
+                }
+                (source_path, methods)
+            }
+            None => {
+                let methods = stub_methods.with_context(|| {
+                    format!("Class '{class_name}' not found in {relative_path} or its stub")
+                })?;
+                let (stub_path, _) = files.stub.as_ref().expect("module has a stub");
+                (stub_path.clone(), methods)
+            }
+        };
+        if !include_inherited {
+            return Ok(methods);
+        }
 
-        python_analyzer::extract_class_methods(&source_code, class_name, include_private)
+        let package_root = files.package_root;
+        let class_name = class_name.to_string();
+        tokio::task::spawn_blocking(move || {
+            let mut hierarchy = ClassHierarchy::for_package(&package_root)?;
+            hierarchy.add_inherited(&class_file, &class_name, &mut methods, include_private)?;
+            Ok(methods)
+        })
+        .await?
+    }
+
+    /// Describe a class: methods, base classes, fields and attributes.
+    pub async fn get_class_info(
+        &self,
+        package_name: &str,
+        context_path: &Path,
+        relative_path: &str,
+        class_name: &str,
+    ) -> Result<crate::doc_engine::python_analyzer::ClassInfo> {
+        use crate::doc_engine::python_analyzer;
+
+        let files = ModuleFiles::read(package_name, context_path, relative_path).await?;
+        let stub_info = files
+            .stub
+            .as_ref()
+            .and_then(|(_, stub)| python_analyzer::extract_class_info(stub, class_name).ok());
+        let Some((_, source_code)) = &files.source else {
+            return stub_info.with_context(|| {
+                format!("Class '{class_name}' not found in {relative_path} or its stub")
+            });
+        };
+
+        let mut info = python_analyzer::extract_class_info(source_code, class_name)?;
+        if let Some(stub_info) = stub_info {
+            python_analyzer::merge_stub_class(&mut info, stub_info);
+        }
+        Ok(info)
     }
 
     /// Extract a specific method from a class.
@@ -175,12 +376,22 @@ impl PythonProcessor {
     ) -> Result<crate::doc_engine::python_analyzer::MethodInfo> {
         use crate::doc_engine::python_analyzer;
 
-        let package_root =
-            finder::find_python_package_path_with_context(package_name, Some(context_path))?;
-        let file_path = package_root.join(relative_path);
-        let source_code = tokio::fs::read_to_string(&file_path).await?;
+        let files = ModuleFiles::read(package_name, context_path, relative_path).await?;
+        let stub_method = files.stub.as_ref().and_then(|(_, stub)| {
+            python_analyzer::extract_specific_method(stub, class_name, method_name).ok()
+        });
+        let from_source = files.source.as_ref().and_then(|(_, source_code)| {
+            python_analyzer::extract_specific_method(source_code, class_name, method_name).ok()
+        });
+        let Some(method) = from_source else {
+            return stub_method.with_context(|| {
+                format!("Method '{method_name}' not found in class '{class_name}'")
+            });
+        };
 
-        python_analyzer::extract_specific_method(&source_code, class_name, method_name)
+        let mut methods = vec![method];
+        python_analyzer::merge_stub_methods(&mut methods, stub_method.into_iter().collect());
+        Ok(methods.remove(0))
     }
 
     /// Search across an entire Python package.
@@ -197,6 +408,98 @@ impl PythonProcessor {
         let package_root =
             finder::find_python_package_path_with_context(package_name, Some(context_path))?;
 
-        python_analyzer::search_package(&package_root, pattern, search_mode, limit)
+        python_analyzer::search_package(
+            &package_root,
+            Some(context_path),
+            pattern,
+            search_mode,
+            limit,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::tempdir;
+
+    #[tokio::test]
+    async fn class_defined_only_in_the_stub_is_read_from_the_stub() {
+        let tmp = tempdir().unwrap();
+        let package = tmp
+            .path()
+            .join(".venv/lib/python3.12/site-packages/fastlib");
+        fs::create_dir_all(&package).unwrap();
+        fs::write(package.join("__init__.py"), "").unwrap();
+        fs::write(
+            package.join("core.py"),
+            "from ._speedups import Engine\n\ndef helper():\n    pass\n",
+        )
+        .unwrap();
+        fs::write(
+            package.join("core.pyi"),
+            "class Engine:\n    def run(self, steps: int) -> None:\n        \"\"\"Run the engine.\"\"\"\n",
+        )
+        .unwrap();
+
+        let processor = PythonProcessor;
+        let methods = processor
+            .list_class_methods("fastlib", tmp.path(), "core.py", "Engine", false, false)
+            .await
+            .unwrap();
+        assert_eq!(methods.len(), 1);
+        assert_eq!(methods[0].name, "run");
+
+        let method = processor
+            .get_class_method("fastlib", tmp.path(), "core.py", "Engine", "run")
+            .await
+            .unwrap();
+        assert_eq!(method.name, "run");
+        assert!(processor
+            .get_class_method("fastlib", tmp.path(), "core.py", "Engine", "stop")
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn imported_names_fall_back_to_the_stub_definition() {
+        let tmp = tempdir().unwrap();
+        let package = tmp
+            .path()
+            .join(".venv/lib/python3.12/site-packages/fastlib");
+        fs::create_dir_all(&package).unwrap();
+        fs::write(package.join("__init__.py"), "").unwrap();
+        fs::write(
+            package.join("core.py"),
+            "from ._speedups import Engine\n\ndef helper(count):\n    return count\n",
+        )
+        .unwrap();
+        fs::write(
+            package.join("core.pyi"),
+            "class Engine:\n    def run(self) -> None: ...\n\ndef helper(count: int) -> int:\n    \"\"\"Echo the count.\"\"\"\n",
+        )
+        .unwrap();
+
+        let processor = PythonProcessor;
+        let engine = processor
+            .get_implementation_context("fastlib", tmp.path(), "core.py", "Engine")
+            .await
+            .unwrap();
+        assert!(engine.implementation.starts_with("class Engine:"));
+        assert!(engine.file_path.ends_with("core.pyi"));
+        assert!(engine.typings.is_none());
+
+        let helper = processor
+            .get_implementation_context("fastlib", tmp.path(), "core.py", "helper")
+            .await
+            .unwrap();
+        assert!(helper.implementation.contains("return count"));
+        assert!(helper.file_path.ends_with("core.py"));
+        let typings = helper.typings.unwrap();
+        assert!(typings
+            .declaration
+            .starts_with("def helper(count: int) -> int:"));
+        assert_eq!(helper.documentation.as_deref(), Some("Echo the count."));
     }
 }
@@ -297,8 +297,10 @@ impl LanguageProcessor for RustProcessor {
             file_path: file_path.to_string_lossy().into_owned(),
             item_name: item_name.to_string(),
             documentation,
+            structured_docstring: None,
             implementation,
             language: "rust".to_string(),
+            typings: None,
         })
     }
 }
@@ -3,13 +3,32 @@ use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use std::path::Path;
 
+use crate::shared_types::StructuredDocstring;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ImplementationContext {
     pub file_path: String,
     pub item_name: String,
     pub documentation: Option<String>,
+    /// `documentation` split into sections (Python only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub structured_docstring: Option<StructuredDocstring>,
     pub implementation: String,
     pub language: String,
+    /// Declaration of the item in the package's typings: `.d.ts` files for
+    /// Node.js, `.pyi` stubs for Python items the source also defines
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub typings: Option<TypingsDeclaration>,
+}
+
+/// An item as declared in a `.d.ts` file or `.pyi` stub
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TypingsDeclaration {
+    pub file_path: String,
+    /// Declaration text, one statement per overload
+    pub declaration: String,
+    /// TSDoc comment or docstring of the declaration
+    pub documentation: Option<String>,
 }
 
 #[async_trait]
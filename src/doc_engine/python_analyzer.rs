@@ -7,22 +7,37 @@ use anyhow::{Context, Result};
 use rayon::prelude::*;
 use regex::Regex;
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
 use tree_sitter::{Node, Parser};
 use walkdir::WalkDir;
 
+use crate::doc_engine::finder;
+use crate::doc_engine::python_docstring::parse_docstring;
+use crate::shared_types::StructuredDocstring;
+
 /// Information about a single method in a class.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MethodInfo {
     pub name: String,
     pub signature: String,
     pub docstring: Option<String>,
+    /// `docstring` split into parameters, returns, raises and so on
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub structured_docstring: Option<StructuredDocstring>,
     pub line_start: usize,
     pub line_end: usize,
     pub is_staticmethod: bool,
     pub is_classmethod: bool,
     pub is_property: bool,
     pub is_async: bool,
+    /// Dotted path of the class defining the method, when inherited methods
+    /// were requested
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub defining_class: Option<String>,
+    /// A class later in the MRO defines a method of the same name
+    #[serde(default)]
+    pub is_override: bool,
 }
 
 /// Complete information about a Python class.
@@ -32,10 +47,66 @@ pub struct ClassInfo {
     pub docstring: Option<String>,
     pub methods: Vec<MethodInfo>,
     pub base_classes: Vec<String>,
+    /// How the class declares its fields
+    #[serde(default)]
+    pub kind: ClassKind,
+    /// Fields, class attributes and attributes assigned in `__init__`
+    #[serde(default)]
+    pub attributes: Vec<AttributeInfo>,
+    /// Names listed in `__slots__`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slots: Option<Vec<String>>,
     pub line_start: usize,
     pub line_end: usize,
 }
 
+/// Declarative class flavours whose annotated attributes become
+/// constructor fields.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ClassKind {
+    #[default]
+    Class,
+    Dataclass,
+    Attrs,
+    NamedTuple,
+    TypedDict,
+    Pydantic,
+}
+
+/// Where an attribute is declared.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AttributeKind {
+    /// Field of a dataclass, attrs class, `NamedTuple`, `TypedDict` or
+    /// pydantic model
+    Field,
+    /// Class-level attribute, including `ClassVar`s
+    Class,
+    /// Assigned to `self` in `__init__`
+    Instance,
+}
+
+/// A class attribute or field.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AttributeInfo {
+    pub name: String,
+    pub kind: AttributeKind,
+    /// Annotation, or the `type=` of an `attr.ib()`
+    #[serde(rename = "type")]
+    pub type_name: Option<String>,
+    /// Default value; for instance attributes, the assigned expression
+    pub default: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_factory: Option<String>,
+    /// Whether the constructor requires a value (fields only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub required: Option<bool>,
+    /// Attribute docstring, or the `description=` of a pydantic `Field()`
+    pub docstring: Option<String>,
+    pub line: usize,
+}
+
 /// Search modes for library-wide search.
 #[derive(Debug, Clone, Copy)]
 pub enum SearchMode {
@@ -136,12 +207,17 @@ pub fn extract_class_info(source_code: &str, class_name: &str) -> Result<ClassIn
     }
 
     let methods = extract_class_methods(source_code, class_name, true)?;
+    let kind = class_kind(class_node, source_code.as_bytes());
+    let (attributes, slots) = extract_attributes(class_node, source_code.as_bytes(), kind);
 
     Ok(ClassInfo {
         name: class_name.to_string(),
         docstring,
         methods,
         base_classes,
+        kind,
+        attributes,
+        slots,
         line_start: class_node.start_position().row + 1,
         line_end: class_node.end_position().row + 1,
     })
@@ -165,32 +241,108 @@ pub fn extract_specific_method(
 }
 
 /// Search across an entire Python package.
+///
+/// `.pyi` stubs next to the package's modules, or in a sibling
+/// `<pkg>-stubs` directory, contribute their typed signatures to matching
+/// results. Symbols only present in stubs, such as those of compiled
+/// extension modules, are searched as well. `context_path` selects the
+/// environment whose typeshed describes standard library modules.
 pub fn search_package(
     package_path: &Path,
+    context_path: Option<&Path>,
     pattern: &str,
     search_mode: SearchMode,
     limit: usize,
 ) -> Result<Vec<SearchResult>> {
     let regex = Regex::new(pattern).context("Invalid regex pattern")?;
+    let match_all = Regex::new("").expect("empty regex is valid");
 
-    // Collect all .py files
-    let py_files: Vec<_> = WalkDir::new(package_path)
-        .follow_links(true)
-        .into_iter()
-        .filter_map(|e| e.ok())
-        .filter(|e| e.path().extension().and_then(|s| s.to_str()) == Some("py"))
+    let package_name = package_path
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or_default();
+    let mut roots = vec![package_path.to_path_buf()];
+    roots.extend(finder::find_python_stub_dir(
+        package_path,
+        package_name,
+        context_path,
+    ));
+
+    // Collect all .py and .pyi files with the module they describe
+    let files: Vec<(PathBuf, String, bool)> = roots
+        .iter()
+        .flat_map(|root| {
+            WalkDir::new(root)
+                .follow_links(true)
+                .into_iter()
+                .filter_map(|e| e.ok())
+                .filter_map(move |entry| {
+                    let is_stub = match entry.path().extension().and_then(|s| s.to_str()) {
+                        Some("py") => false,
+                        Some("pyi") => true,
+                        _ => return None,
+                    };
+                    let module = entry
+                        .path()
+                        .strip_prefix(root)
+                        .ok()?
+                        .with_extension("")
+                        .to_string_lossy()
+                        .into_owned();
+                    Some((entry.into_path(), module, is_stub))
+                })
+        })
         .collect();
 
-    // Process files in parallel
-    let file_results: Vec<Vec<SearchResult>> = py_files
+    // Process files in parallel; every stub item is kept for merging
+    let file_results: Vec<(String, bool, Vec<SearchResult>, Vec<SearchResult>)> = files
         .par_iter()
-        .filter_map(|entry| search_file(entry.path(), &regex, &search_mode).ok())
+        .filter_map(|(path, module, is_stub)| {
+            let matched = search_file(path, &regex, &search_mode).ok()?;
+            let all = if *is_stub {
+                search_file(path, &match_all, &SearchMode::Name).ok()?
+            } else {
+                Vec::new()
+            };
+            Some((module.clone(), *is_stub, matched, all))
+        })
         .collect();
 
-    // Flatten and limit results
+    let key = |module: &str, result: &SearchResult| {
+        (
+            module.to_string(),
+            result.class_name.clone(),
+            result.item_name.clone(),
+        )
+    };
+    let mut stub_items: HashMap<(String, Option<String>, String), SearchResult> = HashMap::new();
+    let mut defined = HashSet::new();
+    for (module, is_stub, matched, all) in &file_results {
+        for result in all {
+            // The first stub wins, and of several `@overload`s the first one
+            stub_items
+                .entry(key(module, result))
+                .or_insert_with(|| result.clone());
+        }
+        if !is_stub {
+            defined.extend(matched.iter().map(|result| key(module, result)));
+        }
+    }
+
+    // Flatten, merge and limit results
     let mut results = Vec::new();
-    for file_result in file_results {
-        for result in file_result {
+    let mut seen = HashSet::new();
+    for (module, is_stub, matched, _) in file_results {
+        for mut result in matched {
+            let key = key(&module, &result);
+            // Stub items already found in the source are merged into it
+            if is_stub && (defined.contains(&key) || !seen.insert(key.clone())) {
+                continue;
+            }
+            if let Some(stub) = stub_items.get(&key).filter(|_| !is_stub) {
+                result.signature = stub.signature.clone().or(result.signature);
+                result.docstring = result.docstring.or_else(|| stub.docstring.clone());
+            }
             if results.len() >= limit {
                 return Ok(results);
             }
@@ -201,6 +353,60 @@ pub fn search_package(
     Ok(results)
 }
 
+/// Complete methods extracted from a module with those of its `.pyi` stub:
+/// typed stub signatures replace the source's, docstrings missing from the
+/// source are taken from the stub, and methods only declared in the stub
+/// are appended.
+pub fn merge_stub_methods(methods: &mut Vec<MethodInfo>, stub_methods: Vec<MethodInfo>) {
+    let mut seen = HashSet::new();
+    for stub in stub_methods {
+        // Of several `@overload`s keep the first
+        if !seen.insert(stub.name.clone()) {
+            continue;
+        }
+        match methods.iter_mut().find(|method| method.name == stub.name) {
+            Some(method) => {
+                method.signature = stub.signature;
+                if method.docstring.is_none() {
+                    method.docstring = stub.docstring;
+                    method.structured_docstring = stub.structured_docstring;
+                }
+            }
+            None => methods.push(stub),
+        }
+    }
+}
+
+/// Complete class information from a source file with its `.pyi` stub:
+/// stub signatures and annotations fill in what the source leaves untyped,
+/// and stub-only methods and attributes are appended.
+pub fn merge_stub_class(info: &mut ClassInfo, stub: ClassInfo) {
+    merge_stub_methods(&mut info.methods, stub.methods);
+    for stub_attribute in stub.attributes {
+        match info
+            .attributes
+            .iter_mut()
+            .find(|attribute| attribute.name == stub_attribute.name)
+        {
+            Some(attribute) => {
+                if attribute.type_name.is_none() {
+                    attribute.type_name = stub_attribute.type_name;
+                }
+                if attribute.docstring.is_none() {
+                    attribute.docstring = stub_attribute.docstring;
+                }
+            }
+            None => info.attributes.push(stub_attribute),
+        }
+    }
+    if info.kind == ClassKind::Class {
+        info.kind = stub.kind;
+    }
+    if info.slots.is_none() {
+        info.slots = stub.slots;
+    }
+}
+
 // ===== Helper Functions =====
 
 fn find_class_node<'a>(node: Node<'a>, class_name: &str, source: &'a [u8]) -> Option<Node<'a>> {
@@ -255,6 +461,7 @@ fn extract_method_info(
     Some(MethodInfo {
         name,
         signature,
+        structured_docstring: docstring.as_deref().map(parse_docstring),
         docstring,
         line_start: method_node.start_position().row + 1,
         line_end: method_node.end_position().row + 1,
@@ -262,6 +469,8 @@ fn extract_method_info(
         is_classmethod: false,
         is_property: false,
         is_async,
+        defining_class: None,
+        is_override: false,
     })
 }
 
@@ -323,18 +532,7 @@ fn extract_first_docstring(node: Node, source: &[u8]) -> Option<String> {
             for expr_child in child.children(&mut child.walk()) {
                 if expr_child.kind() == "string" {
                     if let Ok(text) = expr_child.utf8_text(source) {
-                        // Clean up docstring (remove quotes)
-                        let cleaned = text
-                            .trim_start_matches("\"\"\"")
-                            .trim_start_matches("'''")
-                            .trim_start_matches('"')
-                            .trim_start_matches('\'')
-                            .trim_end_matches("\"\"\"")
-                            .trim_end_matches("'''")
-                            .trim_end_matches('"')
-                            .trim_end_matches('\'')
-                            .trim();
-                        return Some(cleaned.to_string());
+                        return Some(strip_quotes(text));
                     }
                 }
             }
@@ -344,6 +542,303 @@ fn extract_first_docstring(node: Node, source: &[u8]) -> Option<String> {
     None
 }
 
+/// Contents of a string literal, without prefix, quotes or surrounding
+/// whitespace.
+fn strip_quotes(text: &str) -> String {
+    text.trim_start_matches(|c: char| c.is_ascii_alphabetic())
+        .trim_start_matches("\"\"\"")
+        .trim_start_matches("'''")
+        .trim_start_matches('"')
+        .trim_start_matches('\'')
+        .trim_end_matches("\"\"\"")
+        .trim_end_matches("'''")
+        .trim_end_matches('"')
+        .trim_end_matches('\'')
+        .trim()
+        .to_string()
+}
+
+/// Last dotted segment of a decorator or base class, without call
+/// arguments or subscripts: `dataclasses.dataclass(frozen=True)` gives
+/// `dataclass`.
+fn bare_name(text: &str) -> &str {
+    let text = text.trim_start_matches('@');
+    let end = text.find(['(', '[']).unwrap_or(text.len());
+    let name = text[..end].trim();
+    name.rsplit('.').next().unwrap_or(name)
+}
+
+fn class_kind(class_node: Node, source: &[u8]) -> ClassKind {
+    let decorated = class_node
+        .parent()
+        .filter(|parent| parent.kind() == "decorated_definition");
+    if let Some(decorated) = decorated {
+        for decorator in decorated.children(&mut decorated.walk()) {
+            if decorator.kind() != "decorator" {
+                continue;
+            }
+            let text = decorator.utf8_text(source).unwrap_or("");
+            let qualified = text.trim_start_matches('@').trim();
+            match bare_name(text) {
+                "dataclass" => return ClassKind::Dataclass,
+                "define" | "frozen" | "mutable" => return ClassKind::Attrs,
+                "s" | "attrs" if qualified.starts_with("attr") => return ClassKind::Attrs,
+                _ => {}
+            }
+        }
+    }
+
+    let Some(superclasses) = class_node.child_by_field_name("superclasses") else {
+        return ClassKind::Class;
+    };
+    for base in superclasses.named_children(&mut superclasses.walk()) {
+        if base.kind() == "keyword_argument" {
+            continue;
+        }
+        match bare_name(base.utf8_text(source).unwrap_or("")) {
+            "NamedTuple" => return ClassKind::NamedTuple,
+            "TypedDict" => return ClassKind::TypedDict,
+            "BaseModel" | "BaseSettings" | "RootModel" | "SQLModel" => return ClassKind::Pydantic,
+            _ => {}
+        }
+    }
+    ClassKind::Class
+}
+
+/// Whether a `TypedDict` was declared with `total=False`.
+fn is_partial_typed_dict(class_node: Node, source: &[u8]) -> bool {
+    let Some(superclasses) = class_node.child_by_field_name("superclasses") else {
+        return false;
+    };
+    superclasses
+        .named_children(&mut superclasses.walk())
+        .filter(|argument| argument.kind() == "keyword_argument")
+        .any(|argument| {
+            let text = |field| {
+                argument
+                    .child_by_field_name(field)
+                    .and_then(|node| node.utf8_text(source).ok())
+            };
+            text("name") == Some("total") && text("value") == Some("False")
+        })
+}
+
+/// String literal following a statement, the convention for documenting
+/// attributes.
+fn attribute_docstring(statement: Node, source: &[u8]) -> Option<String> {
+    let next = statement.next_named_sibling()?;
+    let string = next.named_child(0)?;
+    (next.kind() == "expression_statement" && string.kind() == "string")
+        .then(|| strip_quotes(string.utf8_text(source).unwrap_or("")))
+        .filter(|doc| !doc.is_empty())
+}
+
+/// Class-level attributes and fields, attributes assigned to `self` in
+/// `__init__`, and `__slots__`.
+fn extract_attributes(
+    class_node: Node,
+    source: &[u8],
+    kind: ClassKind,
+) -> (Vec<AttributeInfo>, Option<Vec<String>>) {
+    let mut attributes: Vec<AttributeInfo> = Vec::new();
+    let mut slots = None;
+    let Some(body) = class_node.child_by_field_name("body") else {
+        return (attributes, slots);
+    };
+    let text = |node: Node| node.utf8_text(source).unwrap_or("").to_string();
+    let total = !is_partial_typed_dict(class_node, source);
+
+    for statement in body.named_children(&mut body.walk()) {
+        let Some(assignment) = statement.named_child(0).filter(|node| {
+            statement.kind() == "expression_statement" && node.kind() == "assignment"
+        }) else {
+            continue;
+        };
+        let Some(left) = assignment
+            .child_by_field_name("left")
+            .filter(|left| left.kind() == "identifier")
+        else {
+            continue;
+        };
+        let name = text(left);
+        let right = assignment.child_by_field_name("right");
+        if name == "__slots__" {
+            slots = right.map(|value| match value.kind() {
+                "string" => vec![strip_quotes(&text(value))],
+                _ => value
+                    .named_children(&mut value.walk())
+                    .filter(|item| item.kind() == "string")
+                    .map(|item| strip_quotes(&text(item)))
+                    .collect(),
+            });
+            continue;
+        }
+
+        let mut attribute = AttributeInfo {
+            name,
+            kind: AttributeKind::Class,
+            type_name: assignment.child_by_field_name("type").map(text),
+            default: right.map(text),
+            default_factory: None,
+            required: None,
+            docstring: attribute_docstring(statement, source),
+            line: statement.start_position().row + 1,
+        };
+        let annotation = attribute
+            .type_name
+            .as_deref()
+            .map(|type_name| bare_name(type_name).to_string());
+        let annotation = annotation.as_deref();
+        if annotation == Some("KW_ONLY") {
+            continue;
+        }
+        let field_call = right.filter(|value| value.kind() == "call").filter(|call| {
+            let function = call.child_by_field_name("function").map(text);
+            matches!(
+                function.as_deref().map(bare_name),
+                Some("field" | "Field" | "ib" | "attrib")
+            )
+        });
+        let is_field = match kind {
+            ClassKind::Class => false,
+            ClassKind::Attrs => field_call.is_some() || annotation.is_some(),
+            ClassKind::Pydantic => {
+                annotation.is_some()
+                    && !attribute.name.starts_with('_')
+                    && attribute.name != "model_config"
+            }
+            _ => annotation.is_some(),
+        };
+        if !is_field || annotation == Some("ClassVar") {
+            attributes.push(attribute);
+            continue;
+        }
+
+        attribute.kind = AttributeKind::Field;
+        if let Some(call) = field_call {
+            attribute.default = None;
+            let arguments = call.child_by_field_name("arguments");
+            for (position, argument) in arguments
+                .iter()
+                .flat_map(|arguments| {
+                    arguments
+                        .named_children(&mut arguments.walk())
+                        .collect::<Vec<_>>()
+                })
+                .enumerate()
+            {
+                if argument.kind() != "keyword_argument" {
+                    // `Field(default, ...)`
+                    if position == 0 && kind == ClassKind::Pydantic {
+                        attribute.default = Some(text(argument));
+                    }
+                    continue;
+                }
+                let value = argument.child_by_field_name("value").map(text);
+                match argument.child_by_field_name("name").map(text).as_deref() {
+                    Some("default") => attribute.default = value,
+                    Some("default_factory" | "factory") => attribute.default_factory = value,
+                    Some("type") if attribute.type_name.is_none() => attribute.type_name = value,
+                    Some("description") if attribute.docstring.is_none() => {
+                        attribute.docstring = value.map(|value| strip_quotes(&value))
+                    }
+                    _ => {}
+                }
+            }
+        }
+        // `x: int = ...` and `Field(...)` mark required pydantic fields
+        if attribute.default.as_deref() == Some("...") {
+            attribute.default = None;
+        }
+        attribute.required = Some(match kind {
+            ClassKind::TypedDict => match annotation {
+                Some("Required") => true,
+                Some("NotRequired") => false,
+                _ => total,
+            },
+            _ => attribute.default.is_none() && attribute.default_factory.is_none(),
+        });
+        attributes.push(attribute);
+    }
+
+    let init = body.named_children(&mut body.walk()).find_map(|child| {
+        let function = match child.kind() {
+            "decorated_definition" => child.child_by_field_name("definition")?,
+            _ => child,
+        };
+        let is_init = function.kind() == "function_definition"
+            && function.child_by_field_name("name").map(text).as_deref() == Some("__init__");
+        is_init.then_some(function)
+    });
+    if let Some(init) = init {
+        let self_name = init
+            .child_by_field_name("parameters")
+            .and_then(|parameters| parameters.named_child(0))
+            .map(text)
+            .unwrap_or_else(|| "self".to_string());
+        if let Some(init_body) = init.child_by_field_name("body") {
+            collect_instance_attributes(init_body, source, &self_name, &mut attributes);
+        }
+    }
+
+    (attributes, slots)
+}
+
+/// Attributes assigned to `self_name` anywhere in a method body, outside
+/// nested functions and classes.
+fn collect_instance_attributes(
+    node: Node,
+    source: &[u8],
+    self_name: &str,
+    attributes: &mut Vec<AttributeInfo>,
+) {
+    let text = |node: Node| node.utf8_text(source).unwrap_or("").to_string();
+    for child in node.named_children(&mut node.walk()) {
+        match child.kind() {
+            "function_definition" | "class_definition" | "lambda" => continue,
+            "assignment" => {
+                let right = child.child_by_field_name("right");
+                let targets: Vec<Node> = match child.child_by_field_name("left") {
+                    Some(left) if matches!(left.kind(), "pattern_list" | "tuple_pattern") => {
+                        left.named_children(&mut left.walk()).collect()
+                    }
+                    Some(left) => vec![left],
+                    None => Vec::new(),
+                };
+                let single = targets.len() == 1;
+                for target in targets {
+                    let object = target.child_by_field_name("object").map(text);
+                    let attribute = target.child_by_field_name("attribute").map(text);
+                    let (Some(object), Some(name)) = (object, attribute) else {
+                        continue;
+                    };
+                    if target.kind() != "attribute"
+                        || object != self_name
+                        || attributes.iter().any(|existing| existing.name == name)
+                    {
+                        continue;
+                    }
+                    let statement = child.parent().unwrap_or(child);
+                    attributes.push(AttributeInfo {
+                        name,
+                        kind: AttributeKind::Instance,
+                        type_name: child.child_by_field_name("type").map(text),
+                        default: right.filter(|_| single).map(text),
+                        default_factory: None,
+                        required: None,
+                        docstring: attribute_docstring(statement, source),
+                        line: child.start_position().row + 1,
+                    });
+                }
+                if let Some(right) = right {
+                    collect_instance_attributes(right, source, self_name, attributes);
+                }
+            }
+            _ => collect_instance_attributes(child, source, self_name, attributes),
+        }
+    }
+}
+
 fn build_signature(func_node: Node, source: &[u8]) -> Option<String> {
     let name = func_node
         .child_by_field_name("name")?
@@ -562,6 +1057,13 @@ class MyClass:
         assert!(public_method.signature.contains("int"));
         assert!(public_method.signature.contains("-> str"));
         assert_eq!(public_method.docstring.as_deref(), Some("A public method."));
+        assert_eq!(
+            public_method
+                .structured_docstring
+                .as_ref()
+                .and_then(|doc| doc.summary.as_deref()),
+            Some("A public method.")
+        );
 
         let static_method = methods.iter().find(|m| m.name == "static_method").unwrap();
         assert!(static_method.is_staticmethod);
@@ -593,6 +1095,188 @@ class Child(Parent):
         assert_eq!(info.methods.len(), 1);
     }
 
+    #[test]
+    fn test_extract_class_attributes() {
+        let source = r#"
+from dataclasses import dataclass, field
+from typing import ClassVar, NamedTuple, NotRequired, TypedDict
+from pydantic import BaseModel, Field
+
+@dataclass(frozen=True)
+class Config:
+    """Settings."""
+
+    registry: ClassVar[dict] = {}
+    host: str
+    """Server host name."""
+    port: int = 8080
+    tags: list[str] = field(default_factory=list)
+
+class Settings(BaseModel):
+    model_config = {"frozen": True}
+    name: str = Field(..., description="Display name")
+    retries: int = Field(3, ge=0)
+    _cache: dict = {}
+
+class Point(NamedTuple):
+    x: float
+    y: float = 0.0
+
+class Options(TypedDict, total=False):
+    verbose: bool
+    level: NotRequired[int]
+
+class Connection:
+    __slots__ = ("sock", "timeout")
+    default_timeout = 10
+
+    def __init__(self, sock, timeout: float = 1.0):
+        self.sock = sock
+        self.timeout: float = timeout
+        if timeout:
+            self.deadline = None
+        def helper():
+            self.ignored = 1
+"#;
+
+        let summary = |info: &ClassInfo| {
+            info.attributes
+                .iter()
+                .map(|a| {
+                    (
+                        a.name.clone(),
+                        a.kind,
+                        a.type_name.clone(),
+                        a.default.clone(),
+                        a.required,
+                    )
+                })
+                .collect::<Vec<_>>()
+        };
+        let owned = |value: &str| Some(value.to_string());
+
+        let config = extract_class_info(source, "Config").unwrap();
+        assert_eq!(config.kind, ClassKind::Dataclass);
+        assert_eq!(
+            summary(&config),
+            vec![
+                (
+                    "registry".into(),
+                    AttributeKind::Class,
+                    owned("ClassVar[dict]"),
+                    owned("{}"),
+                    None
+                ),
+                (
+                    "host".into(),
+                    AttributeKind::Field,
+                    owned("str"),
+                    None,
+                    Some(true)
+                ),
+                (
+                    "port".into(),
+                    AttributeKind::Field,
+                    owned("int"),
+                    owned("8080"),
+                    Some(false)
+                ),
+                (
+                    "tags".into(),
+                    AttributeKind::Field,
+                    owned("list[str]"),
+                    None,
+                    Some(false)
+                ),
+            ]
+        );
+        assert_eq!(
+            config.attributes[1].docstring.as_deref(),
+            Some("Server host name.")
+        );
+        assert_eq!(
+            config.attributes[3].default_factory.as_deref(),
+            Some("list")
+        );
+
+        let settings = extract_class_info(source, "Settings").unwrap();
+        assert_eq!(settings.kind, ClassKind::Pydantic);
+        let kinds: Vec<_> = settings
+            .attributes
+            .iter()
+            .map(|a| (a.name.as_str(), a.kind, a.required))
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                ("model_config", AttributeKind::Class, None),
+                ("name", AttributeKind::Field, Some(true)),
+                ("retries", AttributeKind::Field, Some(false)),
+                ("_cache", AttributeKind::Class, None),
+            ]
+        );
+        assert_eq!(
+            settings.attributes[1].docstring.as_deref(),
+            Some("Display name")
+        );
+        assert_eq!(settings.attributes[2].default.as_deref(), Some("3"));
+
+        let point = extract_class_info(source, "Point").unwrap();
+        assert_eq!(point.kind, ClassKind::NamedTuple);
+        assert_eq!(
+            point
+                .attributes
+                .iter()
+                .map(|a| a.required)
+                .collect::<Vec<_>>(),
+            vec![Some(true), Some(false)]
+        );
+
+        let options = extract_class_info(source, "Options").unwrap();
+        assert_eq!(options.kind, ClassKind::TypedDict);
+        assert!(options.attributes.iter().all(|a| a.required == Some(false)));
+
+        let connection = extract_class_info(source, "Connection").unwrap();
+        assert_eq!(connection.kind, ClassKind::Class);
+        assert_eq!(
+            connection.slots,
+            Some(vec!["sock".to_string(), "timeout".to_string()])
+        );
+        assert_eq!(
+            summary(&connection),
+            vec![
+                (
+                    "default_timeout".into(),
+                    AttributeKind::Class,
+                    None,
+                    owned("10"),
+                    None
+                ),
+                (
+                    "sock".into(),
+                    AttributeKind::Instance,
+                    None,
+                    owned("sock"),
+                    None
+                ),
+                (
+                    "timeout".into(),
+                    AttributeKind::Instance,
+                    owned("float"),
+                    owned("timeout"),
+                    None
+                ),
+                (
+                    "deadline".into(),
+                    AttributeKind::Instance,
+                    None,
+                    owned("None"),
+                    None
+                ),
+            ]
+        );
+    }
+
     #[test]
     fn test_search_by_name() {
         let source = r#"
@@ -609,8 +1293,14 @@ def delete_user(user_id):
         let temp_file = std::env::temp_dir().join("test_search.py");
         std::fs::write(&temp_file, source).unwrap();
 
-        let results =
-            search_package(temp_file.parent().unwrap(), "find_.*", SearchMode::Name, 10).unwrap();
+        let results = search_package(
+            temp_file.parent().unwrap(),
+            None,
+            "find_.*",
+            SearchMode::Name,
+            10,
+        )
+        .unwrap();
 
         assert!(results.iter().any(|r| r.item_name == "find_user"));
         assert!(results.iter().any(|r| r.item_name == "find_product"));
@@ -618,4 +1308,53 @@ def delete_user(user_id):
 
         std::fs::remove_file(&temp_file).ok();
     }
+
+    #[test]
+    fn test_search_merges_stub_signatures() {
+        let temp = tempfile::tempdir().unwrap();
+        let package = temp.path().join("fastjson");
+        let stubs = temp.path().join("fastjson-stubs");
+        std::fs::create_dir_all(&package).unwrap();
+        std::fs::create_dir_all(&stubs).unwrap();
+        std::fs::write(
+            package.join("api.py"),
+            "def dumps(obj, indent=None):\n    \"\"\"Serialize obj to JSON.\"\"\"\n",
+        )
+        .unwrap();
+        std::fs::write(
+            package.join("_speedups.cpython-312-x86_64-linux-gnu.so"),
+            "",
+        )
+        .unwrap();
+        std::fs::write(
+            stubs.join("api.pyi"),
+            "def dumps(obj: object, indent: int | None = ...) -> str: ...\n",
+        )
+        .unwrap();
+        std::fs::write(
+            stubs.join("_speedups.pyi"),
+            "from typing import overload\n\n@overload\ndef dump_bytes(obj: dict) -> bytes: ...\n@overload\ndef dump_bytes(obj: list) -> bytes: ...\n",
+        )
+        .unwrap();
+
+        let results = search_package(&package, None, "dump", SearchMode::Name, 10).unwrap();
+        assert_eq!(results.len(), 2, "{results:?}");
+
+        let dumps = results.iter().find(|r| r.item_name == "dumps").unwrap();
+        assert_eq!(
+            dumps.signature.as_deref(),
+            Some("dumps(obj: object, indent: int | None = ...) -> str")
+        );
+        assert_eq!(dumps.docstring.as_deref(), Some("Serialize obj to JSON."));
+        assert!(dumps.file_path.ends_with("api.py"));
+
+        let compiled = results
+            .iter()
+            .find(|r| r.item_name == "dump_bytes")
+            .unwrap();
+        assert_eq!(
+            compiled.signature.as_deref(),
+            Some("dump_bytes(obj: dict) -> bytes")
+        );
+    }
 }
@@ -0,0 +1,711 @@
+//! Structured parsing of Python docstrings.
+//!
+//! Splits a docstring into summary, description, parameters, return value,
+//! raised exceptions, examples and deprecation notes. Google (`Args:`),
+//! NumPy (`Parameters` underlined with dashes) and Sphinx (`:param x:`)
+//! conventions are detected automatically; anything else is treated as
+//! plain prose, from which doctest examples and `.. deprecated::` directives
+//! are still picked out.
+
+use std::sync::OnceLock;
+
+use regex::Regex;
+
+use crate::shared_types::{
+    DocstringParameter, DocstringRaises, DocstringReturns, DocstringStyle, StructuredDocstring,
+};
+
+struct DocstringPatterns {
+    sphinx_field: Regex,
+    google_header: Regex,
+    numpy_underline: Regex,
+    google_param: Regex,
+    numpy_item: Regex,
+    raises_item: Regex,
+    deprecated: Regex,
+}
+
+fn patterns() -> &'static DocstringPatterns {
+    static PATTERNS: OnceLock<DocstringPatterns> = OnceLock::new();
+    PATTERNS.get_or_init(|| DocstringPatterns {
+        sphinx_field: Regex::new(r"^:([A-Za-z]+)((?:\s+[^:]+)?):\s*(.*)$").unwrap(),
+        google_header: Regex::new(r"^([A-Z][A-Za-z ]*):\s*$").unwrap(),
+        numpy_underline: Regex::new(r"^\s*-{3,}\s*$").unwrap(),
+        google_param: Regex::new(
+            r"^(\*{0,2}[A-Za-z_][A-Za-z0-9_]*)\s*(?:\(([^)]*)\))?\s*:\s*(.*)$",
+        )
+        .unwrap(),
+        numpy_item: Regex::new(
+            r"^(\*{0,2}[A-Za-z_][\w.]*(?:\s*,\s*\*{0,2}[A-Za-z_]\w*)*)\s*(?::\s*(.*))?$",
+        )
+        .unwrap(),
+        raises_item: Regex::new(r"^([A-Za-z_][\w.]*)\s*:\s*(.*)$").unwrap(),
+        deprecated: Regex::new(r"^\.\.\s+deprecated::\s*(.*)$").unwrap(),
+    })
+}
+
+/// Conventional section a header introduces.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Section {
+    Parameters,
+    Returns,
+    Raises,
+    Examples,
+    Deprecated,
+    /// Notes, warnings, see-also and the like stay in the description
+    Other,
+}
+
+fn section_for(header: &str) -> Option<Section> {
+    let section = match header.trim().to_ascii_lowercase().as_str() {
+        "args" | "arguments" | "parameters" | "params" | "keyword args" | "keyword arguments"
+        | "other parameters" | "kwargs" => Section::Parameters,
+        "returns" | "return" | "yields" | "yield" => Section::Returns,
+        "raises" | "raise" | "except" | "exceptions" => Section::Raises,
+        "example" | "examples" => Section::Examples,
+        "deprecated" => Section::Deprecated,
+        "note" | "notes" | "warning" | "warnings" | "warns" | "see also" | "references"
+        | "attributes" | "todo" | "methods" => Section::Other,
+        _ => return None,
+    };
+    Some(section)
+}
+
+/// Parse a docstring, with or without its quotes and indentation.
+pub fn parse_docstring(raw: &str) -> StructuredDocstring {
+    let lines = clean_lines(raw);
+    let style = detect_style(&lines);
+    let mut doc = StructuredDocstring {
+        style,
+        ..StructuredDocstring::default()
+    };
+
+    let mut prose: Vec<String> = Vec::new();
+    match style {
+        DocstringStyle::Google => {
+            for (header, body) in split_google(&lines, &mut prose) {
+                apply_section(&mut doc, &mut prose, style, &header, &body);
+            }
+        }
+        DocstringStyle::Numpy => {
+            for (header, body) in split_numpy(&lines, &mut prose) {
+                apply_section(&mut doc, &mut prose, style, &header, &body);
+            }
+        }
+        DocstringStyle::Sphinx => parse_sphinx(&lines, &mut doc, &mut prose),
+        DocstringStyle::Plain => prose = lines,
+    }
+
+    apply_prose(&mut doc, &prose);
+    doc
+}
+
+/// Strip quotes, then dedent like `inspect.cleandoc`: the first line is
+/// trimmed and the common indentation of the others removed.
+fn clean_lines(raw: &str) -> Vec<String> {
+    let trimmed = raw.trim();
+    let inner = ["\"\"\"", "'''", "\"", "'"]
+        .iter()
+        .find_map(|quote| {
+            trimmed
+                .strip_prefix(quote)
+                .and_then(|rest| rest.strip_suffix(quote))
+        })
+        .unwrap_or(trimmed);
+
+    let raw_lines: Vec<&str> = inner.lines().collect();
+    let indent = raw_lines
+        .iter()
+        .skip(1)
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+    let mut lines: Vec<String> = raw_lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| match i {
+            0 => line.trim().to_string(),
+            _ => line
+                .get(indent..)
+                .unwrap_or(line.trim_start())
+                .trim_end()
+                .to_string(),
+        })
+        .collect();
+    while lines.first().is_some_and(|line| line.is_empty()) {
+        lines.remove(0);
+    }
+    while lines.last().is_some_and(|line| line.is_empty()) {
+        lines.pop();
+    }
+    lines
+}
+
+fn indent_of(line: &str) -> usize {
+    line.len() - line.trim_start().len()
+}
+
+fn detect_style(lines: &[String]) -> DocstringStyle {
+    let numpy = lines.windows(2).any(|pair| {
+        section_for(&pair[0]).is_some() && patterns().numpy_underline.is_match(&pair[1])
+    });
+    if numpy {
+        return DocstringStyle::Numpy;
+    }
+    if lines.iter().any(|line| {
+        patterns().sphinx_field.captures(line).is_some_and(|caps| {
+            matches!(
+                &caps[1],
+                "param"
+                    | "parameter"
+                    | "arg"
+                    | "argument"
+                    | "key"
+                    | "keyword"
+                    | "type"
+                    | "returns"
+                    | "return"
+                    | "rtype"
+                    | "raises"
+                    | "raise"
+                    | "except"
+                    | "exception"
+                    | "yields"
+                    | "yield"
+            )
+        })
+    }) {
+        return DocstringStyle::Sphinx;
+    }
+    let google = lines.iter().any(|line| {
+        patterns()
+            .google_header
+            .captures(line)
+            .is_some_and(|caps| section_for(&caps[1]).is_some())
+    });
+    if google {
+        DocstringStyle::Google
+    } else {
+        DocstringStyle::Plain
+    }
+}
+
+/// Sections introduced by `Header:` lines with indented bodies. Lines before
+/// the first section go to `prose`.
+fn split_google(lines: &[String], prose: &mut Vec<String>) -> Vec<(String, Vec<String>)> {
+    let mut sections: Vec<(String, Vec<String>)> = Vec::new();
+    for line in lines {
+        let header = (indent_of(line) == 0)
+            .then(|| patterns().google_header.captures(line))
+            .flatten()
+            .map(|caps| caps[1].to_string())
+            .filter(|header| section_for(header).is_some());
+        match (header, sections.last_mut()) {
+            (Some(header), _) => sections.push((header, Vec::new())),
+            (None, Some((_, body))) if line.is_empty() || indent_of(line) > 0 => {
+                body.push(line.clone())
+            }
+            // Unindented text ends the section
+            (None, _) => prose.push(line.clone()),
+        }
+    }
+    for (_, body) in &mut sections {
+        *body = dedent(body);
+    }
+    sections
+}
+
+/// Sections introduced by a header underlined with dashes; bodies run until
+/// the next header.
+fn split_numpy(lines: &[String], prose: &mut Vec<String>) -> Vec<(String, Vec<String>)> {
+    let mut sections: Vec<(String, Vec<String>)> = Vec::new();
+    let mut i = 0;
+    while i < lines.len() {
+        let is_header = section_for(&lines[i]).is_some()
+            && lines
+                .get(i + 1)
+                .is_some_and(|next| patterns().numpy_underline.is_match(next));
+        if is_header {
+            sections.push((lines[i].trim().to_string(), Vec::new()));
+            i += 2;
+            continue;
+        }
+        match sections.last_mut() {
+            Some((_, body)) => body.push(lines[i].clone()),
+            None => prose.push(lines[i].clone()),
+        }
+        i += 1;
+    }
+    sections
+}
+
+fn apply_section(
+    doc: &mut StructuredDocstring,
+    prose: &mut Vec<String>,
+    style: DocstringStyle,
+    header: &str,
+    body: &[String],
+) {
+    let Some(section) = section_for(header) else {
+        return;
+    };
+    match section {
+        Section::Parameters => doc.parameters.extend(match style {
+            DocstringStyle::Numpy => numpy_parameters(body),
+            _ => google_parameters(body),
+        }),
+        Section::Returns => {
+            if doc.returns.is_none() {
+                doc.returns = match style {
+                    DocstringStyle::Numpy => numpy_returns(body),
+                    _ => google_returns(body),
+                };
+            }
+        }
+        Section::Raises => doc.raises.extend(match style {
+            DocstringStyle::Numpy => numpy_raises(body),
+            _ => google_raises(body),
+        }),
+        Section::Examples => {
+            if let Some(example) = join_block(body) {
+                doc.examples.push(example);
+            }
+        }
+        Section::Deprecated => doc.deprecated = join_paragraph(body),
+        Section::Other => {
+            prose.push(String::new());
+            prose.push(format!("{header}:"));
+            prose.extend(body.iter().cloned());
+        }
+    }
+}
+
+/// Items starting at the body's base indentation, with their more indented
+/// continuation lines.
+fn items(body: &[String]) -> Vec<(String, Vec<String>)> {
+    let base = body
+        .iter()
+        .filter(|line| !line.is_empty())
+        .map(|line| indent_of(line))
+        .min()
+        .unwrap_or(0);
+    let mut items: Vec<(String, Vec<String>)> = Vec::new();
+    for line in body {
+        if !line.is_empty() && indent_of(line) == base {
+            items.push((line.trim().to_string(), Vec::new()));
+        } else if let Some((_, rest)) = items.last_mut() {
+            rest.push(line.trim().to_string());
+        }
+    }
+    items
+}
+
+fn google_parameters(body: &[String]) -> Vec<DocstringParameter> {
+    items(body)
+        .into_iter()
+        .filter_map(|(first, rest)| {
+            let caps = patterns().google_param.captures(&first)?;
+            Some(DocstringParameter {
+                name: caps[1].to_string(),
+                type_name: caps.get(2).map(|m| m.as_str().trim().to_string()),
+                description: join_paragraph(&with_first(&caps[3], rest)),
+            })
+        })
+        .collect()
+}
+
+fn google_returns(body: &[String]) -> Option<DocstringReturns> {
+    let (first, rest) = items(body).into_iter().next()?;
+    // `int: The count` names a type; prose with a colon does not
+    let typed = first.split_once(':').filter(|(ty, _)| {
+        let ty = ty.trim();
+        !ty.is_empty() && !ty.contains(". ") && ty.split_whitespace().count() <= 4
+    });
+    let mut lines = match typed {
+        Some((ty, desc)) => {
+            let lines = with_first(desc, rest);
+            return Some(DocstringReturns {
+                type_name: Some(ty.trim().to_string()),
+                description: join_paragraph(&lines),
+            });
+        }
+        None => vec![first],
+    };
+    lines.extend(rest);
+    Some(DocstringReturns {
+        type_name: None,
+        description: join_paragraph(&lines),
+    })
+}
+
+fn google_raises(body: &[String]) -> Vec<DocstringRaises> {
+    items(body)
+        .into_iter()
+        .map(
+            |(first, rest)| match patterns().raises_item.captures(&first) {
+                Some(caps) => DocstringRaises {
+                    exception: caps[1].to_string(),
+                    description: join_paragraph(&with_first(&caps[2], rest)),
+                },
+                None => DocstringRaises {
+                    exception: first,
+                    description: join_paragraph(&rest),
+                },
+            },
+        )
+        .collect()
+}
+
+fn numpy_parameters(body: &[String]) -> Vec<DocstringParameter> {
+    let mut parameters = Vec::new();
+    for (first, rest) in items(body) {
+        let Some(caps) = patterns().numpy_item.captures(&first) else {
+            continue;
+        };
+        let type_name = caps
+            .get(2)
+            .map(|m| m.as_str().trim().to_string())
+            .filter(|ty| !ty.is_empty());
+        let description = join_paragraph(&rest);
+        // `x1, x2 : array_like` documents both names
+        for name in caps[1].split(',') {
+            parameters.push(DocstringParameter {
+                name: name.trim().to_string(),
+                type_name: type_name.clone(),
+                description: description.clone(),
+            });
+        }
+    }
+    parameters
+}
+
+fn numpy_returns(body: &[String]) -> Option<DocstringReturns> {
+    let (first, rest) = items(body).into_iter().next()?;
+    // `name : type` or just `type`
+    let type_name = match first.split_once(" : ") {
+        Some((_, ty)) => ty.trim().to_string(),
+        None => first,
+    };
+    Some(DocstringReturns {
+        type_name: Some(type_name).filter(|ty| !ty.is_empty()),
+        description: join_paragraph(&rest),
+    })
+}
+
+fn numpy_raises(body: &[String]) -> Vec<DocstringRaises> {
+    items(body)
+        .into_iter()
+        .map(|(exception, rest)| DocstringRaises {
+            exception,
+            description: join_paragraph(&rest),
+        })
+        .collect()
+}
+
+/// `:field args: text` lines with indented continuations.
+fn parse_sphinx(lines: &[String], doc: &mut StructuredDocstring, prose: &mut Vec<String>) {
+    let mut fields: Vec<(String, String, Vec<String>)> = Vec::new();
+    let mut in_field = false;
+    for line in lines {
+        if let Some(caps) = patterns().sphinx_field.captures(line) {
+            fields.push((
+                caps[1].to_string(),
+                caps[2].trim().to_string(),
+                with_first(&caps[3], Vec::new()),
+            ));
+            in_field = true;
+        } else if in_field && (indent_of(line) > 0 || line.is_empty()) {
+            if let Some((_, _, text)) = fields.last_mut() {
+                text.push(line.trim().to_string());
+            }
+        } else {
+            in_field = false;
+            prose.push(line.clone());
+        }
+    }
+
+    let mut types: Vec<(String, String)> = Vec::new();
+    for (field, args, text) in fields {
+        let description = join_paragraph(&text);
+        match field.as_str() {
+            "param" | "parameter" | "arg" | "argument" | "key" | "keyword" => {
+                // `:param int count:` carries the type inline
+                let (type_name, name) = match args.rsplit_once(char::is_whitespace) {
+                    Some((ty, name)) => (Some(ty.trim().to_string()), name.to_string()),
+                    None => (None, args),
+                };
+                doc.parameters.push(DocstringParameter {
+                    name,
+                    type_name,
+                    description,
+                });
+            }
+            "type" => types.push((args, description.unwrap_or_default())),
+            "returns" | "return" | "yields" | "yield" => {
+                doc.returns.get_or_insert_with(Default::default).description = description;
+            }
+            "rtype" | "ytype" => {
+                doc.returns.get_or_insert_with(Default::default).type_name = description;
+            }
+            "raises" | "raise" | "except" | "exception" => doc.raises.push(DocstringRaises {
+                exception: args,
+                description,
+            }),
+            _ => {}
+        }
+    }
+    for (name, type_name) in types {
+        if let Some(parameter) = doc.parameters.iter_mut().find(|p| p.name == name) {
+            parameter.type_name = Some(type_name);
+        }
+    }
+}
+
+/// Summary, description, doctest examples and `.. deprecated::` directives
+/// from the text outside sections.
+fn apply_prose(doc: &mut StructuredDocstring, prose: &[String]) {
+    let mut text: Vec<String> = Vec::new();
+    let mut i = 0;
+    while i < prose.len() {
+        let line = &prose[i];
+        if let Some(caps) = patterns().deprecated.captures(line.trim()) {
+            let version = caps[1].trim().to_string();
+            let mut body = Vec::new();
+            i += 1;
+            while i < prose.len() && (prose[i].is_empty() || indent_of(&prose[i]) > indent_of(line))
+            {
+                body.push(prose[i].trim().to_string());
+                i += 1;
+            }
+            doc.deprecated = match (version.is_empty(), join_paragraph(&body)) {
+                (false, Some(body)) => Some(format!("{version}: {body}")),
+                (false, None) => Some(version),
+                (true, body) => body,
+            };
+            continue;
+        }
+        if line.trim_start().starts_with(">>>") {
+            // A doctest runs until the next blank line
+            let mut block = Vec::new();
+            while i < prose.len() && !prose[i].trim().is_empty() {
+                block.push(prose[i].trim().to_string());
+                i += 1;
+            }
+            doc.examples.push(block.join("\n"));
+            continue;
+        }
+        text.push(line.clone());
+        i += 1;
+    }
+
+    let mut paragraphs = text
+        .split(|line| line.trim().is_empty())
+        .filter(|p| !p.is_empty());
+    doc.summary = paragraphs.next().and_then(join_paragraph);
+    let description = text
+        .iter()
+        .skip_while(|line| line.trim().is_empty())
+        .skip_while(|line| !line.trim().is_empty())
+        .cloned()
+        .collect::<Vec<_>>();
+    doc.description = join_block(&description);
+}
+
+fn dedent(lines: &[String]) -> Vec<String> {
+    let indent = lines
+        .iter()
+        .filter(|line| !line.is_empty())
+        .map(|line| indent_of(line))
+        .min()
+        .unwrap_or(0);
+    lines
+        .iter()
+        .map(|line| line.get(indent..).unwrap_or("").to_string())
+        .collect()
+}
+
+fn with_first(first: &str, rest: Vec<String>) -> Vec<String> {
+    std::iter::once(first.trim().to_string())
+        .chain(rest)
+        .collect()
+}
+
+/// Lines joined into one line of prose; `None` when empty.
+fn join_paragraph(lines: &[String]) -> Option<String> {
+    let joined = lines
+        .iter()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+    (!joined.is_empty()).then_some(joined)
+}
+
+/// Lines kept as a block with their layout; `None` when empty.
+fn join_block(lines: &[String]) -> Option<String> {
+    let joined = dedent(lines).join("\n");
+    let joined = joined.trim_matches('\n').trim_end();
+    (!joined.is_empty()).then(|| joined.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_google_style() {
+        let doc = parse_docstring(
+            r#""""Send a request.
+
+    Retries on connection errors.
+
+    Args:
+        url (str): Target URL.
+        timeout (float, optional): Seconds to wait
+            before giving up.
+        **kwargs: Passed to the adapter.
+
+    Returns:
+        Response: The server response.
+
+    Raises:
+        TimeoutError: If the server does not answer.
+
+    Example:
+        >>> send("https://example.org")
+        <Response 200>
+
+    Deprecated:
+        Use ``Session.send`` instead.
+    """"#,
+        );
+        assert_eq!(doc.style, DocstringStyle::Google);
+        assert_eq!(doc.summary.as_deref(), Some("Send a request."));
+        assert_eq!(
+            doc.description.as_deref(),
+            Some("Retries on connection errors.")
+        );
+        assert_eq!(doc.parameters.len(), 3);
+        assert_eq!(doc.parameters[1].name, "timeout");
+        assert_eq!(
+            doc.parameters[1].type_name.as_deref(),
+            Some("float, optional")
+        );
+        assert_eq!(
+            doc.parameters[1].description.as_deref(),
+            Some("Seconds to wait before giving up.")
+        );
+        assert_eq!(doc.parameters[2].name, "**kwargs");
+        let returns = doc.returns.unwrap();
+        assert_eq!(returns.type_name.as_deref(), Some("Response"));
+        assert_eq!(returns.description.as_deref(), Some("The server response."));
+        assert_eq!(doc.raises[0].exception, "TimeoutError");
+        assert_eq!(
+            doc.examples,
+            vec![">>> send(\"https://example.org\")\n<Response 200>"]
+        );
+        assert_eq!(
+            doc.deprecated.as_deref(),
+            Some("Use ``Session.send`` instead.")
+        );
+    }
+
+    #[test]
+    fn parses_numpy_style() {
+        let doc = parse_docstring(
+            "Compute the weighted mean.
+
+    .. deprecated:: 2.1
+        Use `average` instead.
+
+    Parameters
+    ----------
+    a, b : array_like
+        Input arrays.
+    axis : int, optional
+        Axis along which to average.
+
+    Returns
+    -------
+    mean : ndarray
+        The weighted mean.
+
+    Raises
+    ------
+    ValueError
+        When the weights sum to zero.
+
+    Notes
+    -----
+    Uses pairwise summation.
+    ",
+        );
+        assert_eq!(doc.style, DocstringStyle::Numpy);
+        assert_eq!(doc.summary.as_deref(), Some("Compute the weighted mean."));
+        assert_eq!(
+            doc.deprecated.as_deref(),
+            Some("2.1: Use `average` instead.")
+        );
+        let names: Vec<&str> = doc.parameters.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, ["a", "b", "axis"]);
+        assert_eq!(doc.parameters[0].type_name.as_deref(), Some("array_like"));
+        assert_eq!(
+            doc.parameters[2].description.as_deref(),
+            Some("Axis along which to average.")
+        );
+        assert_eq!(
+            doc.returns.as_ref().unwrap().type_name.as_deref(),
+            Some("ndarray")
+        );
+        assert_eq!(doc.raises[0].exception, "ValueError");
+        assert_eq!(
+            doc.description.as_deref(),
+            Some("Notes:\nUses pairwise summation.")
+        );
+    }
+
+    #[test]
+    fn parses_sphinx_style() {
+        let doc = parse_docstring(
+            "Open a connection.
+
+    :param str host: Host name.
+    :param port: Port number,
+        defaults to 80.
+    :type port: int
+    :returns: An open connection.
+    :rtype: Connection
+    :raises OSError: When the host is unreachable.
+
+    >>> open_connection('localhost')
+    ",
+        );
+        assert_eq!(doc.style, DocstringStyle::Sphinx);
+        assert_eq!(doc.parameters[0].name, "host");
+        assert_eq!(doc.parameters[0].type_name.as_deref(), Some("str"));
+        assert_eq!(doc.parameters[1].type_name.as_deref(), Some("int"));
+        assert_eq!(
+            doc.parameters[1].description.as_deref(),
+            Some("Port number, defaults to 80.")
+        );
+        let returns = doc.returns.unwrap();
+        assert_eq!(returns.type_name.as_deref(), Some("Connection"));
+        assert_eq!(returns.description.as_deref(), Some("An open connection."));
+        assert_eq!(doc.raises[0].exception, "OSError");
+        assert_eq!(doc.examples, vec![">>> open_connection('localhost')"]);
+        assert_eq!(doc.description, None);
+    }
+
+    #[test]
+    fn plain_docstrings_keep_summary_and_description() {
+        let doc = parse_docstring("Return the sum.\n\nBoth operands must be numbers.");
+        assert_eq!(doc.style, DocstringStyle::Plain);
+        assert_eq!(doc.summary.as_deref(), Some("Return the sum."));
+        assert_eq!(
+            doc.description.as_deref(),
+            Some("Both operands must be numbers.")
+        );
+        assert!(doc.parameters.is_empty());
+    }
+}
@@ -0,0 +1,643 @@
+//! Public import paths of Python symbols.
+//!
+//! Packages usually define symbols in submodules and re-export them from
+//! `__init__.py` (`from .sessions import Session`), optionally restricting
+//! their public surface with `__all__`. This module records those re-exports
+//! per file and resolves them into the set of public paths each definition
+//! is importable from, so that `requests.sessions.Session` is reported as
+//! `requests.Session`. In the other direction, [`locate_import_path`] follows
+//! the same re-exports from a dotted import path to the defining file.
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use tree_sitter::{Node, Parser};
+
+use crate::doc_engine::finder;
+
+/// Re-export chains longer than this are treated as unresolvable (and guard
+/// against import cycles).
+const MAX_REEXPORT_DEPTH: usize = 8;
+
+/// Export information of one module, extracted at parse time.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ModuleExports {
+    /// Names listed in `__all__`, when the module defines it
+    pub all: Option<Vec<String>>,
+    /// `from <module> import ...` statements referring to the same package
+    pub reexports: Vec<Reexport>,
+}
+
+/// A name imported from another module of the same package.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Reexport {
+    /// Absolute dotted path of the source module
+    pub module: String,
+    /// Imported name, or `None` for `from module import *`
+    pub name: Option<String>,
+    /// Name bound in the importing module (differs with `import x as y`)
+    pub alias: String,
+}
+
+/// Extract `__all__` and intra-package `from ... import` statements.
+///
+/// `is_package` marks `__init__` modules, whose relative imports resolve
+/// against the module itself rather than its parent.
+pub fn extract_module_exports(
+    root: Node,
+    source: &str,
+    module_path: &str,
+    is_package: bool,
+) -> ModuleExports {
+    let mut exports = ModuleExports::default();
+    let package_root = module_path.split('.').next().unwrap_or(module_path);
+    collect(
+        root,
+        source,
+        module_path,
+        package_root,
+        is_package,
+        &mut exports,
+    );
+    exports
+}
+
+fn collect(
+    node: Node,
+    source: &str,
+    module_path: &str,
+    package_root: &str,
+    is_package: bool,
+    exports: &mut ModuleExports,
+) {
+    let text = |node: Node| node.utf8_text(source.as_bytes()).unwrap_or("").to_string();
+    for statement in node.named_children(&mut node.walk()) {
+        match statement.kind() {
+            "import_from_statement" => {
+                let Some(module) = statement
+                    .child_by_field_name("module_name")
+                    .and_then(|m| resolve_module(&text(m), module_path, package_root, is_package))
+                else {
+                    continue;
+                };
+                let mut cursor = statement.walk();
+                for child in statement.children_by_field_name("name", &mut cursor) {
+                    let (name, alias) = match child.kind() {
+                        "aliased_import" => {
+                            let name = child.child_by_field_name("name").map(text);
+                            let alias = child.child_by_field_name("alias").map(text);
+                            match (name, alias) {
+                                (Some(name), Some(alias)) => (name, alias),
+                                _ => continue,
+                            }
+                        }
+                        _ => (text(child), text(child)),
+                    };
+                    if name.contains('.') {
+                        continue;
+                    }
+                    exports.reexports.push(Reexport {
+                        module: module.clone(),
+                        name: Some(name),
+                        alias,
+                    });
+                }
+                if statement
+                    .named_children(&mut statement.walk())
+                    .any(|child| child.kind() == "wildcard_import")
+                {
+                    exports.reexports.push(Reexport {
+                        module,
+                        name: None,
+                        alias: String::new(),
+                    });
+                }
+            }
+            "expression_statement" => {
+                let Some(assignment) = statement.named_child(0) else {
+                    continue;
+                };
+                let is_all = assignment
+                    .child_by_field_name("left")
+                    .is_some_and(|left| text(left) == "__all__");
+                if !is_all {
+                    continue;
+                }
+                let names = assignment
+                    .child_by_field_name("right")
+                    .map(|right| string_items(right, source))
+                    .unwrap_or_default();
+                match assignment.kind() {
+                    "assignment" => exports.all = Some(names),
+                    "augmented_assignment" => {
+                        exports.all.get_or_insert_with(Vec::new).extend(names)
+                    }
+                    _ => {}
+                }
+            }
+            // Conditional imports (`try: from ._speedups import x`) still export
+            "if_statement" | "try_statement" | "block" | "else_clause" | "elif_clause"
+            | "except_clause" | "finally_clause" => {
+                collect(
+                    statement,
+                    source,
+                    module_path,
+                    package_root,
+                    is_package,
+                    exports,
+                );
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Absolute path of an imported module, or `None` when it lies outside the
+/// package.
+fn resolve_module(
+    imported: &str,
+    module_path: &str,
+    package_root: &str,
+    is_package: bool,
+) -> Option<String> {
+    let dots = imported.chars().take_while(|c| *c == '.').count();
+    if dots == 0 {
+        let in_package =
+            imported == package_root || imported.starts_with(&format!("{package_root}."));
+        return in_package.then(|| imported.to_string());
+    }
+
+    let mut base: Vec<&str> = module_path.split('.').collect();
+    if !is_package {
+        base.pop();
+    }
+    for _ in 1..dots {
+        base.pop()?;
+    }
+    let rest = &imported[dots..];
+    if !rest.is_empty() {
+        base.extend(rest.split('.'));
+    }
+    (!base.is_empty()).then(|| base.join("."))
+}
+
+fn string_items(node: Node, source: &str) -> Vec<String> {
+    node.named_children(&mut node.walk())
+        .filter(|child| child.kind() == "string")
+        .filter_map(|child| child.utf8_text(source.as_bytes()).ok())
+        .map(|raw| raw.trim_matches(|c| c == '"' || c == '\'').to_string())
+        .filter(|name| !name.is_empty())
+        .collect()
+}
+
+/// Whether a dotted path goes through a `_private` module or names a
+/// `_private` symbol. Dunder names such as `__init__` are public.
+pub fn is_private_path(path: &str) -> bool {
+    path.split('.')
+        .any(|segment| segment.starts_with('_') && !segment.ends_with("__"))
+}
+
+/// A module's top-level definitions and exports.
+pub struct ModuleSymbols<'a> {
+    pub module_path: &'a str,
+    pub defined: HashSet<&'a str>,
+    pub exports: &'a ModuleExports,
+    pub is_package: bool,
+}
+
+/// Resolved public surface of a package.
+#[derive(Debug, Clone, Default)]
+pub struct PublicApi {
+    /// Definition path (`pkg.sessions.Session`) to the other paths it can be
+    /// imported from (`pkg.Session`)
+    aliases: HashMap<String, Vec<String>>,
+    /// Definitions left out of their module's `__all__`
+    unlisted: HashSet<String>,
+}
+
+/// Public paths of one symbol.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PublicPaths {
+    /// Preferred import path: public before private, then shortest
+    pub canonical: String,
+    /// Every other path the symbol is reachable from, including its
+    /// definition path when that is not canonical
+    pub alternates: Vec<String>,
+    /// No public path leads to the symbol
+    pub private: bool,
+}
+
+impl PublicApi {
+    /// Resolve re-export chains across all modules of a package.
+    pub fn build(modules: &[ModuleSymbols]) -> Self {
+        let by_path: HashMap<&str, &ModuleSymbols> =
+            modules.iter().map(|m| (m.module_path, m)).collect();
+        let mut api = PublicApi::default();
+
+        for module in modules {
+            if let Some(all) = &module.exports.all {
+                for name in &module.defined {
+                    if !all.iter().any(|listed| listed == name) {
+                        api.unlisted
+                            .insert(format!("{}.{name}", module.module_path));
+                    }
+                }
+            }
+
+            for name in exported_names(module, &by_path, 0) {
+                if module.defined.contains(name.as_str()) {
+                    continue;
+                }
+                let Some((def_module, def_name)) = resolve(&by_path, module.module_path, &name, 0)
+                else {
+                    continue;
+                };
+                let alias = format!("{}.{name}", module.module_path);
+                let aliases = api
+                    .aliases
+                    .entry(format!("{def_module}.{def_name}"))
+                    .or_default();
+                if !aliases.contains(&alias) {
+                    aliases.push(alias);
+                }
+            }
+        }
+        api
+    }
+
+    /// Public paths of a symbol defined at `qualified_path` in `module_path`.
+    ///
+    /// Members (`Class.method`) follow their top-level container.
+    pub fn paths(&self, module_path: &str, qualified_path: &str) -> PublicPaths {
+        let member = qualified_path
+            .strip_prefix(module_path)
+            .and_then(|rest| rest.strip_prefix('.'))
+            .unwrap_or(qualified_path);
+        let (top, suffix) = match member.split_once('.') {
+            Some((top, rest)) => (top, format!(".{rest}")),
+            None => (member, String::new()),
+        };
+        let definition = format!("{module_path}.{top}");
+
+        let mut candidates = vec![qualified_path.to_string()];
+        if let Some(aliases) = self.aliases.get(&definition) {
+            candidates.extend(aliases.iter().map(|alias| format!("{alias}{suffix}")));
+        }
+        candidates.sort_by(|a, b| {
+            is_private_path(a)
+                .cmp(&is_private_path(b))
+                .then_with(|| a.split('.').count().cmp(&b.split('.').count()))
+                .then_with(|| a.cmp(b))
+        });
+        candidates.dedup();
+
+        let canonical = candidates.remove(0);
+        let reexported = self.aliases.contains_key(&definition);
+        let private =
+            is_private_path(&canonical) || (!reexported && self.unlisted.contains(&definition));
+        PublicPaths {
+            canonical,
+            alternates: candidates,
+            private,
+        }
+    }
+}
+
+/// Names a module makes public: its `__all__`, or for packages without one
+/// every non-underscore name it re-exports.
+fn exported_names(
+    module: &ModuleSymbols,
+    by_path: &HashMap<&str, &ModuleSymbols>,
+    depth: usize,
+) -> Vec<String> {
+    if let Some(all) = &module.exports.all {
+        return all.clone();
+    }
+    if depth > MAX_REEXPORT_DEPTH {
+        return Vec::new();
+    }
+
+    let mut names: Vec<String> = Vec::new();
+    for reexport in &module.exports.reexports {
+        match &reexport.name {
+            Some(_) if module.is_package => names.push(reexport.alias.clone()),
+            Some(_) => {}
+            None => {
+                if let Some(source) = by_path.get(reexport.module.as_str()) {
+                    names.extend(star_names(source, by_path, depth + 1));
+                }
+            }
+        }
+    }
+    names.retain(|name| !name.starts_with('_'));
+    names
+}
+
+/// Names bound by `from module import *`.
+fn star_names(
+    module: &ModuleSymbols,
+    by_path: &HashMap<&str, &ModuleSymbols>,
+    depth: usize,
+) -> Vec<String> {
+    if let Some(all) = &module.exports.all {
+        return all.clone();
+    }
+    let mut names: Vec<String> = module
+        .defined
+        .iter()
+        .filter(|name| !name.starts_with('_'))
+        .map(|name| name.to_string())
+        .collect();
+    names.extend(
+        module
+            .exports
+            .reexports
+            .iter()
+            .filter(|reexport| reexport.name.is_some() && !reexport.alias.starts_with('_'))
+            .map(|reexport| reexport.alias.clone()),
+    );
+    if depth <= MAX_REEXPORT_DEPTH {
+        for reexport in module.exports.reexports.iter().filter(|r| r.name.is_none()) {
+            if let Some(source) = by_path.get(reexport.module.as_str()) {
+                names.extend(star_names(source, by_path, depth + 1));
+            }
+        }
+    }
+    names
+}
+
+/// Module and name of the definition that `name` in `module_path` refers to.
+fn resolve(
+    by_path: &HashMap<&str, &ModuleSymbols>,
+    module_path: &str,
+    name: &str,
+    depth: usize,
+) -> Option<(String, String)> {
+    if depth > MAX_REEXPORT_DEPTH {
+        return None;
+    }
+    let module = by_path.get(module_path)?;
+    if module.defined.contains(name) {
+        return Some((module_path.to_string(), name.to_string()));
+    }
+    for reexport in &module.exports.reexports {
+        match &reexport.name {
+            Some(source_name) if reexport.alias == name => {
+                return resolve(by_path, &reexport.module, source_name, depth + 1);
+            }
+            _ => {}
+        }
+    }
+    module
+        .exports
+        .reexports
+        .iter()
+        .filter(|reexport| reexport.name.is_none())
+        .find_map(|reexport| resolve(by_path, &reexport.module, name, depth + 1))
+}
+
+/// Where a dotted import path is defined.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ItemLocation {
+    /// Module source relative to the package root (`adapters.py`), as the
+    /// `file#item` item paths spell it
+    pub relative_path: String,
+    /// Item within the module, dotted for members (`HTTPAdapter.send`)
+    pub item_name: String,
+}
+
+/// Resolve a dotted import path (`requests.Session`,
+/// `requests.adapters.HTTPAdapter.send`) to the module defining it,
+/// following `__init__` re-exports. The leading package name is optional.
+/// `context_path` selects the environment whose typeshed describes standard
+/// library modules.
+pub fn locate_import_path(
+    package_root: &Path,
+    package_name: &str,
+    dotted: &str,
+    context_path: Option<&Path>,
+) -> Result<ItemLocation> {
+    let import_name = package_root
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .context("Package root has no name")?
+        .to_string();
+    let mut segments: Vec<String> = dotted
+        .trim()
+        .split('.')
+        .map(|segment| segment.trim().to_string())
+        .collect();
+    if segments.iter().any(|segment| segment.is_empty()) {
+        bail!("'{dotted}' is not a dotted import path");
+    }
+    if segments.len() > 1 && segments[0] == import_name {
+        segments.remove(0);
+    }
+
+    let locator = Locator {
+        package_root,
+        stub_dir: finder::find_python_stub_dir(package_root, package_name, context_path),
+        import_name,
+    };
+    let mut parser = Parser::new();
+    parser
+        .set_language(&tree_sitter_python::LANGUAGE.into())
+        .context("Failed to set Python language")?;
+    locator.locate(&mut parser, &segments, 0)?.with_context(|| {
+        format!("'{dotted}' does not name a definition in package '{package_name}'")
+    })
+}
+
+struct Locator<'a> {
+    package_root: &'a Path,
+    stub_dir: Option<PathBuf>,
+    import_name: String,
+}
+
+impl Locator<'_> {
+    fn locate(
+        &self,
+        parser: &mut Parser,
+        segments: &[String],
+        depth: usize,
+    ) -> Result<Option<ItemLocation>> {
+        if depth > MAX_REEXPORT_DEPTH {
+            return Ok(None);
+        }
+        // The longest prefix naming a module; the rest lies within it
+        let Some((split, relative_path, file)) = (0..=segments.len()).rev().find_map(|split| {
+            let (relative_path, file) = self.module_file(&segments[..split])?;
+            Some((split, relative_path, file))
+        }) else {
+            return Ok(None);
+        };
+        let rest = &segments[split..];
+        if rest.is_empty() {
+            bail!(
+                "'{}' is a module; name a class or function inside it",
+                segments.join(".")
+            );
+        }
+
+        let source = std::fs::read_to_string(&file)
+            .with_context(|| format!("Failed to read {}", file.display()))?;
+        let tree = parser
+            .parse(&source, None)
+            .with_context(|| format!("Failed to parse {}", file.display()))?;
+        let mut defined = HashSet::new();
+        collect_defined_names(tree.root_node(), &source, &mut defined);
+        if defined.contains(rest[0].as_str()) {
+            return Ok(Some(ItemLocation {
+                relative_path,
+                item_name: rest.join("."),
+            }));
+        }
+
+        let module_path = std::iter::once(self.import_name.as_str())
+            .chain(segments[..split].iter().map(String::as_str))
+            .collect::<Vec<_>>()
+            .join(".");
+        let is_package = file.file_stem().is_some_and(|stem| stem == "__init__");
+        let exports = extract_module_exports(tree.root_node(), &source, &module_path, is_package);
+        let redirect = |module: &str, name: Option<&str>| -> Vec<String> {
+            module
+                .split('.')
+                .skip(1)
+                .map(str::to_string)
+                .chain(name.map(str::to_string))
+                .chain(rest[1..].iter().cloned())
+                .collect()
+        };
+        if let Some(reexport) = exports
+            .reexports
+            .iter()
+            .find(|reexport| reexport.name.is_some() && reexport.alias == rest[0])
+        {
+            let target = redirect(&reexport.module, reexport.name.as_deref());
+            return self.locate(parser, &target, depth + 1);
+        }
+        for reexport in exports.reexports.iter().filter(|r| r.name.is_none()) {
+            let target = redirect(&reexport.module, Some(&rest[0]));
+            if let Ok(Some(location)) = self.locate(parser, &target, depth + 1) {
+                return Ok(Some(location));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Relative path and readable file (the source, or else its stub) of a
+    /// module inside the package.
+    fn module_file(&self, segments: &[String]) -> Option<(String, PathBuf)> {
+        if self.package_root.is_file() {
+            let file_name = self
+                .package_root
+                .file_name()?
+                .to_string_lossy()
+                .into_owned();
+            return segments
+                .is_empty()
+                .then(|| (file_name, self.package_root.to_path_buf()));
+        }
+        let base = segments.join("/");
+        let candidates = match base.is_empty() {
+            true => vec!["__init__.py".to_string()],
+            false => vec![format!("{base}.py"), format!("{base}/__init__.py")],
+        };
+        let dirs = std::iter::once(self.package_root).chain(self.stub_dir.as_deref());
+        for dir in dirs {
+            for relative_path in &candidates {
+                let source = dir.join(relative_path);
+                if source.is_file() {
+                    return Some((relative_path.clone(), source));
+                }
+                let stub = source.with_extension("pyi");
+                if stub.is_file() {
+                    return Some((relative_path.clone(), stub));
+                }
+            }
+        }
+        None
+    }
+}
+
+/// Names bound at module level by definitions and assignments.
+fn collect_defined_names<'a>(node: Node, source: &'a str, names: &mut HashSet<&'a str>) {
+    for statement in node.named_children(&mut node.walk()) {
+        let statement = match statement.kind() {
+            "decorated_definition" => match statement.child_by_field_name("definition") {
+                Some(definition) => definition,
+                None => continue,
+            },
+            _ => statement,
+        };
+        match statement.kind() {
+            "class_definition" | "function_definition" => {
+                if let Some(name) = statement.child_by_field_name("name") {
+                    names.extend(name.utf8_text(source.as_bytes()).ok());
+                }
+            }
+            "expression_statement" => {
+                let left = statement
+                    .named_child(0)
+                    .filter(|child| child.kind() == "assignment")
+                    .and_then(|assignment| assignment.child_by_field_name("left"))
+                    .filter(|left| left.kind() == "identifier");
+                if let Some(left) = left {
+                    names.extend(left.utf8_text(source.as_bytes()).ok());
+                }
+            }
+            "if_statement" | "try_statement" | "block" | "else_clause" | "elif_clause"
+            | "except_clause" | "finally_clause" => collect_defined_names(statement, source, names),
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::doc_engine::test_support::write;
+
+    #[test]
+    fn locates_dotted_paths_through_reexports() {
+        let temp = tempfile::tempdir().unwrap();
+        let root = temp.path().join("shopkit");
+        write(
+            &root,
+            "__init__.py",
+            "from .sessions import Session\nfrom .api import *\nfrom ._native import Codec as Encoder\n",
+        );
+        write(&root, "api.py", "from .helpers import get\n");
+        write(&root, "helpers.py", "def get(url):\n    pass\n");
+        write(
+            &root,
+            "sessions.py",
+            "class Session:\n    def send(self):\n        pass\n",
+        );
+        write(&root, "_native.pyi", "class Codec: ...\n");
+
+        let locate = |dotted: &str| {
+            let location = locate_import_path(&root, "shopkit", dotted, None).unwrap();
+            (location.relative_path, location.item_name)
+        };
+        let expect = |path: &str, item: &str| (path.to_string(), item.to_string());
+
+        assert_eq!(locate("shopkit.Session"), expect("sessions.py", "Session"));
+        assert_eq!(
+            locate("Session.send"),
+            expect("sessions.py", "Session.send")
+        );
+        assert_eq!(
+            locate("shopkit.sessions.Session.send"),
+            expect("sessions.py", "Session.send")
+        );
+        assert_eq!(locate("shopkit.get"), expect("helpers.py", "get"));
+        assert_eq!(locate("shopkit.Encoder"), expect("_native.py", "Codec"));
+
+        let missing = locate_import_path(&root, "shopkit", "shopkit.Missing", None).unwrap_err();
+        assert!(missing.to_string().contains("does not name a definition"));
+        let module = locate_import_path(&root, "shopkit", "shopkit.sessions", None).unwrap_err();
+        assert!(module.to_string().contains("is a module"));
+    }
+}
@@ -0,0 +1,607 @@
+//! Inherited methods of Python classes.
+//!
+//! Base classes are resolved through the imports of the module defining each
+//! class: within the package, in other installed packages and in the
+//! standard library, plus typeshed's `builtins.pyi` for `dict` and friends
+//! when a type checker bundles it. Methods are then collected along the C3
+//! method resolution order, as Python computes it.
+
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Context, Result};
+use tree_sitter::{Node, Parser};
+
+use crate::doc_engine::finder;
+use crate::doc_engine::python_analyzer::{self, MethodInfo};
+
+/// Limit for import chains and class hierarchies (and guard against cycles).
+const MAX_RESOLVE_DEPTH: usize = 16;
+
+/// Bases that contribute no API worth listing.
+const IGNORED_BASES: [&str; 3] = ["object", "Generic", "Protocol"];
+
+/// A class identified by its defining module.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct ClassKey {
+    module: String,
+    name: String,
+}
+
+impl ClassKey {
+    fn qualified(&self) -> String {
+        format!("{}.{}", self.module, self.name)
+    }
+}
+
+/// What a module-level name was imported as.
+#[derive(Debug, Clone)]
+enum Binding {
+    /// `import a.b` binds `a`; `import a.b as x` binds `x` to `a.b`
+    Module(String),
+    /// `from module import name`
+    Name { module: String, name: String },
+}
+
+#[derive(Debug)]
+struct Module {
+    source: String,
+    /// Top-level classes with their base class expressions
+    classes: HashMap<String, Vec<String>>,
+    bindings: HashMap<String, Binding>,
+    star_imports: Vec<String>,
+}
+
+/// Resolves classes and their ancestors across modules, caching every
+/// module it parses.
+pub struct ClassHierarchy {
+    /// Directories modules are looked up in, in order
+    roots: Vec<PathBuf>,
+    parser: Parser,
+    modules: HashMap<String, Option<Module>>,
+    linearizations: HashMap<ClassKey, Vec<ClassKey>>,
+    in_progress: HashSet<ClassKey>,
+}
+
+impl ClassHierarchy {
+    /// Hierarchy for classes of the package at `package_root`.
+    ///
+    /// Modules resolve against the directory containing the package (its
+    /// site-packages, for installed packages), the interpreter's standard
+    /// library and typeshed's standard library stubs.
+    pub fn for_package(package_root: &Path) -> Result<Self> {
+        let site_dir = package_root
+            .parent()
+            .context("Package root has no parent directory")?;
+        let mut roots = vec![site_dir.to_path_buf()];
+        roots.extend(finder::find_python_stdlib(site_dir));
+        roots.extend(finder::find_typeshed_stdlib(site_dir));
+
+        let mut parser = Parser::new();
+        parser
+            .set_language(&tree_sitter_python::LANGUAGE.into())
+            .context("Failed to set Python language")?;
+        Ok(Self {
+            roots,
+            parser,
+            modules: HashMap::new(),
+            linearizations: HashMap::new(),
+            in_progress: HashSet::new(),
+        })
+    }
+
+    /// Append the methods `class_name` inherits to its own `methods`.
+    ///
+    /// `module_file` is the source (or stub) defining the class. Methods are
+    /// tagged with their defining class; a method is flagged as an override
+    /// when a class later in the MRO defines the same name.
+    pub fn add_inherited(
+        &mut self,
+        module_file: &Path,
+        class_name: &str,
+        methods: &mut Vec<MethodInfo>,
+        include_private: bool,
+    ) -> Result<()> {
+        let module = self
+            .module_name(module_file)
+            .with_context(|| format!("{} is outside the package", module_file.display()))?;
+        if !self.modules.contains_key(&module) {
+            let parsed = self.parse_file(&module, module_file)?;
+            self.modules.insert(module.clone(), Some(parsed));
+        }
+        let class = ClassKey {
+            module,
+            name: class_name.to_string(),
+        };
+        if self.class_bases(&class).is_none() {
+            return Err(anyhow!(
+                "Class '{class_name}' not found in {}",
+                module_file.display()
+            ));
+        }
+
+        let mut defined: HashMap<String, usize> = HashMap::new();
+        for method in methods.iter_mut() {
+            method.defining_class = Some(class.qualified());
+        }
+        for (position, method) in methods.iter().enumerate() {
+            defined.entry(method.name.clone()).or_insert(position);
+        }
+
+        for ancestor in self.linearize(&class, 0).into_iter().skip(1) {
+            let Some(Some(module)) = self.modules.get(&ancestor.module) else {
+                continue;
+            };
+            let Ok(ancestor_methods) = python_analyzer::extract_class_methods(
+                &module.source,
+                &ancestor.name,
+                include_private,
+            ) else {
+                continue;
+            };
+            let mut seen_here = HashSet::new();
+            for mut method in ancestor_methods {
+                // Several `@overload`s of one method
+                if !seen_here.insert(method.name.clone()) {
+                    continue;
+                }
+                if let Some(&position) = defined.get(&method.name) {
+                    methods[position].is_override = true;
+                    continue;
+                }
+                method.defining_class = Some(ancestor.qualified());
+                defined.insert(method.name.clone(), methods.len());
+                methods.push(method);
+            }
+        }
+        Ok(())
+    }
+
+    /// C3 linearization: the class followed by a merge of its bases'
+    /// linearizations that preserves each one's order. Inconsistent
+    /// hierarchies, which Python would reject, fall back to depth-first
+    /// order.
+    fn linearize(&mut self, class: &ClassKey, depth: usize) -> Vec<ClassKey> {
+        if let Some(linearization) = self.linearizations.get(class) {
+            return linearization.clone();
+        }
+        if depth > MAX_RESOLVE_DEPTH || !self.in_progress.insert(class.clone()) {
+            return vec![class.clone()];
+        }
+
+        let bases: Vec<ClassKey> = self
+            .class_bases(class)
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|base| self.resolve_base(&class.module, base))
+            .filter(|base| base != class)
+            .collect();
+        let mut sequences: Vec<Vec<ClassKey>> = bases
+            .iter()
+            .map(|base| self.linearize(base, depth + 1))
+            .collect();
+        sequences.push(bases);
+
+        let mut linearization = vec![class.clone()];
+        match c3_merge(sequences.clone()) {
+            Some(merged) => linearization.extend(merged),
+            None => {
+                for ancestor in sequences.into_iter().flatten() {
+                    if !linearization.contains(&ancestor) {
+                        linearization.push(ancestor);
+                    }
+                }
+            }
+        }
+        self.in_progress.remove(class);
+        self.linearizations
+            .insert(class.clone(), linearization.clone());
+        linearization
+    }
+
+    fn class_bases(&mut self, class: &ClassKey) -> Option<Vec<String>> {
+        self.module(&class.module)?
+            .classes
+            .get(&class.name)
+            .cloned()
+    }
+
+    /// Class a base class expression in `module` refers to.
+    fn resolve_base(&mut self, module: &str, expression: &str) -> Option<ClassKey> {
+        // `Mapping[str, int]` subclasses `Mapping`
+        let expression = expression.split('[').next().unwrap_or(expression).trim();
+        let last = expression.rsplit('.').next().unwrap_or(expression);
+        if IGNORED_BASES.contains(&last) {
+            return None;
+        }
+
+        let Some((path, name)) = expression.rsplit_once('.') else {
+            return self
+                .resolve_name(module, expression, 0)
+                .or_else(|| self.resolve_name("builtins", expression, 0));
+        };
+        let (head, rest) = match path.split_once('.') {
+            Some((head, rest)) => (head, Some(rest)),
+            None => (path, None),
+        };
+        let base_module = match self.module(module)?.bindings.get(head).cloned() {
+            Some(Binding::Module(bound)) => bound,
+            Some(Binding::Name { module, name }) => format!("{module}.{name}"),
+            None => head.to_string(),
+        };
+        let target = match rest {
+            Some(rest) => format!("{base_module}.{rest}"),
+            None => base_module,
+        };
+        self.resolve_name(&target, name, 0)
+    }
+
+    /// Class bound to `name` in `module`, following re-exports.
+    fn resolve_name(&mut self, module: &str, name: &str, depth: usize) -> Option<ClassKey> {
+        if depth > MAX_RESOLVE_DEPTH {
+            return None;
+        }
+        let parsed = self.module(module)?;
+        if parsed.classes.contains_key(name) {
+            return Some(ClassKey {
+                module: module.to_string(),
+                name: name.to_string(),
+            });
+        }
+        let binding = parsed.bindings.get(name).cloned();
+        let star_imports = parsed.star_imports.clone();
+        if let Some(Binding::Name {
+            module: source,
+            name: source_name,
+        }) = binding
+        {
+            return self.resolve_name(&source, &source_name, depth + 1);
+        }
+        star_imports
+            .iter()
+            .find_map(|source| self.resolve_name(source, name, depth + 1))
+    }
+
+    fn module(&mut self, name: &str) -> Option<&Module> {
+        if !self.modules.contains_key(name) {
+            let parsed = self
+                .module_file(name)
+                .and_then(|path| self.parse_file(name, &path).ok());
+            self.modules.insert(name.to_string(), parsed);
+        }
+        self.modules.get(name)?.as_ref()
+    }
+
+    /// Source (or stub) of a dotted module name.
+    fn module_file(&self, name: &str) -> Option<PathBuf> {
+        let relative: PathBuf = name.split('.').collect();
+        self.roots.iter().find_map(|root| {
+            let base = root.join(&relative);
+            [
+                base.with_extension("py"),
+                base.join("__init__.py"),
+                base.with_extension("pyi"),
+                base.join("__init__.pyi"),
+            ]
+            .into_iter()
+            .find(|path| path.is_file())
+        })
+    }
+
+    /// Dotted module name of a file under one of the roots. Files of a
+    /// `<pkg>-stubs` distribution describe `pkg`.
+    fn module_name(&self, path: &Path) -> Option<String> {
+        let relative = self
+            .roots
+            .iter()
+            .find_map(|root| path.strip_prefix(root).ok())?;
+        let mut segments: Vec<String> = relative
+            .with_extension("")
+            .iter()
+            .map(|segment| segment.to_string_lossy().into_owned())
+            .collect();
+        if segments.last().is_some_and(|last| last == "__init__") {
+            segments.pop();
+        }
+        if let Some(first) = segments.first_mut() {
+            if let Some(package) = first.strip_suffix("-stubs") {
+                *first = package.to_string();
+            }
+        }
+        (!segments.is_empty()).then(|| segments.join("."))
+    }
+
+    fn parse_file(&mut self, name: &str, path: &Path) -> Result<Module> {
+        let source = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let tree = self
+            .parser
+            .parse(&source, None)
+            .with_context(|| format!("Failed to parse {}", path.display()))?;
+        let is_package = path.file_stem().is_some_and(|stem| stem == "__init__");
+        let mut module = Module {
+            source: String::new(),
+            classes: HashMap::new(),
+            bindings: HashMap::new(),
+            star_imports: Vec::new(),
+        };
+        collect_module(tree.root_node(), &source, name, is_package, &mut module);
+        module.source = source;
+        Ok(module)
+    }
+}
+
+/// Merge step of C3: repeatedly take the first head that appears in no
+/// other sequence's tail.
+fn c3_merge(mut sequences: Vec<Vec<ClassKey>>) -> Option<Vec<ClassKey>> {
+    let mut merged = Vec::new();
+    loop {
+        sequences.retain(|sequence| !sequence.is_empty());
+        if sequences.is_empty() {
+            return Some(merged);
+        }
+        let head = sequences
+            .iter()
+            .map(|sequence| &sequence[0])
+            .find(|candidate| {
+                !sequences
+                    .iter()
+                    .any(|sequence| sequence[1..].contains(candidate))
+            })?
+            .clone();
+        for sequence in &mut sequences {
+            if sequence[0] == head {
+                sequence.remove(0);
+            }
+        }
+        merged.push(head);
+    }
+}
+
+/// Record top-level classes and imports, including those under `if` and
+/// `try` blocks.
+fn collect_module(node: Node, source: &str, name: &str, is_package: bool, module: &mut Module) {
+    let text = |node: Node| node.utf8_text(source.as_bytes()).unwrap_or("").to_string();
+    for statement in node.named_children(&mut node.walk()) {
+        let statement = match statement.kind() {
+            "decorated_definition" => match statement.child_by_field_name("definition") {
+                Some(definition) => definition,
+                None => continue,
+            },
+            _ => statement,
+        };
+        match statement.kind() {
+            "class_definition" => {
+                let Some(class_name) = statement.child_by_field_name("name").map(text) else {
+                    continue;
+                };
+                let bases = statement
+                    .child_by_field_name("superclasses")
+                    .map(|arguments| {
+                        arguments
+                            .named_children(&mut arguments.walk())
+                            .filter(|argument| {
+                                !matches!(
+                                    argument.kind(),
+                                    "keyword_argument"
+                                        | "comment"
+                                        | "list_splat"
+                                        | "dictionary_splat"
+                                )
+                            })
+                            .map(text)
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                module.classes.insert(class_name, bases);
+            }
+            "import_statement" => {
+                let mut cursor = statement.walk();
+                for imported in statement.children_by_field_name("name", &mut cursor) {
+                    match imported.kind() {
+                        "aliased_import" => {
+                            let target = imported.child_by_field_name("name").map(text);
+                            let alias = imported.child_by_field_name("alias").map(text);
+                            if let (Some(target), Some(alias)) = (target, alias) {
+                                module.bindings.insert(alias, Binding::Module(target));
+                            }
+                        }
+                        _ => {
+                            let dotted = text(imported);
+                            let head = dotted.split('.').next().unwrap_or(&dotted).to_string();
+                            module.bindings.insert(head.clone(), Binding::Module(head));
+                        }
+                    }
+                }
+            }
+            "import_from_statement" => {
+                let Some(source_module) = statement
+                    .child_by_field_name("module_name")
+                    .and_then(|m| absolute_module(&text(m), name, is_package))
+                else {
+                    continue;
+                };
+                let mut cursor = statement.walk();
+                for imported in statement.children_by_field_name("name", &mut cursor) {
+                    let (imported_name, alias) = match imported.kind() {
+                        "aliased_import" => {
+                            let target = imported.child_by_field_name("name").map(text);
+                            let alias = imported.child_by_field_name("alias").map(text);
+                            match (target, alias) {
+                                (Some(target), Some(alias)) => (target, alias),
+                                _ => continue,
+                            }
+                        }
+                        _ => (text(imported), text(imported)),
+                    };
+                    module.bindings.insert(
+                        alias,
+                        Binding::Name {
+                            module: source_module.clone(),
+                            name: imported_name,
+                        },
+                    );
+                }
+                if statement
+                    .named_children(&mut statement.walk())
+                    .any(|child| child.kind() == "wildcard_import")
+                {
+                    module.star_imports.push(source_module);
+                }
+            }
+            "if_statement" | "try_statement" | "block" | "else_clause" | "elif_clause"
+            | "except_clause" | "finally_clause" => {
+                collect_module(statement, source, name, is_package, module);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Absolute name of a module imported from `module`, resolving leading dots.
+fn absolute_module(imported: &str, module: &str, is_package: bool) -> Option<String> {
+    let dots = imported.chars().take_while(|c| *c == '.').count();
+    if dots == 0 {
+        return Some(imported.to_string());
+    }
+    let mut base: Vec<&str> = module.split('.').collect();
+    if !is_package {
+        base.pop();
+    }
+    for _ in 1..dots {
+        base.pop()?;
+    }
+    let rest = &imported[dots..];
+    if !rest.is_empty() {
+        base.extend(rest.split('.'));
+    }
+    (!base.is_empty()).then(|| base.join("."))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::doc_engine::test_support::write;
+    use std::fs;
+
+    fn names(methods: &[MethodInfo]) -> Vec<(&str, &str, bool)> {
+        methods
+            .iter()
+            .map(|m| {
+                (
+                    m.name.as_str(),
+                    m.defining_class.as_deref().unwrap_or(""),
+                    m.is_override,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn collects_methods_along_the_c3_mro_across_packages_and_stdlib() {
+        let temp = tempfile::tempdir().unwrap();
+        let base_python = temp.path().join("base");
+        let stdlib = base_python.join("lib/python3.12");
+        write(&stdlib, "os.py", "");
+        write(
+            &stdlib,
+            "collections/abc.py",
+            "from _collections_abc import *\n",
+        );
+        write(
+            &stdlib,
+            "_collections_abc.py",
+            r#"
+class Mapping:
+    def get(self, key, default=None):
+        """D.get(k[,d]) -> D[k] if k in D, else d."""
+    def keys(self):
+        """A set-like object providing a view on D's keys."""
+"#,
+        );
+
+        let venv = temp.path().join("venv");
+        write(
+            &venv,
+            "pyvenv.cfg",
+            &format!("home = {}\n", base_python.join("bin").display()),
+        );
+        let site = venv.join("lib/python3.12/site-packages");
+        write(
+            &site,
+            "basekit/__init__.py",
+            r#"
+class Loggable:
+    def log(self, message):
+        """Write a log line."""
+    def describe(self):
+        """Describe the object."""
+"#,
+        );
+        write(
+            &site,
+            "app/models.py",
+            r#"
+import collections.abc as cabc
+from basekit import Loggable
+from .mixins import Cached
+
+class Record(Cached, Loggable, cabc.Mapping):
+    def describe(self):
+        """Describe the record."""
+    def get(self, key, default=None):
+        """Look up a field."""
+"#,
+        );
+        write(
+            &site,
+            "app/mixins.py",
+            r#"
+from basekit import Loggable
+
+class Cached(Loggable):
+    def invalidate(self):
+        """Drop cached values."""
+    def log(self, message):
+        """Log with cache statistics."""
+"#,
+        );
+
+        let models = site.join("app/models.py");
+        let source = fs::read_to_string(&models).unwrap();
+        let mut methods = python_analyzer::extract_class_methods(&source, "Record", false).unwrap();
+        let mut hierarchy = ClassHierarchy::for_package(&site.join("app")).unwrap();
+        hierarchy
+            .add_inherited(&models, "Record", &mut methods, false)
+            .unwrap();
+
+        assert_eq!(
+            names(&methods),
+            vec![
+                ("describe", "app.models.Record", true),
+                ("get", "app.models.Record", true),
+                ("invalidate", "app.mixins.Cached", false),
+                ("log", "app.mixins.Cached", true),
+                ("keys", "_collections_abc.Mapping", false),
+            ]
+        );
+    }
+
+    #[test]
+    fn c3_rejects_inconsistent_orders() {
+        let key = |name: &str| ClassKey {
+            module: "m".to_string(),
+            name: name.to_string(),
+        };
+        let (a, b) = (key("A"), key("B"));
+        assert_eq!(
+            c3_merge(vec![
+                vec![a.clone()],
+                vec![b.clone()],
+                vec![a.clone(), b.clone()]
+            ]),
+            Some(vec![a.clone(), b.clone()])
+        );
+        assert_eq!(c3_merge(vec![vec![a.clone(), b.clone()], vec![b, a]]), None);
+    }
+}
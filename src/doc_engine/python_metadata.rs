@@ -0,0 +1,387 @@
+//! Metadata of installed Python distributions (`*.dist-info`).
+//!
+//! A distribution's name need not match the packages it installs (`Pillow`
+//! provides `PIL`, `PyYAML` provides `yaml`), so distributions are found by
+//! either name.
+
+use anyhow::{Context, Result};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::doc_engine::types::{PythonEntryPoint, PythonPackageInfo, PythonRequirement};
+
+/// PEP 503 normalization: case-insensitive, with runs of `-`, `_` and `.`
+/// equivalent.
+pub fn normalize_distribution_name(name: &str) -> String {
+    let mut normalized = String::with_capacity(name.len());
+    for c in name.trim().chars() {
+        if matches!(c, '-' | '_' | '.') {
+            if !normalized.ends_with('-') {
+                normalized.push('-');
+            }
+        } else {
+            normalized.push(c.to_ascii_lowercase());
+        }
+    }
+    normalized
+}
+
+/// Finds the `*.dist-info` directory in `site_packages` of the distribution
+/// named `name`, or else of the one installing an import package `name`.
+pub fn find_dist_info(site_packages: &Path, name: &str) -> Option<PathBuf> {
+    let wanted = normalize_distribution_name(name);
+    let dist_infos: Vec<PathBuf> = fs::read_dir(site_packages)
+        .ok()?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "dist-info"))
+        .collect();
+
+    let by_name = dist_infos.iter().find(|dist_info| {
+        let stem = dist_info
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or_default();
+        let dist_name = stem.split_once('-').map_or(stem, |(name, _)| name);
+        normalize_distribution_name(dist_name) == wanted
+    });
+    by_name
+        .or_else(|| {
+            dist_infos.iter().find(|dist_info| {
+                import_names(dist_info)
+                    .iter()
+                    .any(|import| normalize_distribution_name(import) == wanted)
+            })
+        })
+        .cloned()
+}
+
+/// Version recorded in a distribution's `METADATA`.
+pub fn dist_info_version(dist_info: &Path) -> Option<String> {
+    let metadata = fs::read_to_string(dist_info.join("METADATA")).ok()?;
+    metadata_headers(&metadata)
+        .into_iter()
+        .find(|(key, _)| key.eq_ignore_ascii_case("Version"))
+        .map(|(_, version)| version)
+}
+
+/// Top-level import names a distribution installs: `top_level.txt` when
+/// present, else the top-level modules and packages listed in `RECORD`.
+pub fn import_names(dist_info: &Path) -> Vec<String> {
+    if let Ok(top_level) = fs::read_to_string(dist_info.join("top_level.txt")) {
+        let mut names: Vec<String> = top_level
+            .lines()
+            .map(|line| line.trim().replace('/', "."))
+            .filter(|line| !line.is_empty())
+            .collect();
+        names.sort();
+        names.dedup();
+        return names;
+    }
+
+    let Ok(record) = fs::read_to_string(dist_info.join("RECORD")) else {
+        return Vec::new();
+    };
+    let mut names: Vec<String> = record
+        .lines()
+        .filter_map(|line| {
+            let path = line.split(',').next()?.trim_matches('"');
+            let (first, nested) = match path.split_once('/') {
+                Some((first, _)) => (first, true),
+                None => (path, false),
+            };
+            let skip = first.is_empty()
+                || first == ".."
+                || first == "__pycache__"
+                || first.ends_with(".dist-info")
+                || first.ends_with(".data");
+            if skip {
+                return None;
+            }
+            if nested {
+                return Some(first.to_string());
+            }
+            // `six.py`, `_cffi_backend.cpython-312-x86_64-linux-gnu.so`
+            let (module, extension) = first.split_once('.')?;
+            let is_module = extension == "py"
+                || extension == "pyi"
+                || extension.ends_with("so")
+                || extension.ends_with("pyd");
+            is_module.then(|| module.to_string())
+        })
+        .collect();
+    names.sort();
+    names.dedup();
+    names
+}
+
+/// Read version, requirements, entry points and import names of the
+/// distribution at `dist_info`.
+pub fn read_package_info(dist_info: &Path) -> Result<PythonPackageInfo> {
+    let metadata_path = dist_info.join("METADATA");
+    let metadata = fs::read_to_string(&metadata_path)
+        .with_context(|| format!("Failed to read {}", metadata_path.display()))?;
+    let headers = metadata_headers(&metadata);
+    let single = |key: &str| {
+        headers
+            .iter()
+            .find(|(header, _)| header.eq_ignore_ascii_case(key))
+            .map(|(_, value)| value.clone())
+            .filter(|value| !value.is_empty() && value != "UNKNOWN")
+    };
+    let multiple = |key: &str| -> Vec<String> {
+        headers
+            .iter()
+            .filter(|(header, _)| header.eq_ignore_ascii_case(key))
+            .map(|(_, value)| value.clone())
+            .collect()
+    };
+
+    let classifiers = multiple("Classifier");
+    // Older metadata only names the license through a classifier
+    let license = single("License-Expression")
+        .or_else(|| single("License").filter(|license| !license.contains('\n')))
+        .or_else(|| {
+            classifiers
+                .iter()
+                .filter_map(|classifier| classifier.strip_prefix("License ::"))
+                .filter_map(|license| license.rsplit("::").next())
+                .map(|license| license.trim().to_string())
+                .next()
+        });
+    let project_urls = multiple("Project-URL")
+        .into_iter()
+        .filter_map(|entry| {
+            let (label, url) = entry.split_once(',')?;
+            Some((label.trim().to_string(), url.trim().to_string()))
+        })
+        .collect();
+
+    let mut entry_points = read_entry_points(dist_info);
+    let console_scripts = entry_points.remove("console_scripts").unwrap_or_default();
+    let gui_scripts = entry_points.remove("gui_scripts").unwrap_or_default();
+
+    Ok(PythonPackageInfo {
+        name: single("Name").unwrap_or_else(|| {
+            let stem = dist_info
+                .file_stem()
+                .and_then(|s| s.to_str())
+                .unwrap_or_default();
+            stem.split_once('-')
+                .map_or(stem, |(name, _)| name)
+                .to_string()
+        }),
+        version: single("Version").unwrap_or_default(),
+        summary: single("Summary"),
+        license,
+        requires_python: single("Requires-Python"),
+        requires_dist: multiple("Requires-Dist")
+            .iter()
+            .map(|requirement| parse_requirement(requirement))
+            .collect(),
+        provides_extras: multiple("Provides-Extra"),
+        classifiers,
+        home_page: single("Home-page"),
+        project_urls,
+        author: single("Author").or_else(|| single("Author-email")),
+        console_scripts,
+        gui_scripts,
+        entry_points,
+        import_names: import_names(dist_info),
+        dist_info_path: dist_info.to_string_lossy().into_owned(),
+    })
+}
+
+/// Headers of an RFC 822 style `METADATA` file, up to the description
+/// body. Continuation lines are joined to their header with newlines.
+fn metadata_headers(metadata: &str) -> Vec<(String, String)> {
+    let mut headers: Vec<(String, String)> = Vec::new();
+    for line in metadata.lines() {
+        if line.trim().is_empty() {
+            break;
+        }
+        if line.starts_with([' ', '\t']) {
+            if let Some((_, value)) = headers.last_mut() {
+                value.push('\n');
+                value.push_str(line.trim());
+            }
+            continue;
+        }
+        if let Some((key, value)) = line.split_once(':') {
+            headers.push((key.trim().to_string(), value.trim().to_string()));
+        }
+    }
+    headers
+}
+
+/// Parse a PEP 508 requirement such as
+/// `requests[socks] (>=2.0) ; extra == "http"`.
+fn parse_requirement(requirement: &str) -> PythonRequirement {
+    let (spec, marker) = match requirement.split_once(';') {
+        Some((spec, marker)) => (spec.trim(), Some(marker.trim().to_string())),
+        None => (requirement.trim(), None),
+    };
+    let name_end = spec
+        .find(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.')))
+        .unwrap_or(spec.len());
+    let name = spec[..name_end].to_string();
+    let mut rest = spec[name_end..].trim();
+
+    let mut extras = Vec::new();
+    if let Some(stripped) = rest.strip_prefix('[') {
+        if let Some((list, after)) = stripped.split_once(']') {
+            extras = list
+                .split(',')
+                .map(|extra| extra.trim().to_string())
+                .filter(|extra| !extra.is_empty())
+                .collect();
+            rest = after.trim();
+        }
+    }
+    let specifier = rest
+        .trim_start_matches('(')
+        .trim_end_matches(')')
+        .trim()
+        .to_string();
+
+    PythonRequirement {
+        name,
+        extras,
+        specifier: (!specifier.is_empty()).then_some(specifier),
+        extra: marker.as_deref().and_then(marker_extra),
+        marker,
+    }
+}
+
+/// The extra a requirement belongs to, from `extra == "name"` in its marker.
+fn marker_extra(marker: &str) -> Option<String> {
+    let mut rest = marker;
+    while let Some(position) = rest.find("extra") {
+        let after = rest[position + "extra".len()..].trim_start();
+        if let Some(value) = after.strip_prefix("==") {
+            let value = value.trim_start();
+            let quote = value.chars().next().filter(|c| *c == '"' || *c == '\'')?;
+            let value = &value[1..];
+            let end = value.find(quote)?;
+            return Some(value[..end].to_string());
+        }
+        rest = after;
+    }
+    None
+}
+
+/// Entry points by group, from `entry_points.txt`.
+fn read_entry_points(dist_info: &Path) -> BTreeMap<String, Vec<PythonEntryPoint>> {
+    let mut groups: BTreeMap<String, Vec<PythonEntryPoint>> = BTreeMap::new();
+    let Ok(contents) = fs::read_to_string(dist_info.join("entry_points.txt")) else {
+        return groups;
+    };
+    let mut group: Option<String> = None;
+    for line in contents.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with(['#', ';']) {
+            continue;
+        }
+        if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            group = Some(name.trim().to_string());
+            continue;
+        }
+        let (Some(group), Some((name, target))) = (&group, line.split_once('=')) else {
+            continue;
+        };
+        groups
+            .entry(group.clone())
+            .or_default()
+            .push(PythonEntryPoint {
+                name: name.trim().to_string(),
+                target: target.trim().to_string(),
+            });
+    }
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_dist_info_of_a_renamed_distribution() {
+        let temp = tempfile::tempdir().unwrap();
+        let site_packages = temp.path();
+        let dist_info = site_packages.join("pillow-10.4.0.dist-info");
+        fs::create_dir_all(&dist_info).unwrap();
+        fs::write(
+            dist_info.join("METADATA"),
+            "Metadata-Version: 2.1\n\
+             Name: pillow\n\
+             Version: 10.4.0\n\
+             Summary: Python Imaging Library (Fork)\n\
+             Requires-Python: >=3.8\n\
+             Classifier: Development Status :: 6 - Mature\n\
+             Classifier: License :: OSI Approved :: Historical Permission Notice and Disclaimer (HPND)\n\
+             Project-URL: Source, https://github.com/python-pillow/Pillow\n\
+             Provides-Extra: docs\n\
+             Requires-Dist: furo ; extra == 'docs'\n\
+             Requires-Dist: typing-extensions[all] (>=4.0) ; python_version < \"3.10\"\n\
+             \n\
+             Long description\n",
+        )
+        .unwrap();
+        fs::write(
+            dist_info.join("RECORD"),
+            "PIL/__init__.py,sha256=abc,100\n\
+             PIL/_imaging.cpython-312-x86_64-linux-gnu.so,,\n\
+             pillow-10.4.0.dist-info/METADATA,,\n\
+             ../../../bin/pil-tool,,\n",
+        )
+        .unwrap();
+        fs::write(
+            dist_info.join("entry_points.txt"),
+            "[console_scripts]\npil-tool = PIL.cli:main\n\n[pillow.plugins]\nwebp = PIL.WebP\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            find_dist_info(site_packages, "Pillow"),
+            Some(dist_info.clone())
+        );
+        assert_eq!(
+            find_dist_info(site_packages, "PIL"),
+            Some(dist_info.clone())
+        );
+        assert_eq!(dist_info_version(&dist_info).as_deref(), Some("10.4.0"));
+
+        let info = read_package_info(&dist_info).unwrap();
+        assert_eq!(info.name, "pillow");
+        assert_eq!(
+            info.summary.as_deref(),
+            Some("Python Imaging Library (Fork)")
+        );
+        assert_eq!(
+            info.license.as_deref(),
+            Some("Historical Permission Notice and Disclaimer (HPND)")
+        );
+        assert_eq!(info.import_names, vec!["PIL"]);
+        assert_eq!(info.console_scripts[0].target, "PIL.cli:main");
+        assert_eq!(info.entry_points["pillow.plugins"][0].name, "webp");
+        assert_eq!(
+            info.project_urls.get("Source").map(String::as_str),
+            Some("https://github.com/python-pillow/Pillow")
+        );
+
+        let furo = &info.requires_dist[0];
+        assert_eq!(furo.name, "furo");
+        assert_eq!(furo.extra.as_deref(), Some("docs"));
+        let typing = &info.requires_dist[1];
+        assert_eq!(typing.extras, vec!["all"]);
+        assert_eq!(typing.specifier.as_deref(), Some(">=4.0"));
+        assert_eq!(typing.extra, None);
+        assert_eq!(typing.marker.as_deref(), Some("python_version < \"3.10\""));
+    }
+
+    #[test]
+    fn normalizes_distribution_names() {
+        assert_eq!(normalize_distribution_name("Foo.Bar__baz"), "foo-bar-baz");
+        assert_eq!(normalize_distribution_name("PyYAML"), "pyyaml");
+    }
+}
@@ -1,37 +1,189 @@
 //! Semantic indexing and search for local Python packages.
 //!
 //! This module builds a lightweight semantic index for Python packages by
-//! extracting public symbols (functions/classes), docstrings, and structural
-//! metadata from local source files. The index provides cosine-similarity
-//! search over TF‑IDF vectors derived from symbol names, docstrings, and
-//! module context — enabling natural-language discovery of functionality.
+//! extracting functions, classes, methods (`Class.method`), properties,
+//! module-level constants and type aliases together with their docstrings
+//! and structural metadata from local source files. Queries are ranked with BM25F over
+//! separate name, signature, docstring and module-path fields, expanded with
+//! a built-in abbreviation/synonym table and with terms fed back from the
+//! package's own best-matching docstrings — enabling natural-language
+//! discovery of functionality without any embedding model.
+//!
+//! Symbols are reported under their public import path: re-exports from
+//! `__init__.py` and `__all__` lists are resolved (see
+//! [`python_exports`](crate::doc_engine::python_exports)), other paths are
+//! listed as alternates, and symbols reachable only through private modules
+//! rank lower.
+//!
+//! `.pyi` stubs next to the package or in a sibling `<pkg>-stubs`
+//! distribution supply typed signatures, and stand in for compiled extension
+//! modules that have no Python source.
 
 use std::{
-    cmp::Ordering,
-    collections::{HashMap, HashSet},
+    collections::{BTreeMap, HashMap, HashSet},
     fs,
     path::{Path, PathBuf},
+    sync::OnceLock,
+    time::SystemTime,
 };
 
 use anyhow::{anyhow, Context, Result};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use tree_sitter::{Node, Parser};
 use walkdir::WalkDir;
 
+use crate::doc_engine::finder;
+use crate::doc_engine::health::ValidationCollector;
+use crate::doc_engine::python_docstring::parse_docstring;
+use crate::doc_engine::python_exports::{
+    extract_module_exports, ModuleExports, ModuleSymbols, PublicApi, PublicPaths,
+};
+use crate::doc_engine::python_metadata;
+use crate::index_core::{
+    identifier_tokens, text_tokens, Bm25Corpus, Bm25Field, CompletionIndex, FieldTerms,
+    ValidationResult,
+};
 use crate::shared_types::SemanticSearchResult;
 
 const MAX_SNIPPET_LINES: usize = 6;
 const MAX_DOC_PREVIEW_CHARS: usize = 200;
 
+/// Bumped whenever the persisted index layout or entry extraction changes.
+const INDEX_FORMAT_VERSION: u32 = 7;
+
+/// Score multiplier for symbols only reachable through private modules or
+/// left out of `__all__`.
+const PRIVATE_SCORE_FACTOR: f32 = 0.5;
+
 /// Semantic index for a Python package.
 #[derive(Debug)]
 pub struct PythonSemanticIndex {
     package_name: String,
     package_root: PathBuf,
+    package_version: Option<String>,
+    /// Directory whose Python environment supplies typeshed stubs for
+    /// standard library modules
+    context_path: Option<PathBuf>,
+    files: BTreeMap<PathBuf, IndexedFile>,
+    /// Term statistics, maintained incrementally as files are added,
+    /// re-parsed or removed
+    corpus: Bm25Corpus,
+    /// Re-export paths, recomputed together with the term statistics
+    public_api: PublicApi,
+    completions: OnceLock<CompletionIndex>,
+}
+
+/// Symbols extracted from one module, plus what is needed to tell whether
+/// its files changed since.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct IndexedFile {
+    module_path: String,
+    stamp: FileStamp,
+    /// `.pyi` stub whose signatures were merged into the entries
+    stub: Option<StubFile>,
+    /// SHA-256 of the source (and stub) the entries were extracted from
+    content_hash: [u8; 32],
     entries: Vec<PythonSemanticEntry>,
-    idf: HashMap<String, f32>,
+    exports: ModuleExports,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct StubFile {
+    path: PathBuf,
+    stamp: FileStamp,
+}
+
+/// A module on disk: its source file, or its stub when the module has no
+/// Python source (compiled extensions, stub-only distributions), and the
+/// stub describing that source.
+#[derive(Debug, Clone)]
+struct ModuleSource {
+    module_path: String,
+    path: PathBuf,
+    stamp: FileStamp,
+    stub: Option<StubFile>,
+}
+
+impl ModuleSource {
+    fn is_current(&self, file: &IndexedFile) -> bool {
+        file.stamp == self.stamp && file.stub == self.stub
+    }
+}
+
+/// Cheap metadata check consulted before hashing file contents.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct FileStamp {
+    modified: Option<SystemTime>,
+    len: u64,
+}
+
+impl FileStamp {
+    fn read(path: &Path) -> Option<Self> {
+        let metadata = fs::metadata(path).ok()?;
+        Some(Self {
+            modified: metadata.modified().ok(),
+            len: metadata.len(),
+        })
+    }
+}
+
+fn content_hash(source: &str, stub: Option<&str>) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(source.as_bytes());
+    if let Some(stub) = stub {
+        hasher.update([0]);
+        hasher.update(stub.as_bytes());
+    }
+    hasher.finalize().into()
+}
+
+/// Outcome of refreshing an index against the files on disk
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct IndexRefresh {
+    /// Files that were new or modified and had to be parsed
+    pub reparsed: usize,
+    /// Files that disappeared since the index was built
+    pub removed: usize,
+    /// Files whose contents still matched, including files that were only
+    /// touched
+    pub unchanged: usize,
+    /// Unchanged files that were touched and only needed new stamps
+    pub restamped: usize,
+}
+
+impl IndexRefresh {
+    /// Whether the refresh changed the index.
+    pub fn changed(&self) -> bool {
+        self.reparsed > 0 || self.removed > 0
+    }
+
+    /// Whether the persisted copy of the index is out of date, either
+    /// because entries changed or because files only got new stamps.
+    pub fn needs_persist(&self) -> bool {
+        self.changed() || self.restamped > 0
+    }
+}
+
+#[derive(Serialize)]
+struct PersistedIndexRef<'a> {
+    format_version: u32,
+    package_name: &'a str,
+    package_root: &'a Path,
+    package_version: Option<&'a str>,
+    files: &'a BTreeMap<PathBuf, IndexedFile>,
+}
+
+#[derive(Deserialize)]
+struct PersistedIndex {
+    format_version: u32,
+    package_name: String,
+    package_root: PathBuf,
+    package_version: Option<String>,
+    files: BTreeMap<PathBuf, IndexedFile>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct PythonSemanticEntry {
     name: String,
     name_lower: String,
@@ -42,302 +194,1007 @@ struct PythonSemanticEntry {
     file_path: PathBuf,
     line: u32,
     doc_preview: Option<String>,
+    /// Cleaned docstring, parsed into sections for search results
+    docstring: Option<String>,
     signature: Option<String>,
     source_preview: Option<String>,
-    vector: SemanticVector,
-}
-
-#[derive(Debug)]
-struct SemanticVector {
-    weights: HashMap<String, f32>,
-    norm: f32,
-}
-
-impl SemanticVector {
-    fn new(weights: HashMap<String, f32>) -> Self {
-        Self { weights, norm: 1.0 }
-    }
-
-    fn apply_idf(&mut self, idf: &HashMap<String, f32>) {
-        for (token, weight) in self.weights.iter_mut() {
-            let idf_weight = idf.get(token).copied().unwrap_or(1.0);
-            *weight *= idf_weight;
-        }
-        let norm = self
-            .weights
-            .values()
-            .map(|v| v * v)
-            .fold(0.0_f32, |acc, val| acc + val);
-        self.norm = norm.sqrt().max(f32::EPSILON);
-    }
+    terms: FieldTerms,
 }
 
 impl PythonSemanticIndex {
     /// Build a semantic index for the given package root.
     pub fn build(package_name: &str, package_root: &Path) -> Result<Self> {
-        let mut parser = Parser::new();
-        parser
-            .set_language(&tree_sitter_python::LANGUAGE.into())
-            .context("Unable to load tree-sitter grammar for Python")?;
+        Self::build_with_progress(package_name, package_root, None, |_, _| {})
+    }
 
-        let mut entries = Vec::new();
+    /// Build a semantic index, calling `on_progress(files_done, total_files)`
+    /// after each source file is parsed. `context_path` selects the
+    /// environment whose typeshed describes standard library modules.
+    pub fn build_with_progress(
+        package_name: &str,
+        package_root: &Path,
+        context_path: Option<&Path>,
+        on_progress: impl FnMut(usize, usize),
+    ) -> Result<Self> {
+        let mut index = Self {
+            package_name: package_name.to_string(),
+            package_root: package_root.to_path_buf(),
+            package_version: installed_package_version(package_root, package_name),
+            context_path: context_path.map(Path::to_path_buf),
+            files: BTreeMap::new(),
+            corpus: Bm25Corpus::default(),
+            public_api: PublicApi::default(),
+            completions: OnceLock::new(),
+        };
+        index.refresh_with_progress(on_progress)?;
+        Ok(index)
+    }
 
-        for entry in WalkDir::new(package_root)
-            .follow_links(true)
-            .into_iter()
-            .filter_map(|e| e.ok())
-        {
-            if !entry.file_type().is_file() {
-                continue;
+    /// Bring the index up to date with the package sources on disk.
+    ///
+    /// Files whose modification time and size are unchanged are skipped
+    /// without being read. Otherwise the content hash decides: touched but
+    /// identical files only get their stamp updated, new or edited files are
+    /// re-parsed, and entries of deleted files are dropped. Document
+    /// frequencies are adjusted for the affected entries only, so the IDF
+    /// table is recomputed without rescanning the whole package.
+    pub fn refresh_with_progress(
+        &mut self,
+        mut on_progress: impl FnMut(usize, usize),
+    ) -> Result<IndexRefresh> {
+        let current: BTreeMap<PathBuf, ModuleSource> = python_modules(
+            &self.package_name,
+            &self.package_root,
+            self.context_path.as_deref(),
+        )
+        .into_iter()
+        .map(|module| (module.path.clone(), module))
+        .collect();
+
+        let removed: Vec<PathBuf> = self
+            .files
+            .keys()
+            .filter(|path| !current.contains_key(*path))
+            .cloned()
+            .collect();
+        for path in &removed {
+            if let Some(file) = self.files.remove(path) {
+                self.forget_terms(&file.entries);
             }
-            if entry.path().extension().and_then(|s| s.to_str()) != Some("py") {
-                continue;
+        }
+        let mut refresh = IndexRefresh {
+            removed: removed.len(),
+            ..IndexRefresh::default()
+        };
+
+        let candidates: Vec<(&PathBuf, &ModuleSource)> = current
+            .iter()
+            .filter(|(path, module)| self.files.get(*path).is_none_or(|f| !module.is_current(f)))
+            .collect();
+        refresh.unchanged = current.len() - candidates.len();
+
+        let mut parser = None;
+        on_progress(0, candidates.len());
+        for (done, (path, module)) in candidates.iter().enumerate() {
+            let source = fs::read_to_string(path)
+                .with_context(|| format!("Failed to read Python source file {}", path.display()))?;
+            let stub_source = match &module.stub {
+                Some(stub) => Some(fs::read_to_string(&stub.path).with_context(|| {
+                    format!("Failed to read Python stub file {}", stub.path.display())
+                })?),
+                None => None,
+            };
+            let hash = content_hash(&source, stub_source.as_deref());
+
+            if let Some(file) = self.files.get_mut(*path) {
+                if file.content_hash == hash {
+                    file.stamp = module.stamp.clone();
+                    file.stub = module.stub.clone();
+                    refresh.unchanged += 1;
+                    refresh.restamped += 1;
+                    on_progress(done + 1, candidates.len());
+                    continue;
+                }
             }
 
-            let source = fs::read_to_string(entry.path()).with_context(|| {
-                format!(
-                    "Failed to read Python source file {}",
-                    entry.path().display()
-                )
-            })?;
-
-            let tree = parser
-                .parse(&source, None)
-                .ok_or_else(|| anyhow!("Failed to parse {}", entry.path().display()))?;
-
-            let lines: Vec<&str> = source.lines().collect();
-            entries.extend(extract_entries(
-                &tree,
-                &source,
-                &lines,
-                package_name,
-                package_root,
-                entry.path(),
-            )?);
+            let parser = match &mut parser {
+                Some(parser) => parser,
+                None => parser.insert(python_parser()?),
+            };
+            let (entries, exports) = parse_module(parser, module, &source, stub_source.as_deref())?;
+            self.remember_terms(&entries);
+            let previous = self.files.insert(
+                (*path).clone(),
+                IndexedFile {
+                    module_path: module.module_path.clone(),
+                    stamp: module.stamp.clone(),
+                    stub: module.stub.clone(),
+                    content_hash: hash,
+                    entries,
+                    exports,
+                },
+            );
+            if let Some(previous) = previous {
+                self.forget_terms(&previous.entries);
+            }
+            refresh.reparsed += 1;
+            on_progress(done + 1, candidates.len());
         }
 
-        if entries.is_empty() {
+        if self.is_empty() {
             return Err(anyhow!(
                 "No Python symbols discovered for package '{}'",
-                package_name
+                self.package_name
             ));
         }
+        if refresh.changed() || !self.corpus.is_finalized() {
+            self.reweight();
+        }
+        Ok(refresh)
+    }
 
-        let mut document_frequency: HashMap<String, usize> = HashMap::new();
-        for entry in &entries {
-            let mut seen = HashSet::new();
-            for token in entry.vector.weights.keys() {
-                if seen.insert(token.as_str()) {
-                    *document_frequency.entry(token.clone()).or_insert(0) += 1;
-                }
-            }
+    /// Copy of this index brought up to date with the files on disk.
+    ///
+    /// Unchanged files keep their parsed entries, so this is as cheap as
+    /// [`refresh_with_progress`](Self::refresh_with_progress) on a shared
+    /// index that cannot be mutated in place.
+    pub fn refreshed(&self, on_progress: impl FnMut(usize, usize)) -> Result<(Self, IndexRefresh)> {
+        let mut index = Self {
+            package_name: self.package_name.clone(),
+            package_root: self.package_root.clone(),
+            package_version: self.package_version.clone(),
+            context_path: self.context_path.clone(),
+            files: self.files.clone(),
+            corpus: self.corpus.clone(),
+            public_api: self.public_api.clone(),
+            completions: OnceLock::new(),
+        };
+        let refresh = index.refresh_with_progress(on_progress)?;
+        Ok((index, refresh))
+    }
+
+    /// Whether any source file was added, removed or modified since the
+    /// index was last built or refreshed.
+    pub fn is_stale(&self) -> bool {
+        let current = python_modules(
+            &self.package_name,
+            &self.package_root,
+            self.context_path.as_deref(),
+        );
+        current.len() != self.files.len()
+            || current.iter().any(|module| {
+                self.files
+                    .get(&module.path)
+                    .is_none_or(|file| !module.is_current(file))
+            })
+    }
+
+    /// Serialize the index for the on-disk cache.
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        bincode::serialize(&PersistedIndexRef {
+            format_version: INDEX_FORMAT_VERSION,
+            package_name: &self.package_name,
+            package_root: &self.package_root,
+            package_version: self.package_version.as_deref(),
+            files: &self.files,
+        })
+        .context("Failed to serialize Python semantic index")
+    }
+
+    /// Restore an index persisted by [`to_bytes`](Self::to_bytes).
+    ///
+    /// Fails when the layout version differs. The result reflects the files
+    /// as they were when persisted; call
+    /// [`refresh_with_progress`](Self::refresh_with_progress) before use.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let persisted: PersistedIndex =
+            bincode::deserialize(bytes).context("Failed to deserialize Python semantic index")?;
+        if persisted.format_version != INDEX_FORMAT_VERSION {
+            return Err(anyhow!(
+                "Persisted Python index has format {}, expected {}",
+                persisted.format_version,
+                INDEX_FORMAT_VERSION
+            ));
+        }
+        let mut index = Self {
+            package_name: persisted.package_name,
+            package_root: persisted.package_root,
+            package_version: persisted.package_version,
+            context_path: None,
+            files: persisted.files,
+            corpus: Bm25Corpus::default(),
+            public_api: PublicApi::default(),
+            completions: OnceLock::new(),
+        };
+        let files = std::mem::take(&mut index.files);
+        for file in files.values() {
+            index.remember_terms(&file.entries);
         }
+        index.files = files;
+        Ok(index)
+    }
 
-        let doc_count = entries.len() as f32;
-        let mut idf = HashMap::with_capacity(document_frequency.len());
-        for (token, df) in document_frequency {
-            let weight = ((doc_count + 1.0) / (df as f32 + 1.0)).ln() + 1.0;
-            idf.insert(token, weight);
+    /// Load a persisted index if it matches the package, refreshing it
+    /// incrementally; otherwise build from scratch.
+    ///
+    /// A persisted index is discarded when its package name, root or
+    /// installed version (from `.dist-info`) differ. Returns the index and
+    /// the refresh outcome, `None` meaning it was rebuilt.
+    pub fn load_or_build(
+        package_name: &str,
+        package_root: &Path,
+        context_path: Option<&Path>,
+        persisted: Option<&[u8]>,
+        mut on_progress: impl FnMut(usize, usize),
+    ) -> Result<(Self, Option<IndexRefresh>)> {
+        let version = installed_package_version(package_root, package_name);
+        let reusable = persisted
+            .and_then(|bytes| Self::from_bytes(bytes).ok())
+            .filter(|index| {
+                index.package_name == package_name
+                    && index.package_root == package_root
+                    && index.package_version == version
+            });
+
+        if let Some(mut index) = reusable {
+            index.context_path = context_path.map(Path::to_path_buf);
+            let refresh = index.refresh_with_progress(&mut on_progress)?;
+            return Ok((index, Some(refresh)));
         }
+        Ok((
+            Self::build_with_progress(package_name, package_root, context_path, on_progress)?,
+            None,
+        ))
+    }
+
+    /// Installed distribution version, when the package has `.dist-info`.
+    pub fn package_version(&self) -> Option<&str> {
+        self.package_version.as_deref()
+    }
 
-        for entry in entries.iter_mut() {
-            entry.vector.apply_idf(&idf);
+    fn remember_terms(&mut self, entries: &[PythonSemanticEntry]) {
+        for entry in entries {
+            self.corpus.add(&entry.terms);
         }
+    }
 
-        Ok(Self {
-            package_name: package_name.to_string(),
-            package_root: package_root.to_path_buf(),
-            entries,
-            idf,
-        })
+    fn forget_terms(&mut self, entries: &[PythonSemanticEntry]) {
+        for entry in entries {
+            self.corpus.remove(&entry.terms);
+        }
+    }
+
+    /// Recompute IDF weights after entries were added or removed.
+    fn reweight(&mut self) {
+        self.corpus.finalize();
+        self.public_api = self.resolve_public_api();
+        self.completions = OnceLock::new();
+    }
+
+    fn resolve_public_api(&self) -> PublicApi {
+        let modules: Vec<ModuleSymbols> = self
+            .files
+            .iter()
+            .map(|(path, file)| ModuleSymbols {
+                module_path: &file.module_path,
+                defined: file
+                    .entries
+                    .iter()
+                    .filter(|entry| {
+                        entry.qualified_path == format!("{}.{}", file.module_path, entry.name)
+                    })
+                    .map(|entry| entry.name.as_str())
+                    .collect(),
+                exports: &file.exports,
+                is_package: is_package_init(path),
+            })
+            .collect();
+        PublicApi::build(&modules)
+    }
+
+    fn entries(&self) -> impl Iterator<Item = &PythonSemanticEntry> + Clone {
+        self.files.values().flat_map(|file| file.entries.iter())
     }
 
     /// Execute a semantic search query, returning ranked results.
+    ///
+    /// Ranked with BM25F; see [`Bm25Corpus::rank`] for query expansion.
     pub fn search(&self, query: &str, limit: usize) -> Vec<SemanticSearchResult> {
         if query.trim().is_empty() || limit == 0 {
             return Vec::new();
         }
 
-        let mut query_vector = build_text_vector(query, 1.0, 1.0);
-        for (token, weight) in query_vector.iter_mut() {
-            let idf_weight = self.idf.get(token).copied().unwrap_or(1.0);
-            *weight *= idf_weight;
-        }
-        let query_norm = query_vector
-            .values()
-            .map(|v| v * v)
-            .fold(0.0_f32, |acc, val| acc + val)
-            .sqrt()
-            .max(f32::EPSILON);
-        let query_text = query.to_lowercase();
-
-        let mut scored: Vec<(f32, &PythonSemanticEntry)> = Vec::new();
-        for entry in &self.entries {
-            let mut dot = 0.0_f32;
-            for (token, q_weight) in &query_vector {
-                if let Some(doc_weight) = entry.vector.weights.get(token) {
-                    dot += q_weight * doc_weight;
-                }
-            }
-            if dot == 0.0 {
-                continue;
-            }
-            let mut score = dot / (entry.vector.norm * query_norm);
-
-            if entry.name_lower == query_text {
-                score += 0.35;
-            } else if entry.name_lower.contains(&query_text) {
-                score += 0.2;
-            } else if entry.qualified_lower.contains(&query_text) {
-                score += 0.1;
-            }
+        let scored = self.corpus.rank(
+            query,
+            self.entries(),
+            |entry| &entry.terms,
+            |a, b| {
+                a.module_path
+                    .cmp(&b.module_path)
+                    .then_with(|| a.name.cmp(&b.name))
+            },
+        );
+
+        let mut ranked: Vec<_> = scored
+            .into_iter()
+            .map(|(score, entry)| {
+                let (score, paths) = self.public_score(entry, score);
+                (score, (paths, entry))
+            })
+            .collect();
+        prefer_public(&mut ranked, limit);
+        ranked
+            .into_iter()
+            .map(|(score, (paths, entry))| self.search_result(entry, paths, score))
+            .collect()
+    }
 
-            scored.push((score, entry));
+    /// Search several packages at once with a single ranking.
+    ///
+    /// Term statistics of all packages are combined so that scores are
+    /// comparable across packages; each result carries its package name.
+    pub fn search_many(
+        indexes: &[&PythonSemanticIndex],
+        query: &str,
+        limit: usize,
+    ) -> Vec<SemanticSearchResult> {
+        if query.trim().is_empty() || limit == 0 {
+            return Vec::new();
         }
 
-        scored.sort_by(|(a_score, a_entry), (b_score, b_entry)| {
-            b_score
-                .partial_cmp(a_score)
-                .unwrap_or(Ordering::Equal)
-                .then_with(|| a_entry.module_path.cmp(&b_entry.module_path))
-                .then_with(|| a_entry.name.cmp(&b_entry.name))
-        });
+        let corpus = Bm25Corpus::merged(indexes.iter().map(|index| &index.corpus));
+        let entries: Vec<(&PythonSemanticIndex, &PythonSemanticEntry)> = indexes
+            .iter()
+            .flat_map(|index| index.entries().map(move |entry| (*index, entry)))
+            .collect();
+        let scored = corpus.rank(
+            query,
+            entries.iter(),
+            |(_, entry)| &entry.terms,
+            |(a_index, a), (b_index, b)| {
+                a_index
+                    .package_name
+                    .cmp(&b_index.package_name)
+                    .then_with(|| a.module_path.cmp(&b.module_path))
+                    .then_with(|| a.name.cmp(&b.name))
+            },
+        );
 
-        scored
+        let mut ranked: Vec<_> = scored
             .into_iter()
-            .take(limit)
-            .map(|(score, entry)| SemanticSearchResult {
-                language: "python".to_string(),
-                package: self.package_name.clone(),
-                module_path: entry.module_path.clone(),
-                item_name: entry.name.clone(),
-                qualified_path: entry.qualified_path.clone(),
-                kind: entry.kind.clone(),
-                file: entry.file_path.to_string_lossy().into_owned(),
-                line: entry.line,
-                score,
-                doc_preview: entry.doc_preview.clone(),
-                signature: entry.signature.clone(),
-                source_preview: entry.source_preview.clone(),
+            .map(|(score, &(index, entry))| {
+                let (score, paths) = index.public_score(entry, score);
+                (score, (paths, index, entry))
             })
+            .collect();
+        prefer_public(&mut ranked, limit);
+        ranked
+            .into_iter()
+            .map(|(score, (paths, index, entry))| index.search_result(entry, paths, score))
             .collect()
     }
 
+    /// Public paths of `entry` and its score, scaled by
+    /// [`PRIVATE_SCORE_FACTOR`] when no public path leads to it.
+    fn public_score(&self, entry: &PythonSemanticEntry, score: f32) -> (f32, PublicPaths) {
+        let paths = self
+            .public_api
+            .paths(&entry.module_path, &entry.qualified_path);
+        let score = if paths.private {
+            score * PRIVATE_SCORE_FACTOR
+        } else {
+            score
+        };
+        (score, paths)
+    }
+
+    /// Result for `entry`, reported under its canonical public path.
+    fn search_result(
+        &self,
+        entry: &PythonSemanticEntry,
+        paths: PublicPaths,
+        score: f32,
+    ) -> SemanticSearchResult {
+        SemanticSearchResult {
+            language: "python".to_string(),
+            package: self.package_name.clone(),
+            module_path: entry.module_path.clone(),
+            item_name: entry.name.clone(),
+            qualified_path: paths.canonical,
+            alternate_paths: paths.alternates,
+            kind: entry.kind.clone(),
+            file: entry.file_path.to_string_lossy().into_owned(),
+            line: entry.line,
+            score,
+            doc_preview: entry.doc_preview.clone(),
+            structured_docstring: entry.docstring.as_deref().map(parse_docstring),
+            signature: entry.signature.clone(),
+            source_preview: entry.source_preview.clone(),
+        }
+    }
+
+    /// Prefix-completion index over module and qualified symbol paths.
+    ///
+    /// Built lazily on first use; parent modules of every indexed module are
+    /// included so `requests.ad` completes to `requests.adapters`.
+    pub fn completion_index(&self) -> &CompletionIndex {
+        self.completions.get_or_init(|| {
+            let mut paths = Vec::with_capacity(self.len() * 2);
+            for entry in self.entries() {
+                let mut module = entry.module_path.as_str();
+                loop {
+                    paths.push((module.to_string(), "module".to_string()));
+                    match module.rfind('.') {
+                        Some(pos) => module = &module[..pos],
+                        None => break,
+                    }
+                }
+                paths.push((entry.qualified_path.clone(), entry.kind.clone()));
+                let public = self
+                    .public_api
+                    .paths(&entry.module_path, &entry.qualified_path);
+                for path in std::iter::once(public.canonical).chain(public.alternates) {
+                    if path != entry.qualified_path {
+                        paths.push((path, entry.kind.clone()));
+                    }
+                }
+            }
+            CompletionIndex::from_entries(paths)
+        })
+    }
+
+    /// Number of indexed symbols.
+    pub fn len(&self) -> usize {
+        self.files.values().map(|file| file.entries.len()).sum()
+    }
+
+    /// Whether the index holds no symbols.
+    pub fn is_empty(&self) -> bool {
+        self.files.values().all(|file| file.entries.is_empty())
+    }
+
+    /// Rough in-memory footprint of the indexed entries and vectors.
+    pub fn approximate_size_bytes(&self) -> u64 {
+        let entry_bytes: usize = self
+            .entries()
+            .map(|entry| {
+                entry.name.len() * 2
+                    + entry.qualified_path.len() * 2
+                    + entry.module_path.len()
+                    + entry.file_path.as_os_str().len()
+                    + entry.doc_preview.as_ref().map_or(0, String::len)
+                    + entry.docstring.as_ref().map_or(0, String::len)
+                    + entry.signature.as_ref().map_or(0, String::len)
+                    + entry.source_preview.as_ref().map_or(0, String::len)
+                    + entry.terms.approximate_size_bytes()
+            })
+            .sum();
+        (entry_bytes + self.corpus.approximate_size_bytes()) as u64
+    }
+
+    /// Validate indexed entries.
+    ///
+    /// Entries without a name or qualified path are errors, entries whose
+    /// source file disappeared count as broken links, and duplicate qualified
+    /// paths are reported as warnings.
+    pub fn validate(&self) -> ValidationResult {
+        let mut collector = ValidationCollector::default();
+        let mut seen = HashSet::new();
+
+        for entry in self.entries() {
+            collector.stats.total_checked += 1;
+            let mut valid = true;
+
+            if entry.name.is_empty() || entry.qualified_path.is_empty() {
+                collector.error(format!(
+                    "{} entry at {}:{} has no name or path",
+                    entry.kind,
+                    entry.file_path.display(),
+                    entry.line
+                ));
+                valid = false;
+            }
+            if !entry.file_path.is_file() {
+                collector.error(format!(
+                    "Source file for '{}' no longer exists: {}",
+                    entry.qualified_path,
+                    entry.file_path.display()
+                ));
+                collector.stats.broken_links += 1;
+                valid = false;
+            }
+            if entry.doc_preview.is_none() {
+                collector.stats.missing_docs += 1;
+            }
+            if valid && !seen.insert(entry.qualified_path.as_str()) {
+                collector.warning(format!("Duplicate symbol '{}'", entry.qualified_path));
+            }
+            if !valid {
+                collector.stats.invalid_items += 1;
+            }
+        }
+
+        collector.finish()
+    }
+
     /// Return the on-disk root used for indexing.
     pub fn package_root(&self) -> &Path {
         &self.package_root
     }
+
+    /// Import name the package was indexed under.
+    pub fn package_name(&self) -> &str {
+        &self.package_name
+    }
 }
 
-fn extract_entries(
-    tree: &tree_sitter::Tree,
-    source: &str,
-    lines: &[&str],
+/// Re-sort scored entries after private symbols were down-weighted and keep
+/// the top `limit`. The sort is stable, so equal scores keep their ranking
+/// order.
+fn prefer_public<T>(ranked: &mut Vec<(f32, T)>, limit: usize) {
+    ranked.sort_by(|(a, _), (b, _)| b.total_cmp(a));
+    ranked.truncate(limit);
+}
+
+fn python_parser() -> Result<Parser> {
+    let mut parser = Parser::new();
+    parser
+        .set_language(&tree_sitter_python::LANGUAGE.into())
+        .context("Unable to load tree-sitter grammar for Python")?;
+    Ok(parser)
+}
+
+/// Modules of a package, each paired with the `.pyi` stub describing it.
+///
+/// Stubs next to a module win over those of a sibling `<pkg>-stubs`
+/// distribution. A stub without Python source (compiled extensions, or a
+/// stub-only distribution) stands in for the module itself.
+fn python_modules(
     package_name: &str,
     package_root: &Path,
-    file_path: &Path,
-) -> Result<Vec<PythonSemanticEntry>> {
-    let mut entries = Vec::new();
-    let module_path = module_path(package_name, package_root, file_path)?;
-
-    fn visit(
-        node: Node,
-        source: &str,
-        lines: &[&str],
-        module_path: &str,
-        file_path: &Path,
-        entries: &mut Vec<PythonSemanticEntry>,
-    ) -> Result<()> {
-        match node.kind() {
-            "function_definition" | "class_definition" => {
-                if let Some(entry) =
-                    build_entry(node, source, lines, module_path, file_path, node.kind())?
-                {
-                    entries.push(entry);
-                }
-            }
-            _ => {
-                let mut walk = node.walk();
-                for child in node.children(&mut walk) {
-                    visit(child, source, lines, module_path, file_path, entries)?;
-                }
-            }
+    context_path: Option<&Path>,
+) -> Vec<ModuleSource> {
+    let mut roots = vec![package_root.to_path_buf()];
+    if package_root.is_file() {
+        roots.push(package_root.with_extension("pyi"));
+    }
+    roots.extend(finder::find_python_stub_dir(
+        package_root,
+        package_name,
+        context_path,
+    ));
+
+    let mut sources: BTreeMap<String, PathBuf> = BTreeMap::new();
+    let mut stubs: BTreeMap<String, PathBuf> = BTreeMap::new();
+    for root in &roots {
+        let files = WalkDir::new(root)
+            .follow_links(true)
+            .into_iter()
+            .filter_map(|e| e.ok())
+            .filter(|entry| entry.file_type().is_file());
+        for entry in files {
+            let path = entry.into_path();
+            let is_stub = match path.extension().and_then(|s| s.to_str()) {
+                Some("py") => false,
+                Some("pyi") => true,
+                _ => continue,
+            };
+            let Ok(module_path) = module_path(package_name, root, &path) else {
+                continue;
+            };
+            let target = if is_stub { &mut stubs } else { &mut sources };
+            target.entry(module_path).or_insert(path);
         }
-        Ok(())
     }
 
-    visit(
-        tree.root_node(),
+    let mut modules = Vec::with_capacity(sources.len());
+    for (module_path, path) in sources {
+        let stub = stubs.remove(&module_path).and_then(|path| {
+            let stamp = FileStamp::read(&path)?;
+            Some(StubFile { path, stamp })
+        });
+        if let Some(stamp) = FileStamp::read(&path) {
+            modules.push(ModuleSource {
+                module_path,
+                path,
+                stamp,
+                stub,
+            });
+        }
+    }
+    for (module_path, path) in stubs {
+        if let Some(stamp) = FileStamp::read(&path) {
+            modules.push(ModuleSource {
+                module_path,
+                path,
+                stamp,
+                stub: None,
+            });
+        }
+    }
+    modules
+}
+
+/// Version of the installed distribution providing `package_name`.
+///
+/// Looks for a sibling `*.dist-info` directory whose name matches the
+/// package, or which installs it (e.g. `PyYAML` providing `yaml`), and
+/// reads `Version:` from its `METADATA`. Local source trees have no
+/// `.dist-info` and yield `None`.
+pub fn installed_package_version(package_root: &Path, package_name: &str) -> Option<String> {
+    let dist_info = python_metadata::find_dist_info(package_root.parent()?, package_name)?;
+    python_metadata::dist_info_version(&dist_info)
+}
+
+/// Count Python source files under `root` modified after `since`.
+pub fn files_modified_since(root: &Path, since: std::time::SystemTime) -> usize {
+    WalkDir::new(root)
+        .follow_links(true)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|entry| {
+            matches!(
+                entry.path().extension().and_then(|s| s.to_str()),
+                Some("py" | "pyi")
+            )
+        })
+        .filter_map(|entry| entry.metadata().ok()?.modified().ok())
+        .filter(|modified| *modified > since)
+        .count()
+}
+
+/// Where a definition appears.
+#[derive(Clone, Copy)]
+enum Scope<'a> {
+    Module,
+    /// Inside a class body; holds the dotted class path (`Outer.Inner`)
+    Class(&'a str),
+}
+
+/// Per-file state shared by the extraction helpers.
+struct SourceFile<'a> {
+    source: &'a str,
+    lines: &'a [&'a str],
+    module_path: &'a str,
+    file_path: &'a Path,
+    /// The source is a `.pyi` stub, where `@overload` signatures are all
+    /// there is
+    is_stub: bool,
+    /// Entries of the module's stub, by qualified path, whose typed
+    /// signatures replace the source's
+    stub_entries: Option<&'a HashMap<String, PythonSemanticEntry>>,
+}
+
+impl SourceFile<'_> {
+    fn text(&self, node: Node) -> &str {
+        node.utf8_text(self.source.as_bytes()).unwrap_or("")
+    }
+}
+
+/// Parse a module and merge its stub, if any: typed signatures come from
+/// the stub, docstrings from the source (falling back to the stub's), and
+/// stub-only symbols are added.
+fn parse_module(
+    parser: &mut Parser,
+    module: &ModuleSource,
+    source: &str,
+    stub_source: Option<&str>,
+) -> Result<(Vec<PythonSemanticEntry>, ModuleExports)> {
+    let stub = match (&module.stub, stub_source) {
+        (Some(stub), Some(stub_source)) => Some(extract_entries(
+            parser,
+            stub_source,
+            &module.module_path,
+            &stub.path,
+            None,
+        )?),
+        _ => None,
+    };
+    let Some((stub_entries, stub_exports)) = stub else {
+        return extract_entries(parser, source, &module.module_path, &module.path, None);
+    };
+
+    let stub_entries: HashMap<String, PythonSemanticEntry> = stub_entries
+        .into_iter()
+        .map(|entry| (entry.qualified_path.clone(), entry))
+        .collect();
+    let (mut entries, mut exports) = extract_entries(
+        parser,
         source,
-        lines,
-        &module_path,
-        file_path,
-        &mut entries,
+        &module.module_path,
+        &module.path,
+        Some(&stub_entries),
     )?;
-    Ok(entries)
+
+    let known: HashSet<String> = entries.iter().map(|e| e.qualified_path.clone()).collect();
+    let mut stub_only: Vec<PythonSemanticEntry> = stub_entries
+        .into_values()
+        .filter(|entry| !known.contains(&entry.qualified_path))
+        .collect();
+    stub_only.sort_by_key(|entry| entry.line);
+    entries.extend(stub_only);
+
+    if exports.all.is_none() {
+        exports.all = stub_exports.all;
+    }
+    for reexport in stub_exports.reexports {
+        if !exports.reexports.contains(&reexport) {
+            exports.reexports.push(reexport);
+        }
+    }
+    Ok((entries, exports))
 }
 
-fn build_entry(
-    node: Node,
+fn extract_entries(
+    parser: &mut Parser,
     source: &str,
-    lines: &[&str],
     module_path: &str,
     file_path: &Path,
-    kind: &str,
-) -> Result<Option<PythonSemanticEntry>> {
-    let name_node = node
+    stub_entries: Option<&HashMap<String, PythonSemanticEntry>>,
+) -> Result<(Vec<PythonSemanticEntry>, ModuleExports)> {
+    let tree = parser
+        .parse(source, None)
+        .ok_or_else(|| anyhow!("Failed to parse {}", file_path.display()))?;
+    let lines: Vec<&str> = source.lines().collect();
+    let is_stub = file_path.extension().is_some_and(|ext| ext == "pyi");
+    let file = SourceFile {
+        source,
+        lines: &lines,
+        module_path,
+        file_path,
+        is_stub,
+        stub_entries,
+    };
+    let mut entries = Vec::new();
+    visit(&file, tree.root_node(), Scope::Module, &mut entries);
+    if is_stub {
+        // Keep the first of several `@overload`s
+        let mut seen = HashSet::new();
+        entries.retain(|entry| seen.insert(entry.qualified_path.clone()));
+    }
+    let exports = extract_module_exports(
+        tree.root_node(),
+        source,
+        module_path,
+        is_package_init(file_path),
+    );
+    Ok((entries, exports))
+}
+
+fn is_package_init(path: &Path) -> bool {
+    path.file_stem().is_some_and(|stem| stem == "__init__")
+}
+
+/// Collect classes, functions, methods, properties, constants and type
+/// aliases. Function bodies are not descended into: nested helpers are not
+/// part of a package's API.
+fn visit(file: &SourceFile, node: Node, scope: Scope, entries: &mut Vec<PythonSemanticEntry>) {
+    match node.kind() {
+        "decorated_definition" => {
+            let decorators: Vec<&str> = node
+                .named_children(&mut node.walk())
+                .filter(|child| child.kind() == "decorator")
+                .map(|decorator| file.text(decorator).trim_start_matches('@').trim())
+                .collect();
+            if let Some(definition) = node.child_by_field_name("definition") {
+                visit_definition(file, definition, scope, &decorators, entries);
+            }
+        }
+        "function_definition" | "class_definition" => {
+            visit_definition(file, node, scope, &[], entries);
+        }
+        "expression_statement" if matches!(scope, Scope::Module) => {
+            if let Some(entry) = module_assignment(file, node) {
+                entries.push(entry);
+            }
+        }
+        "type_alias_statement" if matches!(scope, Scope::Module) => {
+            let name = node
+                .child_by_field_name("left")
+                .map(|left| file.text(left))
+                .map(|left| left.split('[').next().unwrap_or(left).trim().to_string());
+            if let Some(name) = name.filter(|name| !name.is_empty()) {
+                let docstring = following_docstring(file, node);
+                entries.push(build_entry(file, node, name, None, "type_alias", docstring));
+            }
+        }
+        _ => {
+            for child in node.children(&mut node.walk()) {
+                visit(file, child, scope, entries);
+            }
+        }
+    }
+}
+
+fn visit_definition(
+    file: &SourceFile,
+    node: Node,
+    scope: Scope,
+    decorators: &[&str],
+    entries: &mut Vec<PythonSemanticEntry>,
+) {
+    let Some(name) = node
         .child_by_field_name("name")
-        .ok_or_else(|| anyhow!("Missing name for Python {kind} definition"))?;
-    let name = name_node
-        .utf8_text(source.as_bytes())
-        .unwrap_or("")
-        .to_string();
-    if name.is_empty() {
-        return Ok(None);
+        .map(|name| file.text(name).to_string())
+        .filter(|name| !name.is_empty())
+    else {
+        return;
+    };
+    let container = match scope {
+        Scope::Module => None,
+        Scope::Class(class_path) => Some(class_path),
+    };
+    let docstring = docstring_for_node(&node, file.source);
+
+    if node.kind() == "class_definition" {
+        entries.push(build_entry(
+            file,
+            node,
+            name.clone(),
+            container,
+            "class",
+            docstring,
+        ));
+        let class_path = match container {
+            Some(outer) => format!("{outer}.{name}"),
+            None => name,
+        };
+        if let Some(body) = node.child_by_field_name("body") {
+            for child in body.children(&mut body.walk()) {
+                visit(file, child, Scope::Class(&class_path), entries);
+            }
+        }
+        return;
+    }
+
+    let decorator_name = |decorator: &&str| {
+        decorator
+            .split('(')
+            .next()
+            .unwrap_or(decorator)
+            .rsplit('.')
+            .next()
+            .unwrap_or(decorator)
+            .to_string()
+    };
+    let decorator_names: Vec<String> = decorators.iter().map(decorator_name).collect();
+    // `@overload` stubs precede the implementation; property setters and
+    // deleters repeat the property
+    if decorator_names
+        .iter()
+        .any(|d| matches!(d.as_str(), "setter" | "deleter") || (d == "overload" && !file.is_stub))
+    {
+        return;
+    }
+    let kind = match scope {
+        Scope::Module => "function",
+        Scope::Class(_) if is_special_method(&name) => return,
+        Scope::Class(_)
+            if decorator_names.iter().any(|d| {
+                matches!(
+                    d.as_str(),
+                    "property" | "cached_property" | "abstractproperty"
+                )
+            }) =>
+        {
+            "property"
+        }
+        Scope::Class(_) => "method",
+    };
+    entries.push(build_entry(file, node, name, container, kind, docstring));
+}
+
+/// Dunder methods other than the constructor and `__call__` describe
+/// protocol plumbing rather than functionality worth searching for.
+fn is_special_method(name: &str) -> bool {
+    name.starts_with("__") && name.ends_with("__") && name != "__init__" && name != "__call__"
+}
+
+/// Module-level `UPPER_CASE` constants and `Name: TypeAlias = ...` aliases.
+fn module_assignment(file: &SourceFile, statement: Node) -> Option<PythonSemanticEntry> {
+    let assignment = statement
+        .named_child(0)
+        .filter(|child| child.kind() == "assignment")?;
+    let left = assignment
+        .child_by_field_name("left")
+        .filter(|left| left.kind() == "identifier")?;
+    let name = file.text(left).to_string();
+
+    let annotation = assignment
+        .child_by_field_name("type")
+        .map(|annotation| file.text(annotation).trim());
+    let kind = if annotation.is_some_and(|a| a == "TypeAlias" || a.ends_with(".TypeAlias")) {
+        "type_alias"
+    } else if is_constant_name(&name) {
+        "constant"
+    } else {
+        return None;
+    };
+    let docstring = following_docstring(file, statement);
+    Some(build_entry(file, statement, name, None, kind, docstring))
+}
+
+fn is_constant_name(name: &str) -> bool {
+    name.chars().any(|c| c.is_ascii_uppercase())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_')
+}
+
+/// Attribute docstring: a string literal statement directly after an
+/// assignment, as understood by Sphinx and most documentation tools.
+fn following_docstring(file: &SourceFile, statement: Node) -> Option<String> {
+    let next = statement.next_named_sibling()?;
+    if next.kind() != "expression_statement" || next.named_child_count() != 1 {
+        return None;
     }
+    let string = next
+        .named_child(0)
+        .filter(|child| child.kind() == "string")?;
+    Some(clean_docstring(file.text(string)))
+}
+
+fn build_entry(
+    file: &SourceFile,
+    node: Node,
+    name: String,
+    container: Option<&str>,
+    kind: &str,
+    docstring: Option<String>,
+) -> PythonSemanticEntry {
+    let qualified_path = match container {
+        Some(container) => format!("{}.{container}.{name}", file.module_path),
+        None => format!("{}.{name}", file.module_path),
+    };
+    let stub = file
+        .stub_entries
+        .and_then(|stubs| stubs.get(&qualified_path));
+    let docstring = docstring.or_else(|| stub.and_then(|stub| stub.docstring.clone()));
 
-    let docstring = docstring_for_node(&node, source);
     let doc_preview = docstring
         .as_ref()
         .map(|d| trim_preview(d, MAX_DOC_PREVIEW_CHARS));
 
     let start_row = node.start_position().row;
     let line = (start_row + 1) as u32;
-    let signature = lines
-        .get(start_row)
-        .map(|l| l.trim().to_string())
-        .filter(|s| !s.is_empty());
-
-    let source_preview = snippet_for_node(&node, lines, start_row);
-
-    let mut vector = HashMap::new();
-    accumulate_identifier_tokens(&mut vector, &name, 1.6);
-    accumulate_identifier_tokens(&mut vector, module_path, 0.75);
+    let signature = stub.and_then(|stub| stub.signature.clone()).or_else(|| {
+        file.lines
+            .get(start_row)
+            .map(|l| l.trim().to_string())
+            .filter(|s| !s.is_empty())
+    });
+
+    let source_preview = snippet_for_node(&node, file.lines, start_row);
+
+    let mut terms = FieldTerms::default();
+    terms.add(Bm25Field::Name, identifier_tokens(&name));
+    let mut path_tokens = identifier_tokens(file.module_path);
+    if let Some(container) = container {
+        path_tokens.extend(identifier_tokens(container));
+    }
+    terms.add(Bm25Field::Path, path_tokens);
     if let Some(sig) = &signature {
-        accumulate_text_tokens(&mut vector, sig, 0.6);
+        terms.add(Bm25Field::Signature, text_tokens(sig));
     }
     if let Some(doc) = &docstring {
-        accumulate_text_tokens(&mut vector, doc, 1.25);
+        terms.add(Bm25Field::Doc, text_tokens(doc));
     }
-    // Capture context of enclosing module path as phrase.
-    accumulate_text_tokens(&mut vector, &module_path.replace('.', " "), 0.4);
 
-    let qualified_path = format!("{}.{}", module_path, name);
-
-    Ok(Some(PythonSemanticEntry {
+    PythonSemanticEntry {
         name_lower: name.to_lowercase(),
         qualified_lower: qualified_path.to_lowercase(),
         name,
         qualified_path,
-        module_path: module_path.to_string(),
-        kind: kind.trim_end_matches("_definition").to_string(),
-        file_path: file_path.to_path_buf(),
+        module_path: file.module_path.to_string(),
+        kind: kind.to_string(),
+        file_path: file.file_path.to_path_buf(),
         line,
         doc_preview,
+        docstring,
         signature,
         source_preview,
-        vector: SemanticVector::new(vector),
-    }))
+        terms,
+    }
 }
 
 fn docstring_for_node(node: &Node, source: &str) -> Option<String> {
@@ -440,13 +1297,16 @@ fn module_path(package_name: &str, package_root: &Path, file: &Path) -> Result<S
         }
     }
     if let Some(last) = components.last() {
-        if last == "__init__.py" {
+        if last == "__init__.py" || last == "__init__.pyi" {
             components.pop();
         }
     }
     if let Some(last) = components.last_mut() {
-        if last.ends_with(".py") {
-            let _ = last.split_off(last.len() - 3);
+        if let Some(stem) = last
+            .strip_suffix(".pyi")
+            .or_else(|| last.strip_suffix(".py"))
+        {
+            *last = stem.to_string();
         }
     }
     let suffix = components
@@ -461,113 +1321,6 @@ fn module_path(package_name: &str, package_root: &Path, file: &Path) -> Result<S
     }
 }
 
-fn accumulate_identifier_tokens(target: &mut HashMap<String, f32>, ident: &str, weight: f32) {
-    if ident.is_empty() {
-        return;
-    }
-    let lower = ident.to_lowercase();
-    *target.entry(lower.clone()).or_insert(0.0) += weight;
-
-    for token in split_identifier(ident) {
-        *target.entry(token).or_insert(0.0) += weight * 0.8;
-    }
-}
-
-fn accumulate_text_tokens(target: &mut HashMap<String, f32>, text: &str, weight: f32) {
-    let tokens = split_freeform(text);
-    for token in tokens {
-        if is_stop_word(&token) {
-            continue;
-        }
-        *target.entry(token).or_insert(0.0) += weight;
-    }
-}
-
-fn build_text_vector(text: &str, main_weight: f32, phrase_bonus: f32) -> HashMap<String, f32> {
-    let mut vector = HashMap::new();
-    let tokens = split_freeform(text);
-    for token in &tokens {
-        if is_stop_word(token) {
-            continue;
-        }
-        *vector.entry(token.clone()).or_insert(0.0) += main_weight;
-    }
-    for window in tokens.windows(2) {
-        if window.iter().all(|tok| !is_stop_word(tok)) {
-            let phrase = format!("{} {}", window[0], window[1]);
-            *vector.entry(phrase).or_insert(0.0) += phrase_bonus;
-        }
-    }
-    vector
-}
-
-fn split_identifier(ident: &str) -> Vec<String> {
-    let mut tokens = Vec::new();
-    let mut current = String::new();
-    for ch in ident.chars() {
-        if ch == '_' || ch == '.' {
-            if !current.is_empty() {
-                tokens.push(current.to_lowercase());
-                current.clear();
-            }
-            continue;
-        }
-        if ch.is_uppercase() && !current.is_empty() {
-            tokens.push(current.to_lowercase());
-            current.clear();
-        }
-        current.push(ch);
-    }
-    if !current.is_empty() {
-        tokens.push(current.to_lowercase());
-    }
-    tokens
-}
-
-fn split_freeform(text: &str) -> Vec<String> {
-    text.split(|c: char| !c.is_alphanumeric())
-        .filter(|token| !token.is_empty())
-        .map(|token| token.to_lowercase())
-        .collect()
-}
-
-fn is_stop_word(token: &str) -> bool {
-    matches!(
-        token,
-        "the"
-            | "and"
-            | "or"
-            | "for"
-            | "with"
-            | "of"
-            | "a"
-            | "an"
-            | "to"
-            | "in"
-            | "is"
-            | "are"
-            | "on"
-            | "by"
-            | "be"
-            | "this"
-            | "that"
-            | "it"
-            | "from"
-            | "into"
-            | "as"
-            | "at"
-            | "self"
-            | "cls"
-            | "returns"
-            | "return"
-            | "args"
-            | "kwargs"
-            | "true"
-            | "false"
-            | "none"
-    )
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -641,5 +1394,485 @@ def deactivate_user(user_id: str) -> None:
             .expect("expected AccountManager class");
         assert_eq!(manager.kind, "class");
         assert!(manager.doc_preview.unwrap().contains("lifecycle"));
+
+        let completions = index.completion_index().complete("sample_pkg.acc", 5);
+        assert_eq!(completions[0].value, "sample_pkg.account");
+        assert_eq!(completions[0].kind, "module");
+        assert!(completions
+            .iter()
+            .any(|c| c.value == "sample_pkg.account.AccountManager"));
+    }
+
+    #[test]
+    fn persisted_index_refreshes_only_changed_files() {
+        let tmp = tempdir().unwrap();
+        let site = tmp.path().join("site-packages");
+        let pkg_root = site.join("tinypkg");
+        write_file(&pkg_root.join("__init__.py"), "");
+        write_file(
+            &pkg_root.join("alpha.py"),
+            "def parse_config(path):\n    \"\"\"Read a configuration file.\"\"\"\n",
+        );
+        write_file(
+            &pkg_root.join("beta.py"),
+            "def send_email(to):\n    \"\"\"Deliver an email message.\"\"\"\n",
+        );
+        write_file(
+            &site.join("tinypkg-1.2.0.dist-info").join("METADATA"),
+            "Metadata-Version: 2.1\nName: tinypkg\nVersion: 1.2.0\n",
+        );
+
+        let index = PythonSemanticIndex::build("tinypkg", &pkg_root).unwrap();
+        assert_eq!(index.package_version(), Some("1.2.0"));
+        assert!(!index.is_stale());
+        let bytes = index.to_bytes().unwrap();
+
+        let (restored, refresh) =
+            PythonSemanticIndex::load_or_build("tinypkg", &pkg_root, None, Some(&bytes), |_, _| {})
+                .unwrap();
+        assert_eq!(refresh.unwrap().reparsed, 0);
+        assert_eq!(restored.len(), index.len());
+        assert_eq!(
+            restored.search("configuration file", 1)[0].item_name,
+            "parse_config"
+        );
+
+        write_file(
+            &pkg_root.join("beta.py"),
+            "def send_sms(number):\n    \"\"\"Deliver a text message to a phone.\"\"\"\n",
+        );
+        fs::remove_file(pkg_root.join("alpha.py")).unwrap();
+        assert!(restored.is_stale());
+
+        let (refreshed, refresh) =
+            PythonSemanticIndex::load_or_build("tinypkg", &pkg_root, None, Some(&bytes), |_, _| {})
+                .unwrap();
+        let refresh = refresh.unwrap();
+        assert_eq!((refresh.reparsed, refresh.removed), (1, 1));
+        assert!(refreshed.search("configuration file", 5).is_empty());
+        assert_eq!(refreshed.search("phone text", 1)[0].item_name, "send_sms");
+
+        write_file(
+            &site.join("tinypkg-1.2.0.dist-info").join("METADATA"),
+            "Metadata-Version: 2.1\nName: tinypkg\nVersion: 1.3.0\n",
+        );
+        let (_, refresh) =
+            PythonSemanticIndex::load_or_build("tinypkg", &pkg_root, None, Some(&bytes), |_, _| {})
+                .unwrap();
+        assert!(refresh.is_none(), "version change must force a rebuild");
+    }
+
+    #[test]
+    fn touch_only_refresh_keeps_public_reexport_paths() {
+        let tmp = tempdir().unwrap();
+        let pkg_root = tmp.path().join("pk");
+        let sessions = pkg_root.join("sessions.py");
+        write_file(
+            &pkg_root.join("__init__.py"),
+            "from .sessions import Session\n",
+        );
+        write_file(
+            &sessions,
+            "class Session:\n    \"\"\"Persist settings across requests.\"\"\"\n",
+        );
+
+        let index = PythonSemanticIndex::build("pk", &pkg_root).unwrap();
+        assert_eq!(index.search("session", 1)[0].qualified_path, "pk.Session");
+
+        fs::File::options()
+            .write(true)
+            .open(&sessions)
+            .unwrap()
+            .set_modified(SystemTime::now() + std::time::Duration::from_secs(5))
+            .unwrap();
+        let (index, refresh) = index.refreshed(|_, _| {}).unwrap();
+        assert!(!refresh.changed());
+        assert_eq!(refresh.restamped, 1);
+        let results = index.search("session", 1);
+        assert_eq!(results[0].qualified_path, "pk.Session");
+        assert_eq!(results[0].alternate_paths, vec!["pk.sessions.Session"]);
+    }
+
+    #[test]
+    fn refresh_rehashes_touched_files_and_matches_full_rebuild() {
+        let tmp = tempdir().unwrap();
+        let pkg_root = tmp.path().join("localpkg");
+        let helpers = pkg_root.join("helpers.py");
+        write_file(&pkg_root.join("__init__.py"), "");
+        write_file(
+            &helpers,
+            "def slugify(text):\n    \"\"\"Turn text into a url slug.\"\"\"\n",
+        );
+        write_file(
+            &pkg_root.join("models.py"),
+            "class Invoice:\n    \"\"\"A customer invoice with line items.\"\"\"\n",
+        );
+
+        let index = PythonSemanticIndex::build("localpkg", &pkg_root).unwrap();
+
+        // Same contents, different mtime: rehashed but not re-parsed.
+        let source = fs::read_to_string(&helpers).unwrap();
+        fs::File::options()
+            .write(true)
+            .open(&helpers)
+            .unwrap()
+            .set_modified(SystemTime::now() + std::time::Duration::from_secs(5))
+            .unwrap();
+        assert!(index.is_stale());
+        let (index, refresh) = index.refreshed(|_, _| {}).unwrap();
+        assert_eq!(refresh.reparsed, 0);
+        assert!(!refresh.changed());
+        assert_eq!(refresh.restamped, 1);
+        assert!(refresh.needs_persist(), "new stamps must be persisted");
+        assert!(!index.is_stale());
+
+        write_file(
+            &helpers,
+            &format!(
+                "{source}\ndef invoice_total(invoice):\n    \"\"\"Sum invoice line items.\"\"\"\n"
+            ),
+        );
+        let (index, refresh) = index.refreshed(|_, _| {}).unwrap();
+        assert_eq!(refresh.reparsed, 1);
+        assert_eq!(refresh.unchanged, 2);
+
+        let rebuilt = PythonSemanticIndex::build("localpkg", &pkg_root).unwrap();
+        assert_eq!(index.corpus, rebuilt.corpus);
+        let top = |index: &PythonSemanticIndex| {
+            index
+                .search("invoice line items", 3)
+                .into_iter()
+                .map(|r| (r.qualified_path, r.score))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(top(&index), top(&rebuilt));
+        assert!(top(&index)
+            .iter()
+            .any(|(path, _)| path == "localpkg.helpers.invoice_total"));
+    }
+
+    #[test]
+    fn searches_installed_packages_with_one_ranking() {
+        let tmp = tempdir().unwrap();
+        let site = tmp.path().join("site-packages");
+        write_file(
+            &site.join("isodates/__init__.py"),
+            r#"
+def parse_iso(text: str):
+    """Parse an ISO 8601 date or datetime string."""
+    return text
+"#,
+        );
+        write_file(
+            &site.join("colorize.py"),
+            r#"
+def parse_color(text: str):
+    """Parse a hex color string such as #ff0000."""
+    return text
+
+def paint(text: str, color: str) -> str:
+    """Wrap text in terminal color escape codes."""
+    return text
+"#,
+        );
+        fs::create_dir_all(site.join("isodates-1.0.dist-info")).unwrap();
+        write_file(&site.join("_virtualenv.py"), "def patch():\n    pass\n");
+
+        let installed = crate::doc_engine::finder::list_python_site_packages(&site).unwrap();
+        assert_eq!(installed, vec!["colorize", "isodates"]);
+
+        let indexes: Vec<PythonSemanticIndex> = installed
+            .iter()
+            .map(|name| {
+                let root = if site.join(name).is_dir() {
+                    site.join(name)
+                } else {
+                    site.join(format!("{name}.py"))
+                };
+                PythonSemanticIndex::build(name, &root).unwrap()
+            })
+            .collect();
+        let refs: Vec<&PythonSemanticIndex> = indexes.iter().collect();
+
+        let results = PythonSemanticIndex::search_many(&refs, "parse ISO dates", 5);
+        assert_eq!(results[0].package, "isodates");
+        assert_eq!(results[0].qualified_path, "isodates.parse_iso");
+        assert!(results
+            .iter()
+            .any(|r| r.package == "colorize" && r.item_name == "parse_color"));
+        assert!(results
+            .windows(2)
+            .all(|pair| pair[0].score >= pair[1].score));
+    }
+
+    #[test]
+    fn indexes_methods_properties_constants_and_type_aliases() {
+        let tmp = tempdir().unwrap();
+        let pkg_root = tmp.path().join("webby");
+        write_file(
+            &pkg_root.join("__init__.py"),
+            r#"
+from typing import TypeAlias, overload
+
+DEFAULT_TIMEOUT = 30
+"""Seconds to wait for a server response before giving up."""
+
+retry_count = 3
+
+Headers: TypeAlias = dict[str, str]
+
+type Params = dict[str, str]
+
+class Session:
+    """Persist settings across requests."""
+
+    def __init__(self):
+        """Create a session with an empty connection pool."""
+
+    def __repr__(self):
+        return "Session()"
+
+    def mount(self, prefix, adapter):
+        """Register a connection adapter for a URL prefix."""
+
+    @property
+    def closed(self) -> bool:
+        """Whether the session released its connections."""
+        return False
+
+    @closed.setter
+    def closed(self, value):
+        pass
+
+    @overload
+    def get(self, url: str) -> str: ...
+    def get(self, url):
+        """Send a GET request."""
+
+    class Hooks:
+        def dispatch(self, event):
+            """Run the hooks registered for an event."""
+"#,
+        );
+
+        let index = PythonSemanticIndex::build("webby", &pkg_root).unwrap();
+        let kinds: std::collections::HashMap<String, String> = index
+            .entries()
+            .map(|entry| (entry.qualified_path.clone(), entry.kind.clone()))
+            .collect();
+        let kind = |path: &str| kinds.get(path).map(String::as_str);
+        assert_eq!(kind("webby.Session"), Some("class"));
+        assert_eq!(kind("webby.Session.__init__"), Some("method"));
+        assert_eq!(kind("webby.Session.mount"), Some("method"));
+        assert_eq!(kind("webby.Session.closed"), Some("property"));
+        assert_eq!(kind("webby.Session.Hooks.dispatch"), Some("method"));
+        assert_eq!(kind("webby.DEFAULT_TIMEOUT"), Some("constant"));
+        assert_eq!(kind("webby.Headers"), Some("type_alias"));
+        assert_eq!(kind("webby.Params"), Some("type_alias"));
+        assert_eq!(kind("webby.Session.__repr__"), None);
+        assert_eq!(kind("webby.retry_count"), None);
+        assert_eq!(
+            index
+                .entries()
+                .filter(|e| e.qualified_path == "webby.Session.get"
+                    || e.qualified_path == "webby.Session.closed")
+                .count(),
+            2,
+            "overloads and property setters are not indexed twice"
+        );
+        assert!(index.validate().warnings.is_empty());
+
+        let mount = index.search("Session.mount", 1);
+        assert_eq!(mount[0].qualified_path, "webby.Session.mount");
+        assert_eq!(mount[0].item_name, "mount");
+        assert_eq!(
+            mount[0].doc_preview.as_deref(),
+            Some("Register a connection adapter for a URL prefix.")
+        );
+
+        let timeout = index.search("server response timeout", 1);
+        assert_eq!(timeout[0].qualified_path, "webby.DEFAULT_TIMEOUT");
+        assert_eq!(timeout[0].kind, "constant");
+    }
+
+    #[test]
+    fn reports_symbols_under_their_public_reexport_paths() {
+        let temp = tempdir().unwrap();
+        let pkg_root = temp.path().join("fetchy");
+        write_file(
+            &pkg_root.join("__init__.py"),
+            r#"
+from .sessions import Session
+from ._compat import *
+from . import _internal
+
+__all__ = ["Session", "urljoin"]
+"#,
+        );
+        write_file(
+            &pkg_root.join("sessions.py"),
+            r#"
+class Session:
+    """Persist cookies and connection pools across HTTP requests."""
+
+    def send(self, request):
+        """Send a prepared HTTP request."""
+"#,
+        );
+        write_file(
+            &pkg_root.join("_compat.py"),
+            r#"
+__all__ = ["urljoin"]
+
+def urljoin(base, url):
+    """Join a base URL and a relative URL."""
+
+def legacy_urljoin(base, url):
+    """Join a base URL and a relative URL on old interpreters."""
+"#,
+        );
+        write_file(
+            &pkg_root.join("_internal.py"),
+            r#"
+def join_url_parts(base, url):
+    """Join a base URL and a relative URL without validation."""
+"#,
+        );
+
+        let index = PythonSemanticIndex::build("fetchy", &pkg_root).unwrap();
+
+        let session = index.search("persist cookies across requests", 1);
+        assert_eq!(session[0].qualified_path, "fetchy.Session");
+        assert_eq!(session[0].module_path, "fetchy.sessions");
+        assert_eq!(session[0].alternate_paths, vec!["fetchy.sessions.Session"]);
+
+        let send = index.search("Session.send", 1);
+        assert_eq!(send[0].qualified_path, "fetchy.Session.send");
+        assert_eq!(
+            send[0].alternate_paths,
+            vec!["fetchy.sessions.Session.send"]
+        );
+
+        let joins = index.search("join base url relative url", 3);
+        let paths: Vec<&str> = joins.iter().map(|r| r.qualified_path.as_str()).collect();
+        assert_eq!(paths[0], "fetchy.urljoin", "{paths:?}");
+        assert_eq!(joins[0].alternate_paths, vec!["fetchy._compat.urljoin"]);
+        assert!(joins[1..].iter().all(|r| r.score < joins[0].score * 0.75));
+
+        let completions = index.completion_index().complete("fetchy.Se", 5);
+        assert!(completions.iter().any(|c| c.value == "fetchy.Session"));
+    }
+
+    #[test]
+    fn merges_stub_signatures_and_indexes_compiled_modules() {
+        let temp = tempdir().unwrap();
+        let pkg_root = temp.path().join("fastjson");
+        write_file(
+            &pkg_root.join("__init__.py"),
+            r#"
+from .api import dumps
+"#,
+        );
+        write_file(
+            &pkg_root.join("api.py"),
+            r#"
+def dumps(obj, indent=None):
+    """Serialize an object to a JSON formatted string."""
+"#,
+        );
+        write_file(
+            &pkg_root.join("api.pyi"),
+            "def dumps(obj: object, indent: int | None = ...) -> str: ...\n",
+        );
+        write_file(
+            &pkg_root.join("_speedups.cpython-312-x86_64-linux-gnu.so"),
+            "",
+        );
+        write_file(
+            &temp.path().join("fastjson-stubs/_speedups.pyi"),
+            r#"
+from typing import overload
+
+@overload
+def encode_basestring(s: str) -> str:
+    """Escape a string for inclusion in JSON output."""
+@overload
+def encode_basestring(s: bytes) -> str: ...
+"#,
+        );
+
+        let index = PythonSemanticIndex::build("fastjson", &pkg_root).unwrap();
+        assert_eq!(index.len(), 2);
+
+        let dumps = index.search("serialize json string", 1);
+        assert_eq!(dumps[0].qualified_path, "fastjson.dumps");
+        assert_eq!(
+            dumps[0].signature.as_deref(),
+            Some("def dumps(obj: object, indent: int | None = ...) -> str: ...")
+        );
+        assert!(dumps[0].file.ends_with("api.py"));
+
+        let escape = index.search("escape string", 1);
+        assert_eq!(
+            escape[0].qualified_path,
+            "fastjson._speedups.encode_basestring"
+        );
+        assert_eq!(
+            escape[0].signature.as_deref(),
+            Some("def encode_basestring(s: str) -> str:")
+        );
+        assert!(escape[0].file.ends_with("fastjson-stubs/_speedups.pyi"));
+        assert!(!index.is_stale());
+
+        write_file(
+            &pkg_root.join("api.pyi"),
+            "def dumps(obj: object, *, indent: int = ...) -> str: ...\n",
+        );
+        assert!(index.is_stale());
+        let (index, refresh) = index.refreshed(|_, _| {}).unwrap();
+        assert_eq!(refresh.reparsed, 1);
+        let dumps = index.search("serialize json string", 1);
+        assert_eq!(
+            dumps[0].signature.as_deref(),
+            Some("def dumps(obj: object, *, indent: int = ...) -> str: ...")
+        );
+    }
+
+    #[test]
+    fn search_results_carry_structured_docstrings() {
+        let temp = tempdir().unwrap();
+        let pkg_root = temp.path().join("mailer");
+        write_file(
+            &pkg_root.join("__init__.py"),
+            r#"
+def send_mail(to, subject, retries=3):
+    """Deliver an email message.
+
+    Args:
+        to (str): Recipient address.
+        subject (str): Subject line.
+        retries (int): Attempts before giving up.
+
+    Returns:
+        bool: Whether the server accepted the message.
+
+    Raises:
+        ConnectionError: If the SMTP server is unreachable.
+    """
+"#,
+        );
+
+        let index = PythonSemanticIndex::build("mailer", &pkg_root).unwrap();
+        let results = index.search("deliver email", 1);
+        let doc = results[0].structured_docstring.as_ref().unwrap();
+        assert_eq!(doc.style, crate::shared_types::DocstringStyle::Google);
+        assert_eq!(doc.summary.as_deref(), Some("Deliver an email message."));
+        let names: Vec<&str> = doc.parameters.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, ["to", "subject", "retries"]);
+        assert_eq!(
+            doc.returns.as_ref().unwrap().type_name.as_deref(),
+            Some("bool")
+        );
+        assert_eq!(doc.raises[0].exception, "ConnectionError");
     }
 }
@@ -0,0 +1,683 @@
+//! Semantic search over the items of a Rust crate.
+//!
+//! Entries come from the crate's rustdoc search index (names, paths and
+//! one-line descriptions) and, when its sources are available locally, are
+//! enriched with full `///` doc comments and declaration signatures. A crate
+//! that is only available as local sources (a project crate or an offline
+//! registry checkout) can be indexed from the sources alone. Ranking uses the
+//! same BM25F scoring as the Python semantic index.
+
+use anyhow::{anyhow, Context, Result};
+use regex::Regex;
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    sync::OnceLock,
+};
+use walkdir::WalkDir;
+
+use crate::doc_engine::types::SearchIndexData;
+use crate::index_core::{identifier_tokens, text_tokens, Bm25Corpus, Bm25Field, FieldTerms};
+use crate::shared_types::SemanticSearchResult;
+
+const MAX_DOC_PREVIEW_CHARS: usize = 200;
+
+/// Search-index kinds that do not describe searchable crate items.
+const SKIPPED_KINDS: &[&str] = &["primitive", "keyword", "import", "extern_crate"];
+
+/// Semantic index for a Rust crate.
+#[derive(Debug)]
+pub struct RustSemanticIndex {
+    crate_name: String,
+    version: String,
+    entries: Vec<RustSemanticEntry>,
+    corpus: Bm25Corpus,
+}
+
+#[derive(Debug)]
+struct RustSemanticEntry {
+    name: String,
+    qualified_path: String,
+    module_path: String,
+    kind: String,
+    doc_preview: Option<String>,
+    signature: Option<String>,
+    file: Option<PathBuf>,
+    line: u32,
+    terms: FieldTerms,
+}
+
+/// A public item declared in local crate sources.
+#[derive(Debug, Clone)]
+struct SourceItem {
+    name: String,
+    kind: &'static str,
+    /// Public module path relative to the crate root (`sync::mpsc`)
+    module_path: String,
+    /// Enclosing type or trait for methods
+    parent: Option<String>,
+    doc: String,
+    signature: String,
+    file: PathBuf,
+    line: u32,
+}
+
+impl RustSemanticIndex {
+    /// Index the items of a rustdoc search index, enriching them with doc
+    /// comments and signatures from `sources` (the crate directory) when given.
+    pub fn from_search_index(data: &SearchIndexData, sources: Option<&Path>) -> Self {
+        let crate_ident = data.crate_name.replace('-', "_");
+        let source_items = sources.map(scan_crate_sources).unwrap_or_default();
+        let mut by_name: HashMap<(&str, Option<&str>), Vec<&SourceItem>> = HashMap::new();
+        for item in &source_items {
+            by_name
+                .entry((item.name.as_str(), item.parent.as_deref()))
+                .or_default()
+                .push(item);
+        }
+
+        let entries = data
+            .items
+            .iter()
+            .filter(|item| !SKIPPED_KINDS.contains(&item.kind.as_str()))
+            .map(|item| {
+                let parent_path = item
+                    .parent_index
+                    .and_then(|idx| data.paths.get(idx))
+                    .filter(|path| !path.is_empty());
+                let parent_name = parent_path.and_then(|path| path.rsplit("::").next());
+                let qualified_path = match parent_path {
+                    Some(parent) => format!("{parent}::{}", item.name),
+                    None if item.path.is_empty() => item.name.clone(),
+                    None => format!("{}::{}", item.path, item.name),
+                };
+                let relative_module = item
+                    .path
+                    .strip_prefix(&crate_ident)
+                    .map(|rest| rest.trim_start_matches("::"))
+                    .unwrap_or(&item.path);
+                let source = by_name
+                    .get(&(item.name.as_str(), parent_name))
+                    .and_then(|candidates| best_source_match(candidates, relative_module));
+
+                let description = strip_html(&item.description);
+                let doc = source
+                    .map(|s| s.doc.clone())
+                    .filter(|doc| !doc.is_empty())
+                    .or_else(|| (!description.is_empty()).then_some(description));
+                RustSemanticEntry::new(
+                    &item.name,
+                    qualified_path,
+                    item.path.clone(),
+                    item.kind.clone(),
+                    doc.as_deref(),
+                    source,
+                )
+            })
+            .collect();
+
+        Self::with_entries(&data.crate_name, &data.version, entries)
+    }
+
+    /// Index a crate from its local sources alone.
+    ///
+    /// Without rustdoc's view of re-exports, items are placed at their
+    /// declaring module with private modules collapsed into their parent,
+    /// which matches the public path for the common `mod x; pub use x::*`
+    /// layout.
+    pub fn from_sources(crate_name: &str, version: &str, root: &Path) -> Result<Self> {
+        let crate_ident = crate_name.replace('-', "_");
+        let entries: Vec<RustSemanticEntry> = scan_crate_sources(root)
+            .iter()
+            .map(|item| {
+                let module_path = if item.module_path.is_empty() {
+                    crate_ident.clone()
+                } else {
+                    format!("{crate_ident}::{}", item.module_path)
+                };
+                let qualified_path = match &item.parent {
+                    Some(parent) => format!("{module_path}::{parent}::{}", item.name),
+                    None => format!("{module_path}::{}", item.name),
+                };
+                RustSemanticEntry::new(
+                    &item.name,
+                    qualified_path,
+                    module_path,
+                    item.kind.to_string(),
+                    Some(&item.doc)
+                        .filter(|doc| !doc.is_empty())
+                        .map(|d| d.as_str()),
+                    Some(item),
+                )
+            })
+            .collect();
+
+        if entries.is_empty() {
+            return Err(anyhow!(
+                "No public Rust items discovered for crate '{}' in {}",
+                crate_name,
+                root.display()
+            ));
+        }
+        Ok(Self::with_entries(crate_name, version, entries))
+    }
+
+    fn with_entries(crate_name: &str, version: &str, entries: Vec<RustSemanticEntry>) -> Self {
+        let corpus = Bm25Corpus::from_documents(entries.iter().map(|entry| &entry.terms));
+        Self {
+            crate_name: crate_name.to_string(),
+            version: version.to_string(),
+            entries,
+            corpus,
+        }
+    }
+
+    /// Execute a semantic search query, returning ranked results.
+    pub fn search(&self, query: &str, limit: usize) -> Vec<SemanticSearchResult> {
+        if query.trim().is_empty() || limit == 0 {
+            return Vec::new();
+        }
+
+        self.corpus
+            .rank(
+                query,
+                self.entries.iter(),
+                |entry| &entry.terms,
+                |a, b| {
+                    a.qualified_path
+                        .len()
+                        .cmp(&b.qualified_path.len())
+                        .then_with(|| a.qualified_path.cmp(&b.qualified_path))
+                },
+            )
+            .into_iter()
+            .take(limit)
+            .map(|(score, entry)| SemanticSearchResult {
+                language: "rust".to_string(),
+                package: self.crate_name.clone(),
+                module_path: entry.module_path.clone(),
+                item_name: entry.name.clone(),
+                qualified_path: entry.qualified_path.clone(),
+                alternate_paths: Vec::new(),
+                kind: entry.kind.clone(),
+                file: entry
+                    .file
+                    .as_ref()
+                    .map(|f| f.to_string_lossy().into_owned())
+                    .unwrap_or_default(),
+                line: entry.line,
+                score,
+                doc_preview: entry.doc_preview.clone(),
+                structured_docstring: None,
+                signature: entry.signature.clone(),
+                source_preview: None,
+            })
+            .collect()
+    }
+
+    /// Crate version the index was built for.
+    pub fn version(&self) -> &str {
+        &self.version
+    }
+
+    /// Number of indexed items.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the index holds no items.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl RustSemanticEntry {
+    fn new(
+        name: &str,
+        qualified_path: String,
+        module_path: String,
+        kind: String,
+        doc: Option<&str>,
+        source: Option<&SourceItem>,
+    ) -> Self {
+        let mut terms = FieldTerms::default();
+        terms.add(Bm25Field::Name, identifier_tokens(name));
+        let container = qualified_path
+            .strip_suffix(name)
+            .unwrap_or(&qualified_path)
+            .split("::")
+            .flat_map(identifier_tokens)
+            .collect();
+        terms.add(Bm25Field::Path, container);
+        // Only the summary paragraph is indexed: it is what rustdoc's search
+        // index carries, and the remainder is dominated by examples and caveats.
+        let summary = doc.map(first_paragraph);
+        if let Some(summary) = &summary {
+            terms.add(Bm25Field::Doc, text_tokens(summary));
+        }
+        let signature = source.map(|s| s.signature.clone());
+        if let Some(signature) = &signature {
+            terms.add(Bm25Field::Signature, text_tokens(signature));
+        }
+
+        Self {
+            name: name.to_string(),
+            qualified_path,
+            module_path,
+            kind,
+            doc_preview: summary,
+            signature,
+            file: source.map(|s| s.file.clone()),
+            line: source.map_or(0, |s| s.line),
+            terms,
+        }
+    }
+}
+
+/// Pick the declaration whose module best matches the indexed item's module.
+fn best_source_match<'a>(candidates: &[&'a SourceItem], module: &str) -> Option<&'a SourceItem> {
+    let wanted: Vec<&str> = module.split("::").filter(|s| !s.is_empty()).collect();
+    candidates
+        .iter()
+        .max_by_key(|item| {
+            let segments: Vec<&str> = item
+                .module_path
+                .split("::")
+                .filter(|s| !s.is_empty())
+                .collect();
+            let exact = segments == wanted;
+            let shared = segments.iter().filter(|s| wanted.contains(s)).count();
+            (exact, shared)
+        })
+        .copied()
+}
+
+/// Source directory of a crate checkout (`<crate>/src`, or the directory
+/// itself for sysroot crates).
+fn source_root(root: &Path) -> PathBuf {
+    if root.join("src").join("lib.rs").is_file() {
+        root.join("src")
+    } else {
+        root.to_path_buf()
+    }
+}
+
+/// Collect public items from every `.rs` file of a crate.
+fn scan_crate_sources(root: &Path) -> Vec<SourceItem> {
+    let src = source_root(root);
+    let files: Vec<(Vec<String>, PathBuf, String)> = WalkDir::new(&src)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .filter(|e| e.path().extension().is_some_and(|ext| ext == "rs"))
+        .filter_map(|e| {
+            let segments = file_module_segments(&src, e.path())?;
+            let content = fs::read_to_string(e.path()).ok()?;
+            Some((segments, e.into_path(), content))
+        })
+        .collect();
+
+    let by_module: HashMap<&[String], &str> = files
+        .iter()
+        .map(|(segments, _, content)| (segments.as_slice(), content.as_str()))
+        .collect();
+
+    let mut items = Vec::new();
+    for (segments, path, content) in &files {
+        let module_path = public_module_path(segments, &by_module);
+        scan_source_file(content, path, &module_path, &mut items);
+    }
+    items
+}
+
+/// Module segments of a file relative to the source root: `lib.rs` is the
+/// crate root, `a/mod.rs` and `a.rs` are `a`, `a/b.rs` is `a::b`.
+fn file_module_segments(src: &Path, file: &Path) -> Option<Vec<String>> {
+    let relative = file.strip_prefix(src).ok()?.with_extension("");
+    let mut segments: Vec<String> = relative
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy().into_owned())
+        .collect();
+    match segments.last().map(String::as_str) {
+        Some("mod") | Some("lib") => {
+            segments.pop();
+        }
+        Some("main") | Some("build") if segments.len() == 1 => return None,
+        _ => {}
+    }
+    if segments.first().is_some_and(|s| s == "bin" || s == "tests") {
+        return None;
+    }
+    Some(segments)
+}
+
+/// Public path of a module, dropping segments not declared `pub mod` in
+/// their parent module.
+fn public_module_path(segments: &[String], by_module: &HashMap<&[String], &str>) -> String {
+    let mut public = Vec::new();
+    for depth in 1..=segments.len() {
+        let name = &segments[depth - 1];
+        let declared_pub = by_module.get(&segments[..depth - 1]).is_some_and(|parent| {
+            Regex::new(&format!(
+                r"(?m)^\s*pub\s+mod\s+(r#)?{}\s*[;{{]",
+                regex::escape(name)
+            ))
+            .is_ok_and(|re| re.is_match(parent))
+        });
+        if declared_pub {
+            public.push(name.as_str());
+        }
+    }
+    public.join("::")
+}
+
+struct SourcePatterns {
+    item: Regex,
+    trait_fn: Regex,
+    container: Regex,
+    macro_rules: Regex,
+}
+
+fn patterns() -> &'static SourcePatterns {
+    static PATTERNS: OnceLock<SourcePatterns> = OnceLock::new();
+    PATTERNS.get_or_init(|| SourcePatterns {
+        item: Regex::new(
+            r#"^\s*pub\s+(?:(?:async|unsafe|const|extern\s+"[^"]*")\s+)*(fn|struct|enum|trait|type|union|const|static|mod)\s+(?:r#)?([A-Za-z_][A-Za-z0-9_]*)"#,
+        )
+        .unwrap(),
+        trait_fn: Regex::new(r"^\s*(?:(?:async|unsafe|const)\s+)*fn\s+([A-Za-z_][A-Za-z0-9_]*)")
+            .unwrap(),
+        container: Regex::new(
+            r"^(\s*)(?:pub\s+)?(?:unsafe\s+)?(impl|trait)\b(?:\s*<[^{]*?>)?\s+(?:[^{]*?\s+for\s+)?(?:[A-Za-z_][A-Za-z0-9_]*::)*([A-Za-z_][A-Za-z0-9_]*)",
+        )
+        .unwrap(),
+        macro_rules: Regex::new(r"^\s*macro_rules!\s*([A-Za-z_][A-Za-z0-9_]*)").unwrap(),
+    })
+}
+
+/// Line-based scan for documented public declarations.
+///
+/// Tracks the enclosing `impl`/`trait` block by indentation so that methods
+/// are attributed to their type; trait impls are skipped since their
+/// documentation lives on the trait.
+fn scan_source_file(content: &str, file: &Path, module_path: &str, items: &mut Vec<SourceItem>) {
+    let patterns = patterns();
+    let mut doc_lines: Vec<&str> = Vec::new();
+    let mut macro_exported = false;
+    // (indent, type name, is trait, is trait impl)
+    let mut container: Option<(usize, String, bool, bool)> = None;
+    let mut in_code_block = false;
+
+    for (idx, line) in content.lines().enumerate() {
+        let trimmed = line.trim_start();
+        let indent = line.len() - trimmed.len();
+
+        if let Some((open_indent, ..)) = &container {
+            if indent == *open_indent && trimmed.starts_with('}') {
+                container = None;
+                doc_lines.clear();
+                continue;
+            }
+        }
+
+        if let Some(doc) = trimmed.strip_prefix("///") {
+            let doc = doc.strip_prefix(' ').unwrap_or(doc);
+            if doc.trim_start().starts_with("```") {
+                in_code_block = !in_code_block;
+            } else if !in_code_block {
+                doc_lines.push(doc);
+            }
+            continue;
+        }
+        in_code_block = false;
+        if trimmed.starts_with("#[") {
+            macro_exported |= trimmed.starts_with("#[macro_export");
+            continue;
+        }
+
+        let (parent, in_trait, in_trait_impl) = match &container {
+            Some((_, name, is_trait, is_trait_impl)) => {
+                (Some(name.clone()), *is_trait, *is_trait_impl)
+            }
+            None => (None, false, false),
+        };
+        let declared = if let Some(caps) = patterns.item.captures(line) {
+            let kind = match &caps[1] {
+                "fn" if parent.is_some() => "method",
+                "fn" => "function",
+                "type" => "type_def",
+                "const" => "constant",
+                "mod" => "module",
+                "struct" => "struct",
+                "enum" => "enum",
+                "trait" => "trait",
+                "union" => "union",
+                _ => "static",
+            };
+            (!in_trait_impl).then(|| (kind, caps[2].to_string()))
+        } else if in_trait {
+            patterns
+                .trait_fn
+                .captures(line)
+                .map(|caps| ("tymethod", caps[1].to_string()))
+        } else if macro_exported {
+            patterns
+                .macro_rules
+                .captures(line)
+                .map(|caps| ("macro", caps[1].to_string()))
+        } else {
+            None
+        };
+
+        if let Some((kind, name)) = declared {
+            items.push(SourceItem {
+                name,
+                kind,
+                module_path: if kind == "macro" {
+                    String::new()
+                } else {
+                    module_path.to_string()
+                },
+                parent: if kind == "method" || kind == "tymethod" {
+                    parent
+                } else {
+                    None
+                },
+                doc: doc_lines.join("\n").trim().to_string(),
+                signature: declaration_signature(trimmed),
+                file: file.to_path_buf(),
+                line: (idx + 1) as u32,
+            });
+        }
+
+        if container.is_none() && trimmed.trim_end().ends_with('{') {
+            if let Some(caps) = patterns.container.captures(line) {
+                let is_trait = &caps[2] == "trait";
+                let is_trait_impl = !is_trait && line.contains(" for ");
+                container = Some((caps[1].len(), caps[3].to_string(), is_trait, is_trait_impl));
+            }
+        }
+        doc_lines.clear();
+        macro_exported = false;
+    }
+}
+
+/// Declaration line without its body opener.
+fn declaration_signature(line: &str) -> String {
+    let line = line.trim();
+    line.strip_suffix('{')
+        .or_else(|| line.strip_suffix(';'))
+        .unwrap_or(line)
+        .trim_end()
+        .to_string()
+}
+
+fn first_paragraph(doc: &str) -> String {
+    let paragraph = doc
+        .split("\n\n")
+        .next()
+        .unwrap_or(doc)
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ");
+    if paragraph.chars().count() <= MAX_DOC_PREVIEW_CHARS {
+        return paragraph;
+    }
+    let mut preview: String = paragraph.chars().take(MAX_DOC_PREVIEW_CHARS).collect();
+    preview.push('…');
+    preview
+}
+
+fn strip_html(text: &str) -> String {
+    static TAGS: OnceLock<Regex> = OnceLock::new();
+    let tags = TAGS.get_or_init(|| Regex::new(r"<[^>]+>").unwrap());
+    tags.replace_all(text, "")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+}
+
+/// Read the `[package]` name and version of a local crate manifest.
+///
+/// Only plain `key = "value"` lines are understood; a workspace-inherited
+/// version (`version.workspace = true`) is reported as `0.0.0`.
+pub fn local_crate_manifest(dir: &Path) -> Result<(String, String)> {
+    let manifest_path = dir.join("Cargo.toml");
+    let manifest = fs::read_to_string(&manifest_path)
+        .with_context(|| format!("Failed to read {}", manifest_path.display()))?;
+
+    let mut in_package = false;
+    let mut name = None;
+    let mut version = None;
+    for line in manifest.lines().map(str::trim) {
+        if line.starts_with('[') {
+            in_package = line == "[package]";
+            continue;
+        }
+        if !in_package {
+            continue;
+        }
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        let value = value.trim().trim_matches('"').to_string();
+        match key.trim() {
+            "name" => name = Some(value),
+            "version" => version = Some(value),
+            _ => {}
+        }
+    }
+
+    let name = name.ok_or_else(|| anyhow!("{} has no package name", manifest_path.display()))?;
+    Ok((name, version.unwrap_or_else(|| "0.0.0".to_string())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::doc_engine::types::SearchIndexItem;
+    use tempfile::tempdir;
+
+    fn item(name: &str, kind: &str, path: &str, description: &str) -> SearchIndexItem {
+        SearchIndexItem {
+            name: name.to_string(),
+            kind: kind.to_string(),
+            path: path.to_string(),
+            description: description.to_string(),
+            parent_index: None,
+        }
+    }
+
+    #[test]
+    fn ranks_bounded_channel_constructor_first() {
+        let data = SearchIndexData {
+            crate_name: "tokio".to_string(),
+            version: "1.0.0".to_string(),
+            items: vec![
+                item("sync", "module", "tokio", "Synchronization primitives for use in asynchronous contexts."),
+                item("mpsc", "module", "tokio::sync", "A multi-producer, single-consumer queue for sending values between asynchronous tasks."),
+                item("channel", "function", "tokio::sync::mpsc", "Creates a bounded mpsc channel for communicating between asynchronous tasks with backpressure."),
+                item("unbounded_channel", "function", "tokio::sync::mpsc", "Creates an unbounded mpsc channel for communicating between asynchronous tasks without backpressure."),
+                item("channel", "function", "tokio::sync::oneshot", "Creates a new one-shot channel for sending single values across asynchronous tasks."),
+                item("channel", "function", "tokio::sync::broadcast", "Create a bounded, multi-producer, multi-consumer channel where each sent value is broadcasted to all active receivers."),
+                item("channel", "function", "tokio::sync::watch", "Creates a new watch channel, returning the &quot;send&quot; and &quot;receive&quot; handles."),
+                item("Sender", "struct", "tokio::sync::mpsc", "Sends values to the associated <code>Receiver</code>."),
+                item("Semaphore", "struct", "tokio::sync", "Counting semaphore performing asynchronous permit acquisition."),
+                item("spawn", "function", "tokio::task", "Spawns a new asynchronous task, returning a <code>JoinHandle</code> for it."),
+                item("sleep", "function", "tokio::time", "Waits until <code>duration</code> has elapsed."),
+                item("futures_channel", "extern_crate", "tokio", "Channels for bounded communication between asynchronous tasks."),
+            ],
+            paths: Vec::new(),
+        };
+
+        let index = RustSemanticIndex::from_search_index(&data, None);
+        let results = index.search("create a bounded channel", 3);
+        assert_eq!(results[0].qualified_path, "tokio::sync::mpsc::channel");
+        assert_eq!(results[0].language, "rust");
+
+        let sleep = index.search("wait for a duration", 1);
+        assert_eq!(sleep[0].qualified_path, "tokio::time::sleep");
+
+        assert!(index
+            .search("bounded channel", 20)
+            .iter()
+            .all(|result| result.qualified_path != "tokio::futures_channel"));
+    }
+
+    #[test]
+    fn indexes_local_sources_with_methods_and_collapsed_private_modules() {
+        let tmp = tempdir().unwrap();
+        let src = tmp.path().join("src");
+        fs::create_dir_all(src.join("queue")).unwrap();
+        fs::write(
+            src.join("lib.rs"),
+            "//! Demo crate.\n\npub mod queue;\n\n/// Parse a configuration string.\npub fn parse_config(input: &str) -> Config {\n    todo!()\n}\n\n#[macro_export]\n/// Build a queue from values.\nmacro_rules! queue {\n    () => {};\n}\n",
+        )
+        .unwrap();
+        fs::write(
+            src.join("queue/mod.rs"),
+            "mod bounded;\npub use bounded::BoundedQueue;\n\n/// Behaviour shared by queues.\npub trait Queue {\n    /// Remove and return the oldest value.\n    fn pop(&mut self) -> Option<u32>;\n}\n",
+        )
+        .unwrap();
+        fs::write(
+            src.join("queue/bounded.rs"),
+            "/// A queue with a fixed capacity.\npub struct BoundedQueue;\n\nimpl BoundedQueue {\n    /// Create an empty queue holding at most `capacity` values.\n    pub fn with_capacity(capacity: usize) -> Self {\n        BoundedQueue\n    }\n}\n\nimpl Queue for BoundedQueue {\n    fn pop(&mut self) -> Option<u32> {\n        None\n    }\n}\n",
+        )
+        .unwrap();
+
+        let index = RustSemanticIndex::from_sources("demo", "0.1.0", tmp.path()).unwrap();
+        let paths: Vec<&str> = index
+            .entries
+            .iter()
+            .map(|e| e.qualified_path.as_str())
+            .collect();
+        assert!(paths.contains(&"demo::queue::BoundedQueue"));
+        assert!(paths.contains(&"demo::queue::BoundedQueue::with_capacity"));
+        assert!(paths.contains(&"demo::queue::Queue::pop"));
+        assert!(paths.contains(&"demo::queue"));
+        assert_eq!(
+            paths.iter().filter(|p| p.ends_with("::pop")).count(),
+            1,
+            "trait impls are not indexed separately"
+        );
+
+        let results = index.search("create an empty queue", 1);
+        assert_eq!(
+            results[0].qualified_path,
+            "demo::queue::BoundedQueue::with_capacity"
+        );
+        assert_eq!(
+            results[0].signature.as_deref(),
+            Some("pub fn with_capacity(capacity: usize) -> Self")
+        );
+        assert!(results[0].file.ends_with("bounded.rs"));
+
+        let macro_result = index.search("build queue from values", 3);
+        assert!(macro_result
+            .iter()
+            .any(|r| r.qualified_path == "demo::queue" && r.kind == "macro"));
+    }
+}
@@ -0,0 +1,1101 @@
+//! Versioned decoder for rustdoc search indexes.
+//!
+//! rustdoc has changed the layout of its search index several times. This
+//! module detects which generation an index belongs to and decodes it into
+//! the version-independent [`SearchIndexData`]:
+//!
+//! | Generation | rustdoc | Layout |
+//! |---|---|---|
+//! | [`SearchIndexFormat::RowTuples`] | ≤ 1.51 | one `[kind, name, path, desc, parent, ..]` tuple per item |
+//! | [`SearchIndexFormat::Columns`] | 1.52 – 1.69 | parallel `t`/`n`/`q`/`d`/`i` arrays, numeric kinds |
+//! | [`SearchIndexFormat::PackedColumns`] | 1.70 – 1.90 | letter-encoded kinds, sparse paths, optional `Map` wrapper |
+//! | [`SearchIndexFormat::Sharded`] | ≥ 1.91 | `search.index/` directory of per-column shards |
+//!
+//! The first three live in a single `search-index.js`. The sharded layout
+//! has a `search.index/root.js` naming, for every column, the shard files
+//! that hold its rows; see [`ShardedIndexRoot`].
+//!
+//! When no index can be read at all, the "List of all items" page
+//! ([`SearchIndexFormat::AllItemsPage`]) still yields item names, kinds and
+//! modules, though no descriptions or associated items.
+
+use anyhow::{anyhow, Context, Result};
+use base64::Engine as _;
+use regex::Regex;
+use scraper::{ElementRef, Html, Selector};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
+
+use crate::doc_engine::types::{SearchIndexData, SearchIndexItem};
+
+/// Item type names in the numbering used before rustdoc 1.70.
+const LEGACY_ITEM_TYPES: &[&str] = &[
+    "mod",
+    "externcrate",
+    "import",
+    "struct",
+    "enum",
+    "fn",
+    "type",
+    "static",
+    "trait",
+    "impl",
+    "tymethod",
+    "method",
+    "structfield",
+    "variant",
+    "macro",
+    "primitive",
+    "associatedtype",
+    "constant",
+    "associatedconstant",
+    "union",
+    "foreigntype",
+    "keyword",
+    "existential",
+    "attr",
+    "derive",
+    "traitalias",
+];
+
+/// Item type names in the numbering used since rustdoc 1.70, which sorts
+/// keywords and primitives first.
+const ITEM_TYPES: &[&str] = &[
+    "keyword",
+    "primitive",
+    "mod",
+    "externcrate",
+    "import",
+    "struct",
+    "enum",
+    "fn",
+    "type",
+    "static",
+    "trait",
+    "impl",
+    "tymethod",
+    "method",
+    "structfield",
+    "variant",
+    "macro",
+    "associatedtype",
+    "constant",
+    "associatedconstant",
+    "union",
+    "foreigntype",
+    "existential",
+    "attr",
+    "derive",
+    "traitalias",
+    "generic",
+    "attribute",
+];
+
+/// Generation of the rustdoc search index layout
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchIndexFormat {
+    /// One tuple per item under `items`/`i` (rustdoc ≤ 1.51)
+    RowTuples,
+    /// Parallel column arrays with numeric kinds (rustdoc 1.52 – 1.69)
+    Columns,
+    /// Columns with letter-encoded kinds and sparse paths (rustdoc 1.70 – 1.90)
+    PackedColumns,
+    /// Per-column shards under `search.index/` (rustdoc ≥ 1.91)
+    Sharded,
+    /// `all.html` item listing, used when no search index is available
+    AllItemsPage,
+}
+
+impl SearchIndexFormat {
+    /// Map a kind id of this generation to the engine's kind vocabulary.
+    pub fn kind_name(&self, kind_id: usize) -> String {
+        let table = match self {
+            SearchIndexFormat::RowTuples | SearchIndexFormat::Columns => LEGACY_ITEM_TYPES,
+            SearchIndexFormat::PackedColumns
+            | SearchIndexFormat::Sharded
+            | SearchIndexFormat::AllItemsPage => ITEM_TYPES,
+        };
+        table
+            .get(kind_id)
+            .map(|name| kind_from_type_name(name).to_string())
+            .unwrap_or_else(|| format!("unknown_{kind_id}"))
+    }
+}
+
+/// A decoded search index together with the detected layout generation
+#[derive(Debug, Clone)]
+pub struct DecodedSearchIndex {
+    pub format: SearchIndexFormat,
+    pub data: SearchIndexData,
+}
+
+/// Decode the entry for `crate_name` from a `search-index.js` file.
+///
+/// Both `crate-name` and `crate_name` keys are accepted. Multi-crate indexes
+/// (e.g. the standard library's) are supported; other crates are ignored.
+pub fn parse_search_index(
+    js_content: &str,
+    crate_name: &str,
+    version: &str,
+) -> Result<DecodedSearchIndex> {
+    let root = extract_index_root(js_content)?;
+    let crate_key_alt = crate_name.replace('-', "_");
+    let crate_data = [crate_name, crate_key_alt.as_str()]
+        .into_iter()
+        .find_map(|key| crate_entry(&root, key))
+        .ok_or_else(|| anyhow!("Crate '{crate_name}' not found in search index"))?;
+
+    let format = detect_format(crate_data)?;
+    let data = match format {
+        SearchIndexFormat::RowTuples => decode_row_tuples(crate_data, crate_name, version)?,
+        SearchIndexFormat::Columns | SearchIndexFormat::PackedColumns => {
+            decode_columns(format, crate_data, crate_name, version)?
+        }
+        SearchIndexFormat::Sharded | SearchIndexFormat::AllItemsPage => {
+            unreachable!("detect_format only yields search-index.js layouts")
+        }
+    };
+    Ok(DecodedSearchIndex { format, data })
+}
+
+/// Decode a rustdoc "List of all items" page (`all.html`).
+///
+/// Item kinds come from the link targets (`struct.Foo.html`), paths from the
+/// link text (`sync::Mutex`), which is relative to the crate root.
+pub fn parse_all_items_page(
+    html: &str,
+    crate_name: &str,
+    version: &str,
+) -> Result<DecodedSearchIndex> {
+    let document = Html::parse_document(html);
+    let link_selector = Selector::parse("ul.all-items li a").expect("valid selector");
+    let crate_ident = crate_name.replace('-', "_");
+
+    let mut items = Vec::new();
+    for link in document.select(&link_selector) {
+        let Some(kind) = link
+            .value()
+            .attr("href")
+            .and_then(|href| href.rsplit('/').next())
+            .and_then(|file| file.split_once('.'))
+            .map(|(type_name, _)| kind_from_type_name(type_name))
+        else {
+            continue;
+        };
+        let text = element_text(&link);
+        let (module, name) = match text.rsplit_once("::") {
+            Some((module, name)) => (format!("{crate_ident}::{module}"), name.to_string()),
+            None => (crate_ident.clone(), text.clone()),
+        };
+        if name.is_empty() {
+            continue;
+        }
+        items.push(SearchIndexItem {
+            name,
+            kind: kind.to_string(),
+            path: module,
+            description: String::new(),
+            parent_index: None,
+        });
+    }
+
+    if items.is_empty() {
+        return Err(anyhow!(
+            "No items found in the all-items page for crate '{crate_name}'"
+        ));
+    }
+
+    Ok(DecodedSearchIndex {
+        format: SearchIndexFormat::AllItemsPage,
+        data: SearchIndexData {
+            crate_name: crate_name.to_string(),
+            version: version.to_string(),
+            items,
+            paths: Vec::new(),
+        },
+    })
+}
+
+/// Data columns of a sharded index read by [`decode_sharded_index`].
+pub const SHARDED_COLUMNS: &[&str] = &["name", "path", "entry", "desc"];
+
+/// The `search.index/root.js` of a sharded index.
+///
+/// Rows are numbered across the whole documentation root, which may hold
+/// several crates. Each column stores the values of its non-empty rows in
+/// consecutive shards, `search.index/<column>/<hash>.js`.
+#[derive(Debug, Clone)]
+pub struct ShardedIndexRoot {
+    columns: HashMap<String, ShardedColumn>,
+}
+
+#[derive(Debug, Clone)]
+struct ShardedColumn {
+    /// Number of non-empty rows stored in each shard
+    counts: Vec<usize>,
+    /// Shard file names (hex hashes), parallel to `counts`
+    shards: Vec<String>,
+    /// Rows without a value; they are skipped in the shards
+    empty: HashSet<u32>,
+}
+
+impl ShardedIndexRoot {
+    /// Parse the `rr_('…')` call of a `root.js` file.
+    pub fn parse(js_content: &str) -> Result<Self> {
+        let call = js_content
+            .find("rr_(")
+            .ok_or_else(|| anyhow!("No rr_() call found in sharded search index root"))?;
+        let literal = unescape_js_string_literal(&js_content[call + "rr_(".len()..])
+            .context("Malformed sharded search index root")?;
+        let root: serde_json::Map<String, Value> =
+            serde_json::from_str(&literal).context("Failed to parse sharded search index root")?;
+
+        let mut columns = HashMap::new();
+        for (name, column) in &root {
+            let field = |key: &str| column.get(key).and_then(Value::as_str);
+            let (Some(counts), Some(hashes)) = (field("N"), field("H")) else {
+                continue;
+            };
+            let counts = decode_hex_vlq(counts);
+            let hashes = decode_base64(hashes)
+                .with_context(|| format!("Invalid shard hashes for column '{name}'"))?;
+            if hashes.len() != counts.len() * 6 {
+                return Err(anyhow!(
+                    "Column '{name}' lists {} shards but {} hash bytes",
+                    counts.len(),
+                    hashes.len()
+                ));
+            }
+            let empty = match field("E") {
+                Some(bitmap) => decode_roaring_bitmap(&decode_base64(bitmap)?)
+                    .with_context(|| format!("Invalid empty-row bitmap for column '{name}'"))?,
+                None => HashSet::new(),
+            };
+            columns.insert(
+                name.clone(),
+                ShardedColumn {
+                    counts,
+                    shards: hashes.chunks(6).map(hex::encode).collect(),
+                    empty,
+                },
+            );
+        }
+        Ok(Self { columns })
+    }
+
+    /// Shard files holding the [`SHARDED_COLUMNS`], relative to the
+    /// `search.index/` directory (`name/ded6a02982a2.js`).
+    pub fn shard_files(&self) -> Vec<String> {
+        SHARDED_COLUMNS
+            .iter()
+            .filter_map(|name| Some((name, self.columns.get(*name)?)))
+            .flat_map(|(name, column)| {
+                column
+                    .shards
+                    .iter()
+                    .map(move |shard| format!("{name}/{shard}.js"))
+            })
+            .collect()
+    }
+
+    /// Values of a column by row, reassembled from its loaded shards.
+    fn column_rows(
+        &self,
+        name: &str,
+        shards: &HashMap<String, String>,
+    ) -> Result<HashMap<u32, String>> {
+        let Some(column) = self.columns.get(name) else {
+            return Ok(HashMap::new());
+        };
+        let mut rows = HashMap::new();
+        let mut row = 0u32;
+        for (shard, &count) in column.shards.iter().zip(&column.counts) {
+            let file = format!("{name}/{shard}.js");
+            let contents = shards
+                .get(&file)
+                .ok_or_else(|| anyhow!("Search index shard {file} was not loaded"))?;
+            let values = decode_shard(contents).with_context(|| format!("Invalid shard {file}"))?;
+            if values.len() < count {
+                return Err(anyhow!(
+                    "Shard {file} holds {} values, expected {count}",
+                    values.len()
+                ));
+            }
+            for value in values.into_iter().take(count) {
+                while column.empty.contains(&row) {
+                    row += 1;
+                }
+                rows.insert(row, value);
+                row += 1;
+            }
+        }
+        Ok(rows)
+    }
+}
+
+/// Decode the items of `crate_name` from a sharded index. `shards` maps the
+/// paths returned by [`ShardedIndexRoot::shard_files`] to their contents.
+///
+/// Every row with an `entry` is an item: `[crate, kind, module, exact
+/// module, parent, trait parent, ..]`, where row references are 1-based and
+/// 0 means none. Modules and parents are rows with a `path` of
+/// `[kind, module path, ..]`.
+pub fn decode_sharded_index(
+    root: &ShardedIndexRoot,
+    shards: &HashMap<String, String>,
+    crate_name: &str,
+    version: &str,
+) -> Result<DecodedSearchIndex> {
+    let names = root.column_rows("name", shards)?;
+    let paths = root.column_rows("path", shards)?;
+    let entries = root.column_rows("entry", shards)?;
+    let descriptions = root.column_rows("desc", shards)?;
+
+    let name = |row: u32| names.get(&row).map(String::as_str).unwrap_or_default();
+    // `module::Name` of a module or parent row
+    let qualified = |row: u32| {
+        let module = paths
+            .get(&row)
+            .and_then(|path| serde_json::from_str::<Value>(path).ok())
+            .and_then(|path| path.get(1).and_then(Value::as_str).map(str::to_string))
+            .unwrap_or_default();
+        if module.is_empty() {
+            name(row).to_string()
+        } else {
+            format!("{module}::{}", name(row))
+        }
+    };
+    let reference = |entry: &[Value], field: usize| {
+        entry
+            .get(field)
+            .and_then(Value::as_u64)
+            .filter(|&row| row > 0)
+            .map(|row| row as u32 - 1)
+    };
+
+    let crate_ident = crate_name.replace('-', "_");
+    let mut item_rows: Vec<(u32, Vec<Value>)> = entries
+        .iter()
+        .filter_map(|(&row, entry)| Some((row, serde_json::from_str(entry).ok()?)))
+        .collect();
+    item_rows.sort_by_key(|(row, _)| *row);
+
+    let mut items = Vec::new();
+    let mut parent_paths = Vec::new();
+    let mut parent_slots: HashMap<u32, usize> = HashMap::new();
+    for (row, entry) in item_rows {
+        let Some(krate) = entry.first().and_then(Value::as_u64).map(|k| k as u32) else {
+            continue;
+        };
+        // The crate root is recorded as an entry of its own crate
+        if krate == row || name(krate) != crate_ident {
+            continue;
+        }
+        let kind_id = entry.get(1).and_then(Value::as_u64).unwrap_or(u64::MAX) as usize;
+        let parent_index = reference(&entry, 4).map(|parent| {
+            *parent_slots.entry(parent).or_insert_with(|| {
+                parent_paths.push(qualified(parent));
+                parent_paths.len() - 1
+            })
+        });
+        items.push(SearchIndexItem {
+            name: name(row).to_string(),
+            kind: SearchIndexFormat::Sharded.kind_name(kind_id),
+            path: reference(&entry, 2).map(qualified).unwrap_or_default(),
+            description: descriptions.get(&row).cloned().unwrap_or_default(),
+            parent_index,
+        });
+    }
+
+    if items.is_empty() {
+        return Err(anyhow!("Crate '{crate_name}' not found in search index"));
+    }
+    Ok(DecodedSearchIndex {
+        format: SearchIndexFormat::Sharded,
+        data: SearchIndexData {
+            crate_name: crate_name.to_string(),
+            version: version.to_string(),
+            items,
+            paths: parent_paths,
+        },
+    })
+}
+
+/// `data-resource-suffix` of a rustdoc page, appended to the name of
+/// `search.index/root.js`.
+pub fn resource_suffix(html: &str) -> Option<String> {
+    let document = Html::parse_document(html);
+    let selector = Selector::parse(r#"meta[name="rustdoc-vars"]"#).expect("valid selector");
+    document
+        .select(&selector)
+        .next()?
+        .value()
+        .attr("data-resource-suffix")
+        .map(str::to_string)
+}
+
+/// Detect the layout generation of a single crate's index entry.
+pub fn detect_format(crate_data: &Value) -> Result<SearchIndexFormat> {
+    if crate_data.get("items").is_some() {
+        return Ok(SearchIndexFormat::RowTuples);
+    }
+    match crate_data.get("t") {
+        Some(Value::String(_)) => Ok(SearchIndexFormat::PackedColumns),
+        Some(Value::Array(_)) => Ok(SearchIndexFormat::Columns),
+        _ if crate_data
+            .get("i")
+            .and_then(Value::as_array)
+            .is_some_and(|rows| rows.iter().all(Value::is_array)) =>
+        {
+            Ok(SearchIndexFormat::RowTuples)
+        }
+        _ => Err(anyhow!(
+            "Unrecognized search index layout (keys: {})",
+            crate_data
+                .as_object()
+                .map(|o| o.keys().cloned().collect::<Vec<_>>().join(", "))
+                .unwrap_or_default()
+        )),
+    }
+}
+
+/// Translate a rustdoc item type name (as used in URLs and `itemTypes`) to
+/// the engine's kind vocabulary.
+pub fn kind_from_type_name(type_name: &str) -> &str {
+    match type_name {
+        "mod" => "module",
+        "externcrate" => "extern_crate",
+        "fn" => "function",
+        "type" => "type_def",
+        "associatedtype" => "assoc_type",
+        "associatedconstant" => "assoc_const",
+        "foreigntype" => "foreign_type",
+        "traitalias" => "trait_alias",
+        other => other,
+    }
+}
+
+/// Extract the JSON root from the JavaScript wrapper.
+///
+/// Handles `JSON.parse('…')` payloads (escaped JS string literals with line
+/// continuations), `new Map(JSON.parse('[…]'))`, plain object literals and
+/// the per-crate `searchIndex["name"] = {…};` assignments of early releases.
+fn extract_index_root(js_content: &str) -> Result<Value> {
+    if let Some(pos) = js_content.find("JSON.parse(") {
+        let literal = unescape_js_string_literal(&js_content[pos + "JSON.parse(".len()..])
+            .context("Malformed JSON.parse string literal in search index")?;
+        return serde_json::from_str(&literal).context("Failed to parse search index JSON");
+    }
+
+    // Early releases abbreviate `null` as `N` (`var N = null;`)
+    let null_alias = Regex::new(r"\bvar\s+N\s*=\s*null\b")
+        .expect("valid regex")
+        .is_match(js_content);
+    let assignment =
+        Regex::new(r#"searchIndex(?:\[\s*["']([^"']+)["']\s*\])?\s*=\s*"#).expect("valid regex");
+    let mut crates = serde_json::Map::new();
+    for caps in assignment.captures_iter(js_content) {
+        let rest = &js_content[caps.get(0).map_or(0, |m| m.end())..];
+        let Some(literal) = balanced_json_slice(rest) else {
+            continue;
+        };
+        let value: Value = if null_alias {
+            serde_json::from_str(&expand_null_alias(literal))
+        } else {
+            serde_json::from_str(literal)
+        }
+        .context("Failed to parse search index object literal")?;
+        match caps.get(1) {
+            Some(key) => {
+                crates.insert(key.as_str().to_string(), value);
+            }
+            // `var searchIndex = {};` followed by per-crate assignments
+            None if value.as_object().is_some_and(|o| o.is_empty()) => {}
+            None => return Ok(value),
+        }
+    }
+
+    if crates.is_empty() {
+        Err(anyhow!("No search index assignment found"))
+    } else {
+        Ok(Value::Object(crates))
+    }
+}
+
+/// Find one crate's entry in an object root (`{"name": {...}}`) or a `Map`
+/// root (`[["name", {...}], ...]`).
+fn crate_entry<'a>(root: &'a Value, crate_key: &str) -> Option<&'a Value> {
+    match root {
+        Value::Object(map) => map.get(crate_key),
+        Value::Array(pairs) => pairs
+            .iter()
+            .find_map(|pair| match pair.as_array()?.as_slice() {
+                [Value::String(name), data] if name == crate_key => Some(data),
+                _ => None,
+            }),
+        _ => None,
+    }
+}
+
+fn decode_row_tuples(
+    crate_data: &Value,
+    crate_name: &str,
+    version: &str,
+) -> Result<SearchIndexData> {
+    let rows = crate_data
+        .get("items")
+        .or_else(|| crate_data.get("i"))
+        .and_then(Value::as_array)
+        .ok_or_else(|| anyhow!("Items array not found in crate data"))?;
+
+    let mut items = Vec::with_capacity(rows.len());
+    let mut current_path = String::new();
+    for row in rows {
+        let Some(row) = row.as_array() else {
+            continue;
+        };
+        let field = |idx: usize| row.get(idx).and_then(Value::as_str).unwrap_or_default();
+        // An empty path repeats the previous item's path
+        if !field(2).is_empty() {
+            current_path = field(2).to_string();
+        }
+        items.push(SearchIndexItem {
+            name: field(1).to_string(),
+            kind: SearchIndexFormat::RowTuples
+                .kind_name(row.first().and_then(Value::as_u64).unwrap_or(u64::MAX) as usize),
+            path: current_path.clone(),
+            description: field(3).to_string(),
+            parent_index: row.get(4).and_then(Value::as_u64).map(|idx| idx as usize),
+        });
+    }
+
+    let parents = crate_data.get("paths").or_else(|| crate_data.get("p"));
+    Ok(SearchIndexData {
+        crate_name: crate_name.to_string(),
+        version: version.to_string(),
+        paths: parent_paths(parents, &items),
+        items,
+    })
+}
+
+fn decode_columns(
+    format: SearchIndexFormat,
+    crate_data: &Value,
+    crate_name: &str,
+    version: &str,
+) -> Result<SearchIndexData> {
+    let kinds: Vec<usize> = match crate_data.get("t") {
+        // Each character encodes one kind id as an offset from 'A'
+        Some(Value::String(letters)) => letters
+            .chars()
+            .map(|c| (c as usize).saturating_sub('A' as usize))
+            .collect(),
+        Some(Value::Array(ids)) => ids
+            .iter()
+            .map(|id| id.as_u64().unwrap_or(u64::MAX) as usize)
+            .collect(),
+        _ => return Err(anyhow!("Kind column 't' not found in crate data")),
+    };
+    let names = crate_data
+        .get("n")
+        .and_then(Value::as_array)
+        .ok_or_else(|| anyhow!("Name column 'n' not found in crate data"))?;
+    if names.len() != kinds.len() {
+        return Err(anyhow!(
+            "Search index columns disagree: {} kinds, {} names",
+            kinds.len(),
+            names.len()
+        ));
+    }
+
+    let module_paths = decode_path_column(crate_data.get("q"), names.len());
+    // Descriptions moved to separate `search.desc` shards in later releases
+    let descriptions = crate_data.get("d").and_then(Value::as_array);
+    // Parent ids are 1-based; 0 means "no parent"
+    let parents = crate_data.get("i").and_then(Value::as_array);
+
+    let items: Vec<SearchIndexItem> = names
+        .iter()
+        .enumerate()
+        .map(|(idx, name)| SearchIndexItem {
+            name: name.as_str().unwrap_or_default().to_string(),
+            kind: format.kind_name(kinds[idx]),
+            path: module_paths[idx].clone(),
+            description: descriptions
+                .and_then(|d| d.get(idx))
+                .and_then(Value::as_str)
+                .unwrap_or_default()
+                .to_string(),
+            parent_index: parents
+                .and_then(|p| p.get(idx))
+                .and_then(Value::as_u64)
+                .filter(|&parent| parent > 0)
+                .map(|parent| parent as usize - 1),
+        })
+        .collect();
+
+    Ok(SearchIndexData {
+        crate_name: crate_name.to_string(),
+        version: version.to_string(),
+        paths: parent_paths(crate_data.get("p"), &items),
+        items,
+    })
+}
+
+/// Expand the module path column.
+///
+/// Dense form: one entry per item, where `""` repeats the previous path.
+/// Sparse form: `[[item_index, path], ...]`, each path applying until the
+/// next entry.
+fn decode_path_column(column: Option<&Value>, len: usize) -> Vec<String> {
+    let mut paths = vec![String::new(); len];
+    let Some(entries) = column.and_then(Value::as_array) else {
+        return paths;
+    };
+
+    let sparse = entries.iter().any(Value::is_array);
+    let mut changes: Vec<(usize, &str)> = if sparse {
+        entries
+            .iter()
+            .filter_map(|entry| match entry.as_array()?.as_slice() {
+                [idx, path, ..] => Some((idx.as_u64()? as usize, path.as_str()?)),
+                _ => None,
+            })
+            .collect()
+    } else {
+        entries
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, path)| Some((idx, path.as_str()?)))
+            .filter(|(_, path)| !path.is_empty())
+            .collect()
+    };
+    changes.sort_by_key(|(idx, _)| *idx);
+
+    let mut current = "";
+    let mut changes = changes.into_iter().peekable();
+    for (idx, slot) in paths.iter_mut().enumerate() {
+        while let Some((_, path)) = changes.next_if(|(at, _)| *at <= idx) {
+            current = path;
+        }
+        *slot = current.to_string();
+    }
+    paths
+}
+
+/// Resolve parent entries (`[kind, name, ..]`) to fully-qualified paths.
+///
+/// The index stores only parent names; the module comes from the first
+/// child that references the parent, which shares its module path.
+fn parent_paths(parents: Option<&Value>, items: &[SearchIndexItem]) -> Vec<String> {
+    let Some(parents) = parents.and_then(Value::as_array) else {
+        return Vec::new();
+    };
+    let mut paths: Vec<String> = parents
+        .iter()
+        .map(|parent| match parent {
+            Value::String(path) => path.clone(),
+            Value::Array(fields) => fields
+                .get(1)
+                .and_then(Value::as_str)
+                .unwrap_or_default()
+                .to_string(),
+            _ => String::new(),
+        })
+        .collect();
+    let mut qualified = vec![false; paths.len()];
+
+    for item in items {
+        let Some(parent) = item.parent_index else {
+            continue;
+        };
+        if parent < paths.len() && !qualified[parent] && !item.path.is_empty() {
+            if !paths[parent].contains("::") {
+                paths[parent] = format!("{}::{}", item.path, paths[parent]);
+            }
+            qualified[parent] = true;
+        }
+    }
+    paths
+}
+
+/// Values stored in a shard: `rd_("…")` holds the bytes as a string,
+/// `rb_("…")` as base64.
+///
+/// Each value is its length as hex digits, all but the last one written
+/// `@`-based and the last `` ` ``-based, followed by its bytes; a digit
+/// `0`–`?` instead repeats one of the last 16 values, most recent first.
+fn decode_shard(js_content: &str) -> Result<Vec<String>> {
+    let (call, base64) = match (js_content.find("rd_("), js_content.find("rb_(")) {
+        (Some(at), _) => (at, false),
+        (None, Some(at)) => (at, true),
+        (None, None) => return Err(anyhow!("No rd_() or rb_() call found")),
+    };
+    let literal = unescape_js_string_literal(&js_content[call + "rd_(".len()..])?;
+    let data = if base64 {
+        decode_base64(&literal)?
+    } else {
+        // Every character stands for one byte
+        literal.chars().map(|c| c as u32 as u8).collect()
+    };
+
+    let mut values = Vec::new();
+    let mut recent: Vec<String> = Vec::new();
+    let mut i = 0;
+    while i < data.len() {
+        if (b'0'..=b'?').contains(&data[i]) {
+            let value = recent
+                .get(usize::from(data[i] - b'0'))
+                .ok_or_else(|| anyhow!("Back-reference beyond the values read so far"))?;
+            values.push(value.clone());
+            i += 1;
+            continue;
+        }
+        let mut len = 0usize;
+        while let Some(&digit) = data.get(i) {
+            len = (len << 4) | usize::from(digit & 0x0F);
+            i += 1;
+            if digit >= 0x60 {
+                break;
+            }
+        }
+        let bytes = data
+            .get(i..i + len)
+            .ok_or_else(|| anyhow!("Value runs past the end of the shard"))?;
+        let value = String::from_utf8_lossy(bytes).into_owned();
+        i += len;
+        recent.insert(0, value.clone());
+        recent.truncate(16);
+        values.push(value);
+    }
+    Ok(values)
+}
+
+/// Decode the shard counts of a column: hex digits written `@`-based, the
+/// last digit of each number `` ` ``-based.
+fn decode_hex_vlq(encoded: &str) -> Vec<usize> {
+    let mut numbers = Vec::new();
+    let mut current = 0usize;
+    for byte in encoded.bytes() {
+        current = (current << 4) | usize::from(byte & 0x0F);
+        if byte >= 0x60 {
+            numbers.push(current);
+            current = 0;
+        }
+    }
+    numbers
+}
+
+fn decode_base64(encoded: &str) -> Result<Vec<u8>> {
+    base64::engine::general_purpose::STANDARD
+        .decode(encoded)
+        .context("Invalid base64 in search index")
+}
+
+/// Decode a serialized Roaring bitmap, in the portable format or one of
+/// the compact forms rustdoc uses for small sets.
+fn decode_roaring_bitmap(bytes: &[u8]) -> Result<HashSet<u32>> {
+    let truncated = || anyhow!("Truncated Roaring bitmap");
+    let u16_at = |at: usize| -> Result<u32> {
+        let pair = bytes.get(at..at + 2).ok_or_else(truncated)?;
+        Ok(u32::from(u16::from_le_bytes([pair[0], pair[1]])))
+    };
+    // Compact forms start from a (low 16 bits, high 16 bits) pair
+    let first_value = || -> Result<u32> { Ok(u16_at(1)? | (u16_at(3)? << 16)) };
+
+    let mut values = HashSet::new();
+    let Some(&tag) = bytes.first() else {
+        return Ok(values);
+    };
+    match tag {
+        0 => {}
+        // Ascending values as 2-byte (0xF_) or 1-byte (0xD_) deltas
+        0xF1..=0xFF | 0xD1..=0xDF => {
+            let wide = tag > 0xF0;
+            let mut value = first_value()?;
+            values.insert(value);
+            let mut at = 5;
+            for _ in 1..(tag & 0x0F) {
+                let delta = if wide {
+                    u16_at(at)?
+                } else {
+                    u32::from(*bytes.get(at).ok_or_else(truncated)?)
+                };
+                value += delta;
+                values.insert(value);
+                at += if wide { 2 } else { 1 };
+            }
+        }
+        // A single run
+        0xE1..=0xEF => {
+            let start = first_value()?;
+            values.extend(start..start + u32::from(tag & 0x0F));
+        }
+        // Up to 57 values, 4 bytes each
+        0x01..=0x39 => {
+            for index in 0..usize::from(tag) {
+                let at = 1 + index * 4;
+                values.insert(u16_at(at)? | (u16_at(at + 2)? << 16));
+            }
+        }
+        0x3A | 0x3B => {
+            let has_runs = tag == 0x3B;
+            let (containers, mut at) = if has_runs {
+                (u16_at(2)? as usize + 1, 4)
+            } else {
+                let count = bytes.get(4..8).ok_or_else(truncated)?;
+                (
+                    u32::from_le_bytes([count[0], count[1], count[2], count[3]]) as usize,
+                    8,
+                )
+            };
+            let run_flags = if has_runs {
+                let len = containers.div_ceil(8);
+                let flags = bytes.get(at..at + len).ok_or_else(truncated)?;
+                at += len;
+                flags
+            } else {
+                &[]
+            };
+            let headers = at;
+            at += containers * 4;
+            if !has_runs || containers >= 4 {
+                // Container offsets
+                at += containers * 4;
+            }
+            for index in 0..containers {
+                let key = u16_at(headers + index * 4)? << 16;
+                let cardinality = u16_at(headers + index * 4 + 2)? as usize + 1;
+                if run_flags
+                    .get(index / 8)
+                    .is_some_and(|f| f & (1 << (index % 8)) != 0)
+                {
+                    let runs = u16_at(at)? as usize;
+                    at += 2;
+                    for run in 0..runs {
+                        let start = u16_at(at + run * 4)?;
+                        let extra = u16_at(at + run * 4 + 2)?;
+                        values.extend((start..=start + extra).map(|low| key | low));
+                    }
+                    at += runs * 4;
+                } else if cardinality >= 4096 {
+                    let bits = bytes.get(at..at + 8192).ok_or_else(truncated)?;
+                    values.extend(
+                        (0..65536u32)
+                            .filter(|low| bits[(low / 8) as usize] & (1 << (low % 8)) != 0)
+                            .map(|low| key | low),
+                    );
+                    at += 8192;
+                } else {
+                    for entry in 0..cardinality {
+                        values.insert(key | u16_at(at + entry * 2)?);
+                    }
+                    at += cardinality * 2;
+                }
+            }
+        }
+        other => return Err(anyhow!("Unknown Roaring bitmap tag {other:#04x}")),
+    }
+    Ok(values)
+}
+
+/// Decode the JS string literal at the start of `input` (after optional
+/// whitespace), stopping at its closing quote.
+fn unescape_js_string_literal(input: &str) -> Result<String> {
+    let input = input.trim_start();
+    let mut chars = input.chars();
+    let quote = chars
+        .next()
+        .filter(|c| matches!(c, '\'' | '"' | '`'))
+        .ok_or_else(|| anyhow!("Expected a string literal"))?;
+
+    let mut out = String::with_capacity(input.len());
+    while let Some(c) = chars.next() {
+        match c {
+            c if c == quote => return Ok(out),
+            '\\' => match chars.next() {
+                // Line continuation
+                Some('\n') => {}
+                Some('\r') => {
+                    let rest = chars.as_str();
+                    if let Some(stripped) = rest.strip_prefix('\n') {
+                        chars = stripped.chars();
+                    }
+                }
+                Some('n') => out.push('\n'),
+                Some('r') => out.push('\r'),
+                Some('t') => out.push('\t'),
+                Some('u') => {
+                    let hex: String = chars.by_ref().take(4).collect();
+                    let code = u32::from_str_radix(&hex, 16)
+                        .with_context(|| format!("Invalid \\u escape '{hex}'"))?;
+                    out.push(char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER));
+                }
+                Some('x') => {
+                    let hex: String = chars.by_ref().take(2).collect();
+                    let code = u32::from_str_radix(&hex, 16)
+                        .with_context(|| format!("Invalid \\x escape '{hex}'"))?;
+                    out.push(char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER));
+                }
+                Some(other) => out.push(other),
+                None => break,
+            },
+            other => out.push(other),
+        }
+    }
+    Err(anyhow!("Unterminated string literal"))
+}
+
+/// Replace bare `N` values (outside strings) with `null`.
+fn expand_null_alias(literal: &str) -> String {
+    let mut out = String::with_capacity(literal.len());
+    let mut in_string = false;
+    let mut escaped = false;
+    let mut prev_significant = ' ';
+    let mut chars = literal.chars().peekable();
+    while let Some(c) = chars.next() {
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+            out.push(c);
+            continue;
+        }
+        let next = chars.peek().copied().unwrap_or(' ');
+        if c == 'N'
+            && matches!(prev_significant, ',' | '[' | ':')
+            && matches!(next, ',' | ']' | '}')
+        {
+            out.push_str("null");
+        } else {
+            if c == '"' {
+                in_string = true;
+            }
+            out.push(c);
+        }
+        if !c.is_whitespace() {
+            prev_significant = c;
+        }
+    }
+    out
+}
+
+/// Return the balanced `{…}` or `[…]` JSON value at the start of `input`
+/// (after optional whitespace), ignoring brackets inside strings.
+fn balanced_json_slice(input: &str) -> Option<&str> {
+    let trimmed = input.trim_start();
+    let offset = input.len() - trimmed.len();
+    let (open, close) = match trimmed.as_bytes().first()? {
+        b'{' => (b'{', b'}'),
+        b'[' => (b'[', b']'),
+        _ => return None,
+    };
+
+    let mut depth = 0usize;
+    let mut in_string = false;
+    let mut escaped = false;
+    for (i, &b) in trimmed.as_bytes().iter().enumerate() {
+        if in_string {
+            match b {
+                _ if escaped => escaped = false,
+                b'\\' => escaped = true,
+                b'"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match b {
+            b'"' => in_string = true,
+            b if b == open => depth += 1,
+            b if b == close => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(&input[offset..offset + i + 1]);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+fn element_text(element: &ElementRef) -> String {
+    element.text().collect::<String>().trim().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn kind_tables_differ_between_generations() {
+        assert_eq!(SearchIndexFormat::Columns.kind_name(3), "struct");
+        assert_eq!(SearchIndexFormat::Columns.kind_name(5), "function");
+        assert_eq!(SearchIndexFormat::Columns.kind_name(8), "trait");
+        assert_eq!(SearchIndexFormat::PackedColumns.kind_name(5), "struct");
+        assert_eq!(SearchIndexFormat::PackedColumns.kind_name(7), "function");
+        assert_eq!(SearchIndexFormat::PackedColumns.kind_name(10), "trait");
+        assert_eq!(SearchIndexFormat::Columns.kind_name(999), "unknown_999");
+    }
+
+    #[test]
+    fn unescapes_js_literals_with_line_continuations() {
+        let literal = "'{\\\n\"a\":\"it\\'s \\\\\"q\\\\\"\"\\\n}') trailing";
+        assert_eq!(
+            unescape_js_string_literal(literal).unwrap(),
+            "{\"a\":\"it's \\\"q\\\"\"}"
+        );
+    }
+
+    #[test]
+    fn decodes_shard_values_and_back_references() {
+        let values = decode_shard(r#"rd_("cAnydfrom0dinto1A`0123456789abcdef")"#).unwrap();
+        assert_eq!(
+            values,
+            vec!["Any", "from", "from", "into", "from", "0123456789abcdef"]
+        );
+        assert_eq!(decode_hex_vlq("BmAcj`"), vec![45, 19, 10, 0]);
+    }
+
+    #[test]
+    fn decodes_portable_and_compact_roaring_bitmaps() {
+        let empty = decode_base64("OjAAAAAAAAA=").unwrap();
+        assert!(decode_roaring_bitmap(&empty).unwrap().is_empty());
+
+        // One run container: 44 and 45
+        let runs = [0x3B, 0x30, 0, 0, 1, 0, 0, 1, 0, 1, 0, 44, 0, 1, 0];
+        assert_eq!(
+            decode_roaring_bitmap(&runs).unwrap(),
+            HashSet::from([44, 45])
+        );
+        let deltas = [0xD3, 7, 0, 1, 0, 2, 3];
+        assert_eq!(
+            decode_roaring_bitmap(&deltas).unwrap(),
+            HashSet::from([0x1_0007, 0x1_0009, 0x1_000C])
+        );
+        let run = [0xE3, 5, 0, 0, 0];
+        assert_eq!(
+            decode_roaring_bitmap(&run).unwrap(),
+            HashSet::from([5, 6, 7])
+        );
+    }
+
+    #[test]
+    fn expands_dense_and_sparse_path_columns() {
+        let dense = serde_json::json!(["a", "", "a::b", ""]);
+        assert_eq!(
+            decode_path_column(Some(&dense), 4),
+            vec!["a", "a", "a::b", "a::b"]
+        );
+        let sparse = serde_json::json!([[0, "a"], [2, "a::b"]]);
+        assert_eq!(
+            decode_path_column(Some(&sparse), 4),
+            vec!["a", "a", "a::b", "a::b"]
+        );
+    }
+}
@@ -3,10 +3,11 @@
 //! This module handles web scraping of pre-built documentation from docs.rs,
 //! replacing the previous approach of building rustdoc JSON locally.
 
-use anyhow::{anyhow, Context, Result};
+use anyhow::{anyhow, Result};
 use reqwest::Client;
 use scraper::{Html, Selector};
 
+use std::collections::HashMap;
 use std::time::Duration;
 use tracing::{debug, info, instrument, warn};
 
@@ -18,7 +19,13 @@ pub struct DocsRsScraper {
     base_url: String,
 }
 
-use crate::doc_engine::types::{SearchIndexData, SearchIndexItem};
+use crate::doc_engine::rustdoc_index;
+use crate::doc_engine::types::SearchIndexData;
+
+/// A page that docs.rs answered with 404
+#[derive(Debug, thiserror::Error)]
+#[error("Documentation not found: {0}")]
+struct NotFound(String);
 
 /// Configuration for the docs.rs scraper
 #[derive(Debug, Clone)]
@@ -111,17 +118,75 @@ impl DocsRsScraper {
         );
         debug!("Fetching search index from: {}", url);
 
-        // Fetch the JavaScript content
-        let js_content = self.fetch_text(&url).await?;
-
-        // Parse the search index data
-        let search_data = self.parse_search_index(&js_content, crate_name, version)?;
+        // Crates documented by rustdoc >= 1.91 have no `search-index.js`;
+        // they ship a sharded `search.index/` directory instead.
+        let decoded = match self.fetch_text(&url).await {
+            Ok(js_content) => rustdoc_index::parse_search_index(&js_content, crate_name, version)?,
+            Err(e) if e.is::<NotFound>() => {
+                debug!("No search-index.js, trying the sharded search index");
+                self.fetch_sharded_search_index(crate_name, version).await?
+            }
+            Err(e) => return Err(e.context("Failed to load search index")),
+        };
 
         info!(
-            "Successfully fetched search index with {} items",
-            search_data.items.len()
+            "Successfully fetched {:?} search index with {} items",
+            decoded.format,
+            decoded.data.items.len()
         );
-        Ok(search_data)
+        Ok(decoded.data)
+    }
+
+    /// Fetch a sharded (`search.index/`) search index: its root, then the
+    /// shards of the columns the decoder reads. The all-items page is used
+    /// only when the crate has no sharded index either.
+    async fn fetch_sharded_search_index(
+        &self,
+        crate_name: &str,
+        version: &str,
+    ) -> Result<rustdoc_index::DecodedSearchIndex> {
+        use futures::stream::{self, StreamExt, TryStreamExt};
+
+        const MAX_CONCURRENT_SHARDS: usize = 4;
+
+        let docs_root = format!("{}/{}/{}", self.base_url, crate_name, version);
+        let crate_page = self
+            .fetch_html(&format!(
+                "{docs_root}/{}/index.html",
+                crate_name.replace('-', "_")
+            ))
+            .await?;
+        // The root file carries the resource suffix rustdoc was run with
+        let suffix = rustdoc_index::resource_suffix(&crate_page).unwrap_or_default();
+        let root = match self
+            .fetch_text(&format!("{docs_root}/search.index/root{suffix}.js"))
+            .await
+        {
+            Ok(root) => rustdoc_index::ShardedIndexRoot::parse(&root)?,
+            Err(e) if e.is::<NotFound>() => {
+                debug!("No sharded search index, decoding the all-items page");
+                let all_items = self
+                    .fetch_html(&format!(
+                        "{docs_root}/{}/all.html",
+                        crate_name.replace('-', "_")
+                    ))
+                    .await?;
+                return rustdoc_index::parse_all_items_page(&all_items, crate_name, version);
+            }
+            Err(e) => return Err(e.context("Failed to load sharded search index")),
+        };
+
+        let docs_root = &docs_root;
+        let shards: HashMap<String, String> = stream::iter(root.shard_files())
+            .map(|file| async move {
+                let url = format!("{docs_root}/search.index/{file}");
+                let contents = self.fetch_text(&url).await?;
+                Ok::<_, anyhow::Error>((file, contents))
+            })
+            .buffer_unordered(MAX_CONCURRENT_SHARDS)
+            .try_collect()
+            .await?;
+        rustdoc_index::decode_sharded_index(&root, &shards, crate_name, version)
     }
 
     /// Check if documentation exists for a crate version
@@ -274,7 +339,7 @@ impl DocsRsScraper {
                             }
                         }
                     } else if response.status().as_u16() == 404 {
-                        return Err(anyhow!("Documentation not found: {}", url));
+                        return Err(NotFound(url.to_string()).into());
                     } else {
                         last_error = Some(anyhow!("HTTP error: {}", response.status()));
                         warn!("HTTP error on attempt {}: {}", attempt, response.status());
@@ -350,231 +415,6 @@ impl DocsRsScraper {
         })
     }
 
-    /// Parse search index JavaScript content
-    fn parse_search_index(
-        &self,
-        js_content: &str,
-        crate_name: &str,
-        version: &str,
-    ) -> Result<SearchIndexData> {
-        // Hardened extraction of docs.rs search-index.js content.
-        //
-        // Historical formats (observed):
-        //  1. var searchIndex = {"crate":{"items":[...],"paths":[...]}, ...};
-        //  2. searchIndex = {"crate":{"i":[...],"p":[...]}, ...};
-        //  3. self.searchIndex = {...};
-        //  4. window.searchIndex = {...};
-        //
-        // Risks:
-        //  - Naïve first/last brace slice can include trailing loader code or miss if
-        //    braces appear in a banner comment.
-        //  - Future minification may rename top variable but internal crate map
-        //    stays JSON-like.
-        //
-        // Strategy:
-        //  1. Attempt targeted regex captures around common assignment patterns.
-        //  2. If unsuccessful, perform a brace-balanced extraction starting at the
-        //     first occurrence of the crate key (crate or crate with '_' instead of '-').
-        //  3. Parse as JSON; locate crate entry under either original or sanitized key.
-        //
-        // NOTE: ETag / backoff integration is handled at the fetch layer; this parser
-        // remains stateless but surfaces granular errors to allow upstream retry/
-        // classification (e.g. distinguishing "structure changed" vs "crate missing").
-        use regex::Regex;
-        use serde_json::Value;
-
-        let crate_key_alt = crate_name.replace('-', "_");
-        let candidate_keys = [crate_name, crate_key_alt.as_str()];
-
-        // Helper: try parsing a JSON candidate string into Value and fetch crate map.
-        let try_parse = |json_str: &str| -> Result<(Value, Value)> {
-            let v: Value =
-                serde_json::from_str(json_str).context("Failed to parse search index JSON")?;
-            for key in candidate_keys {
-                if let Some(entry) = v.get(key) {
-                    return Ok((v.clone(), entry.clone()));
-                }
-            }
-            Err(anyhow!(
-                "Crate data not found in parsed search index object (keys tried: {:?})",
-                candidate_keys
-            ))
-        };
-
-        // 1. Regex-based captures (non-greedy with manual brace balance after initial '{').
-        let regex_patterns = [
-            r#"(?s)searchIndex\s*=\s*(\{.*\});"#,
-            r#"(?s)var\s+searchIndex\s*=\s*(\{.*\});"#,
-            r#"(?s)self\.searchIndex\s*=\s*(\{.*\});"#,
-            r#"(?s)window\.searchIndex\s*=\s*(\{.*\});"#,
-        ];
-
-        for pat in regex_patterns {
-            if let Ok(re) = Regex::new(pat) {
-                if let Some(caps) = re.captures(js_content) {
-                    let blob = caps.get(1).map(|m| m.as_str()).unwrap_or("");
-                    // Perform brace balance to trim trailing over-capture.
-                    if let Some(json_balanced) = Self::balanced_brace_slice(blob) {
-                        if let Ok((json_data, crate_data)) = try_parse(&json_balanced) {
-                            return self.build_search_index(
-                                crate_name,
-                                version,
-                                &crate_data,
-                                &json_data,
-                            );
-                        }
-                    }
-                }
-            }
-        }
-
-        // 2. Fallback: locate crate key and backtrack to opening brace, then balance.
-        let mut fallback_extracted: Option<String> = None;
-        for key in candidate_keys {
-            if let Some(pos) = js_content.find(&format!("\"{key}\"")) {
-                // Backtrack to nearest '{'
-                if let Some(start) = js_content[..pos].rfind('{') {
-                    if let Some(json_balanced) = Self::balanced_brace_slice(&js_content[start..]) {
-                        fallback_extracted = Some(json_balanced);
-                        break;
-                    }
-                }
-            }
-        }
-
-        if let Some(json_blob) = fallback_extracted {
-            if let Ok((json_data, crate_data)) = try_parse(&json_blob) {
-                return self.build_search_index(crate_name, version, &crate_data, &json_data);
-            }
-        }
-
-        Err(anyhow!(
-            "Unable to extract or parse search index for crate '{}'",
-            crate_name
-        ))
-    }
-
-    /// Extract a balanced JSON slice from a string starting at the first '{'.
-    /// Returns None if braces cannot be balanced.
-    fn balanced_brace_slice(input: &str) -> Option<String> {
-        let bytes = input.as_bytes();
-        let mut depth = 0usize;
-        let mut started = false;
-        for (i, &b) in bytes.iter().enumerate() {
-            if b == b'{' {
-                depth += 1;
-                started = true;
-            } else if b == b'}' {
-                if depth == 0 {
-                    return None;
-                }
-                depth -= 1;
-                if depth == 0 {
-                    return Some(String::from_utf8_lossy(&bytes[..=i]).to_string());
-                }
-            }
-        }
-        if started && depth == 0 {
-            Some(String::from_utf8_lossy(bytes).to_string())
-        } else {
-            None
-        }
-    }
-
-    /// Build the strongly typed SearchIndexData from the extracted JSON subtree.
-    fn build_search_index(
-        &self,
-        crate_name: &str,
-        version: &str,
-        crate_data: &serde_json::Value,
-        root_json: &serde_json::Value,
-    ) -> Result<SearchIndexData> {
-        let _ = root_json; // reserved for future structure / schema validation
-        let items_array = crate_data
-            .get("items")
-            .or_else(|| crate_data.get("i"))
-            .and_then(|v| v.as_array())
-            .ok_or_else(|| anyhow!("Items array not found in crate data"))?;
-
-        let mut items = Vec::new();
-        let mut paths = Vec::new();
-
-        for item_value in items_array {
-            if let Some(item_array) = item_value.as_array() {
-                if item_array.len() >= 4 {
-                    let kind = self.kind_id_to_string(item_array[0].as_u64().unwrap_or(0) as usize);
-                    let name = item_array[1].as_str().unwrap_or("").to_string();
-                    let path = item_array[2].as_str().unwrap_or("").to_string();
-                    let description = item_array[3].as_str().unwrap_or("").to_string();
-                    let parent_index = item_array
-                        .get(4)
-                        .and_then(|v| v.as_array())
-                        .and_then(|arr| arr.first())
-                        .and_then(|v| v.as_u64())
-                        .map(|v| v as usize);
-
-                    items.push(SearchIndexItem {
-                        name,
-                        kind,
-                        path,
-                        description,
-                        parent_index,
-                    });
-                }
-            }
-        }
-
-        if let Some(paths_array) = crate_data.get("paths").or_else(|| crate_data.get("p")) {
-            if let Some(paths_arr) = paths_array.as_array() {
-                for path_value in paths_arr {
-                    if let Some(path_str) = path_value.as_str() {
-                        paths.push(path_str.to_string());
-                    }
-                }
-            }
-        }
-
-        Ok(SearchIndexData {
-            crate_name: crate_name.to_string(),
-            version: version.to_string(),
-            items,
-            paths,
-        })
-    }
-
-    /// Convert kind ID to string representation
-    fn kind_id_to_string(&self, kind_id: usize) -> String {
-        match kind_id {
-            0 => "module".to_string(),
-            1 => "extern_crate".to_string(),
-            2 => "import".to_string(),
-            3 => "struct".to_string(),
-            4 => "enum".to_string(),
-            5 => "function".to_string(),
-            6 => "type_def".to_string(),
-            7 => "static".to_string(),
-            8 => "trait".to_string(),
-            9 => "impl".to_string(),
-            10 => "tymethod".to_string(),
-            11 => "method".to_string(),
-            12 => "structfield".to_string(),
-            13 => "variant".to_string(),
-            14 => "macro".to_string(),
-            15 => "primitive".to_string(),
-            16 => "assoc_type".to_string(),
-            17 => "constant".to_string(),
-            18 => "assoc_const".to_string(),
-            19 => "union".to_string(),
-            20 => "foreign_type".to_string(),
-            21 => "keyword".to_string(),
-            22 => "existential".to_string(),
-            23 => "attr".to_string(),
-            24 => "derive".to_string(),
-            25 => "trait_alias".to_string(),
-            _ => format!("unknown_{kind_id}"),
-        }
-    }
-
     /// Extract item kind from HTML document
     fn extract_item_kind(&self, document: &Html, _item_path: &str) -> String {
         // Look for indicators in the HTML structure
@@ -720,16 +560,6 @@ mod tests {
         // Just verify we can create the scraper without panicking
     }
 
-    #[test]
-    fn test_kind_id_conversion() {
-        let scraper = DocsRsScraper::new();
-
-        assert_eq!(scraper.kind_id_to_string(3), "struct");
-        assert_eq!(scraper.kind_id_to_string(5), "function");
-        assert_eq!(scraper.kind_id_to_string(8), "trait");
-        assert_eq!(scraper.kind_id_to_string(999), "unknown_999");
-    }
-
     #[test]
     fn test_parse_source_location() {
         let scraper = DocsRsScraper::new();
@@ -0,0 +1,11 @@
+//! Fixture helpers shared by the unit tests of the doc engine.
+
+use std::fs;
+use std::path::Path;
+
+/// Write `contents` to `root/relative`, creating the parent directories.
+pub(crate) fn write(root: &Path, relative: &str, contents: &str) {
+    let path = root.join(relative);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, contents).unwrap();
+}
@@ -2,7 +2,7 @@
 
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::time::SystemTime;
 
 /// Search result for crates.io search
@@ -54,6 +54,84 @@ pub struct DependencyInfo {
     pub features: Vec<String>,
 }
 
+/// Metadata of an installed Python distribution, read from its `*.dist-info`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PythonPackageInfo {
+    /// Distribution name, as published on PyPI
+    pub name: String,
+    pub version: String,
+    pub summary: Option<String>,
+    pub license: Option<String>,
+    pub requires_python: Option<String>,
+    pub requires_dist: Vec<PythonRequirement>,
+    pub provides_extras: Vec<String>,
+    pub classifiers: Vec<String>,
+    pub home_page: Option<String>,
+    pub project_urls: BTreeMap<String, String>,
+    pub author: Option<String>,
+    pub console_scripts: Vec<PythonEntryPoint>,
+    pub gui_scripts: Vec<PythonEntryPoint>,
+    /// Other entry point groups, such as plugin registrations
+    pub entry_points: BTreeMap<String, Vec<PythonEntryPoint>>,
+    /// Top-level packages and modules the distribution installs, which may
+    /// differ from its name (`Pillow` installs `PIL`)
+    pub import_names: Vec<String>,
+    pub dist_info_path: String,
+}
+
+/// A `Requires-Dist` entry
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PythonRequirement {
+    pub name: String,
+    /// Extras requested from the dependency (`requests[socks]`)
+    pub extras: Vec<String>,
+    /// Version specifier or direct reference (`>=2.0`)
+    pub specifier: Option<String>,
+    /// Environment marker (`python_version < "3.10"`)
+    pub marker: Option<String>,
+    /// Extra of this distribution that pulls the dependency in
+    pub extra: Option<String>,
+}
+
+/// A console script or other entry point (`name = module:function`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PythonEntryPoint {
+    pub name: String,
+    pub target: String,
+}
+
+/// Manifest of an installed Node.js package, read from its `package.json`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NodePackageInfo {
+    pub name: String,
+    pub version: String,
+    pub description: Option<String>,
+    pub license: Option<String>,
+    pub main: Option<String>,
+    pub module: Option<String>,
+    /// `types`, or the older `typings`
+    pub types: Option<String>,
+    /// The `exports` map, as written
+    pub exports: Option<serde_json::Value>,
+    pub dependencies: BTreeMap<String, String>,
+    pub peer_dependencies: BTreeMap<String, String>,
+    /// Peer dependencies marked optional in `peerDependenciesMeta`
+    pub optional_peer_dependencies: Vec<String>,
+    pub engines: BTreeMap<String, String>,
+    /// Commands by name
+    pub bin: BTreeMap<String, String>,
+    pub package_path: String,
+    /// Every installed copy of the package, including nested `node_modules`
+    pub installed_versions: Vec<NodeInstalledCopy>,
+}
+
+/// One installed copy of a Node.js package
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NodeInstalledCopy {
+    pub version: String,
+    pub path: String,
+}
+
 /// Version information
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VersionInfo {
@@ -149,6 +227,74 @@ pub struct SymbolSearchResult {
     pub module_path: String,
 }
 
+/// Which crates a cross-crate symbol search covers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SymbolSearchScope {
+    /// Registry dependencies listed in the project's Cargo.lock
+    Lockfile,
+    /// Every crate with a search index in the local cache
+    Cache,
+}
+
+/// Symbol search hit tagged with the crate it was found in
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CrateSymbolSearchResult {
+    pub crate_name: String,
+    pub crate_version: String,
+    #[serde(flatten)]
+    pub symbol: SymbolSearchResult,
+}
+
+/// Merged result of searching symbols across many crates
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GlobalSymbolSearchResponse {
+    pub scope: SymbolSearchScope,
+    pub results: Vec<CrateSymbolSearchResult>,
+    /// Number of crate indexes that were searched
+    pub crates_searched: usize,
+    /// `name@version` of crates in scope without an available index
+    pub crates_skipped: Vec<String>,
+    pub diagnostics: Vec<String>,
+}
+
+/// A crate or package to pre-index in a batch
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BatchIndexTarget {
+    /// "rust", "python" or "node"
+    pub language: String,
+    pub name: String,
+    /// Exact version (Rust only; defaults to latest)
+    pub version: Option<String>,
+}
+
+/// Outcome of indexing one batch entry
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchIndexEntryResult {
+    pub language: String,
+    pub name: String,
+    pub version: Option<String>,
+    pub success: bool,
+    /// Number of indexed items, when the language builds an index
+    pub item_count: Option<usize>,
+    /// Resolved on-disk location (Python/Node packages)
+    pub location: Option<String>,
+    pub duration_ms: u64,
+    pub error: Option<String>,
+}
+
+/// Per-entry results of a batch index run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchIndexReport {
+    /// Manifest the targets were read from, if any
+    pub source: Option<String>,
+    pub total: usize,
+    pub succeeded: usize,
+    pub failed: usize,
+    pub duration_ms: u64,
+    pub entries: Vec<BatchIndexEntryResult>,
+}
+
 /// Cache entry metadata for tracking cached items
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CacheEntry {
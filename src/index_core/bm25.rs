@@ -0,0 +1,459 @@
+//! BM25F ranking shared by the semantic search indexes.
+//!
+//! Each document is split into name, signature, doc and path fields with
+//! their own boost and length normalization. Queries are expanded offline
+//! with an abbreviation/synonym table and, after a first pass, with
+//! distinctive terms from the best-matching documents (pseudo-relevance
+//! feedback). Document frequencies are maintained incrementally so that
+//! indexes can add and remove documents without rescanning the corpus.
+
+use serde::{Deserialize, Serialize};
+use std::{cmp::Ordering, collections::HashMap};
+
+/// Fields scored separately by BM25F.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Bm25Field {
+    Name = 0,
+    Signature = 1,
+    Doc = 2,
+    Path = 3,
+}
+
+pub const BM25_FIELD_COUNT: usize = 4;
+
+/// Per-field `(boost, b)`: how much a match in the field counts and how
+/// strongly the field's length normalizes term frequency.
+const FIELD_PARAMS: [(f32, f32); BM25_FIELD_COUNT] =
+    [(3.0, 0.4), (1.0, 0.6), (1.5, 0.75), (0.5, 0.3)];
+const K1: f32 = 1.2;
+
+/// Query weight of terms added from the synonym table.
+const SYNONYM_WEIGHT: f32 = 0.5;
+/// Pseudo-relevance feedback: the top documents of a first pass contribute
+/// their most distinctive terms to a second pass.
+const FEEDBACK_DOCS: usize = 3;
+const FEEDBACK_TERMS: usize = 4;
+const FEEDBACK_WEIGHT: f32 = 0.25;
+
+/// Abbreviations and near-synonyms common in library APIs, in stemmed form.
+const SYNONYM_GROUPS: &[&[&str]] = &[
+    &["http", "request", "url"],
+    &["cfg", "config", "conf", "configuration", "setting"],
+    &["del", "delete", "remove", "rm"],
+    &["init", "initialize", "setup"],
+    &[
+        "auth",
+        "authenticate",
+        "authentication",
+        "login",
+        "credential",
+    ],
+    &["db", "database"],
+    &["msg", "message"],
+    &["err", "error", "exception"],
+    &["str", "string"],
+    &["dir", "directory", "folder"],
+    &["env", "environment"],
+    &["arg", "argument", "param", "parameter"],
+    &["num", "number"],
+    &["len", "length", "size"],
+    &["tmp", "temp", "temporary"],
+    &["img", "image"],
+    &["fn", "func", "function", "callable"],
+    &["ctx", "context"],
+    &["addr", "address"],
+    &["repo", "repository"],
+    &["pkg", "package"],
+    &["lib", "library"],
+    &["doc", "docstring", "documentation"],
+    &["max", "maximum"],
+    &["min", "minimum"],
+    &["info", "information", "metadata"],
+    &["calc", "calculate", "compute"],
+    &["conn", "connection", "connect"],
+    &["create", "make", "new"],
+    &["user", "account"],
+    &["serialize", "encode", "dump"],
+    &["deserialize", "decode"],
+];
+
+/// Per-field term frequencies and lengths of one document.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FieldTerms {
+    terms: HashMap<String, [f32; BM25_FIELD_COUNT]>,
+    lengths: [f32; BM25_FIELD_COUNT],
+}
+
+impl FieldTerms {
+    /// Add tokens to a field.
+    pub fn add(&mut self, field: Bm25Field, tokens: Vec<String>) {
+        self.lengths[field as usize] += tokens.len() as f32;
+        for token in tokens {
+            self.terms.entry(token).or_insert([0.0; BM25_FIELD_COUNT])[field as usize] += 1.0;
+        }
+    }
+
+    /// Distinct tokens across all fields.
+    pub fn tokens(&self) -> impl Iterator<Item = &str> {
+        self.terms.keys().map(String::as_str)
+    }
+
+    /// Whether the document has no tokens at all.
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    /// Rough in-memory footprint of the term table.
+    pub fn approximate_size_bytes(&self) -> usize {
+        self.terms
+            .keys()
+            .map(|k| k.len() + std::mem::size_of::<[f32; BM25_FIELD_COUNT]>())
+            .sum()
+    }
+}
+
+/// Corpus statistics: document frequencies, IDF and average field lengths.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Bm25Corpus {
+    document_frequency: HashMap<String, u32>,
+    length_totals: [f32; BM25_FIELD_COUNT],
+    doc_count: usize,
+    idf: HashMap<String, f32>,
+    avg_field_lengths: [f32; BM25_FIELD_COUNT],
+    finalized: bool,
+}
+
+impl Bm25Corpus {
+    /// Build statistics for a set of documents.
+    pub fn from_documents<'a>(docs: impl IntoIterator<Item = &'a FieldTerms>) -> Self {
+        let mut corpus = Self::default();
+        for doc in docs {
+            corpus.add(doc);
+        }
+        corpus.finalize();
+        corpus
+    }
+
+    /// Combine the statistics of several corpora, as if their documents had
+    /// been counted together, so that one ranking can span all of them.
+    pub fn merged<'a>(corpora: impl IntoIterator<Item = &'a Bm25Corpus>) -> Self {
+        let mut merged = Self::default();
+        for corpus in corpora {
+            merged.doc_count += corpus.doc_count;
+            for (total, len) in merged.length_totals.iter_mut().zip(corpus.length_totals) {
+                *total += len;
+            }
+            for (token, df) in &corpus.document_frequency {
+                *merged.document_frequency.entry(token.clone()).or_insert(0) += df;
+            }
+        }
+        merged.finalize();
+        merged
+    }
+
+    /// Count a document. Call [`finalize`](Self::finalize) before scoring.
+    pub fn add(&mut self, doc: &FieldTerms) {
+        self.doc_count += 1;
+        self.finalized = false;
+        for (total, len) in self.length_totals.iter_mut().zip(doc.lengths) {
+            *total += len;
+        }
+        for token in doc.terms.keys() {
+            *self.document_frequency.entry(token.clone()).or_insert(0) += 1;
+        }
+    }
+
+    /// Stop counting a document previously passed to [`add`](Self::add).
+    pub fn remove(&mut self, doc: &FieldTerms) {
+        self.doc_count = self.doc_count.saturating_sub(1);
+        self.finalized = false;
+        for (total, len) in self.length_totals.iter_mut().zip(doc.lengths) {
+            *total -= len;
+        }
+        for token in doc.terms.keys() {
+            if let Some(count) = self.document_frequency.get_mut(token) {
+                *count -= 1;
+                if *count == 0 {
+                    self.document_frequency.remove(token);
+                }
+            }
+        }
+    }
+
+    /// Recompute IDF weights and average field lengths from the counts.
+    pub fn finalize(&mut self) {
+        let doc_count = self.doc_count as f32;
+        self.idf = self
+            .document_frequency
+            .iter()
+            .map(|(token, df)| {
+                let df = *df as f32;
+                let weight = (1.0 + (doc_count - df + 0.5) / (df + 0.5)).ln();
+                (token.clone(), weight)
+            })
+            .collect();
+        self.avg_field_lengths = self
+            .length_totals
+            .map(|total| (total / doc_count.max(1.0)).max(1.0));
+        self.finalized = true;
+    }
+
+    /// Number of documents counted.
+    pub fn len(&self) -> usize {
+        self.doc_count
+    }
+
+    /// Whether no documents are counted.
+    pub fn is_empty(&self) -> bool {
+        self.doc_count == 0
+    }
+
+    /// Whether IDF weights reflect every document added or removed.
+    pub fn is_finalized(&self) -> bool {
+        self.finalized
+    }
+
+    /// Rough in-memory footprint of the IDF table.
+    pub fn approximate_size_bytes(&self) -> usize {
+        self.idf
+            .keys()
+            .map(|k| k.len() + 2 * std::mem::size_of::<f32>())
+            .sum()
+    }
+
+    /// BM25F score of a document for weighted query terms.
+    pub fn score(&self, doc: &FieldTerms, query_terms: &HashMap<String, f32>) -> f32 {
+        let mut score = 0.0;
+        for (token, query_weight) in query_terms {
+            let Some(freqs) = doc.terms.get(token) else {
+                continue;
+            };
+            let tf: f32 = (0..BM25_FIELD_COUNT)
+                .map(|field| {
+                    let (boost, b) = FIELD_PARAMS[field];
+                    let length_ratio = doc.lengths[field] / self.avg_field_lengths[field];
+                    boost * freqs[field] / (1.0 - b + b * length_ratio)
+                })
+                .sum();
+            let idf = self.idf.get(token).copied().unwrap_or(0.0);
+            score += query_weight * idf * tf / (K1 + tf);
+        }
+        score
+    }
+
+    /// Rank `items` for a natural-language query.
+    ///
+    /// Runs a first pass with the synonym-expanded query and a second pass
+    /// including feedback terms from the top hits. Only items with a positive
+    /// score are returned, best first; `tie_break` orders equal scores.
+    pub fn rank<'a, T>(
+        &self,
+        query: &str,
+        items: impl Iterator<Item = &'a T> + Clone,
+        terms: impl Fn(&T) -> &FieldTerms,
+        tie_break: impl Fn(&T, &T) -> Ordering,
+    ) -> Vec<(f32, &'a T)> {
+        let run = |query_terms: &HashMap<String, f32>| {
+            let mut scored: Vec<(f32, &'a T)> = items
+                .clone()
+                .map(|item| (self.score(terms(item), query_terms), item))
+                .filter(|(score, _)| *score > 0.0)
+                .collect();
+            scored.sort_by(|(a_score, a), (b_score, b)| {
+                b_score
+                    .partial_cmp(a_score)
+                    .unwrap_or(Ordering::Equal)
+                    .then_with(|| tie_break(a, b))
+            });
+            scored
+        };
+
+        let mut query_terms = query_terms(query);
+        let scored = run(&query_terms);
+        let feedback = self.feedback_terms(
+            scored
+                .iter()
+                .take(FEEDBACK_DOCS)
+                .map(|(_, item)| terms(item)),
+            &query_terms,
+        );
+        if feedback.is_empty() {
+            return scored;
+        }
+        query_terms.extend(feedback);
+        run(&query_terms)
+    }
+
+    /// Name and doc terms shared by the top-ranked documents and at least
+    /// one other document, weighted by how distinctive they are.
+    fn feedback_terms<'a>(
+        &self,
+        top: impl Iterator<Item = &'a FieldTerms>,
+        query_terms: &HashMap<String, f32>,
+    ) -> Vec<(String, f32)> {
+        let mut candidates: HashMap<&str, f32> = HashMap::new();
+        for doc in top {
+            for (token, freqs) in &doc.terms {
+                let tf = freqs[Bm25Field::Name as usize] + freqs[Bm25Field::Doc as usize];
+                let shared = self.document_frequency.get(token).is_some_and(|df| *df > 1);
+                if tf == 0.0 || !shared || query_terms.contains_key(token) {
+                    continue;
+                }
+                let idf = self.idf.get(token).copied().unwrap_or(0.0);
+                *candidates.entry(token.as_str()).or_insert(0.0) += tf * idf;
+            }
+        }
+
+        let mut candidates: Vec<(&str, f32)> = candidates.into_iter().collect();
+        candidates.sort_by(|(a_token, a), (b_token, b)| {
+            b.partial_cmp(a)
+                .unwrap_or(Ordering::Equal)
+                .then_with(|| a_token.cmp(b_token))
+        });
+        candidates
+            .into_iter()
+            .take(FEEDBACK_TERMS)
+            .map(|(token, _)| (token.to_string(), FEEDBACK_WEIGHT))
+            .collect()
+    }
+}
+
+/// Weighted query terms: identifier-aware tokens of the query plus their
+/// synonyms at a reduced weight.
+pub fn query_terms(query: &str) -> HashMap<String, f32> {
+    let mut terms = HashMap::new();
+    for word in query.split(|c: char| !(c.is_alphanumeric() || c == '_')) {
+        for token in identifier_tokens(word) {
+            terms.insert(token, 1.0);
+        }
+    }
+
+    let expansions: Vec<&str> = terms
+        .keys()
+        .flat_map(|token| synonyms(token))
+        .copied()
+        .collect();
+    for synonym in expansions {
+        terms.entry(synonym.to_string()).or_insert(SYNONYM_WEIGHT);
+    }
+    terms
+}
+
+/// Tokens of an identifier or path: the whole lowercased identifier plus
+/// its snake_case/camelCase/dotted parts when there is more than one.
+pub fn identifier_tokens(ident: &str) -> Vec<String> {
+    if ident.is_empty() {
+        return Vec::new();
+    }
+    let mut tokens = vec![stem(&ident.to_lowercase())];
+    let parts = split_identifier(ident);
+    if parts.len() > 1 {
+        tokens.extend(parts.iter().map(|part| stem(part)));
+    }
+    tokens.retain(|token| !is_stop_word(token));
+    tokens
+}
+
+/// Stemmed, stop-word-free tokens of free text such as doc comments.
+pub fn text_tokens(text: &str) -> Vec<String> {
+    split_freeform(text)
+        .iter()
+        .map(|token| stem(token))
+        .filter(|token| !is_stop_word(token))
+        .collect()
+}
+
+fn synonyms(token: &str) -> &'static [&'static str] {
+    SYNONYM_GROUPS
+        .iter()
+        .find(|group| group.contains(&token))
+        .copied()
+        .unwrap_or(&[])
+}
+
+/// Light plural stemming so that `tokens` matches `token` and `retries`
+/// matches `retry`.
+fn stem(token: &str) -> String {
+    if token.len() <= 3 || !token.is_ascii() {
+        return token.to_string();
+    }
+    if let Some(base) = token.strip_suffix("ies") {
+        return format!("{base}y");
+    }
+    if token.ends_with("ss") || token.ends_with("us") || token.ends_with("is") {
+        return token.to_string();
+    }
+    token.strip_suffix('s').unwrap_or(token).to_string()
+}
+
+fn split_identifier(ident: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    for ch in ident.chars() {
+        if ch == '_' || ch == '.' || ch == ':' {
+            if !current.is_empty() {
+                tokens.push(current.to_lowercase());
+                current.clear();
+            }
+            continue;
+        }
+        if ch.is_uppercase() && !current.is_empty() {
+            tokens.push(current.to_lowercase());
+            current.clear();
+        }
+        current.push(ch);
+    }
+    if !current.is_empty() {
+        tokens.push(current.to_lowercase());
+    }
+    tokens
+}
+
+fn split_freeform(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|token| !token.is_empty())
+        .map(|token| token.to_lowercase())
+        .collect()
+}
+
+fn is_stop_word(token: &str) -> bool {
+    matches!(
+        token,
+        "the"
+            | "and"
+            | "or"
+            | "for"
+            | "with"
+            | "of"
+            | "a"
+            | "an"
+            | "to"
+            | "in"
+            | "is"
+            | "are"
+            | "on"
+            | "by"
+            | "be"
+            | "this"
+            | "that"
+            | "it"
+            | "from"
+            | "into"
+            | "as"
+            | "at"
+            | "self"
+            | "cls"
+            | "returns"
+            | "return"
+            | "args"
+            | "kwargs"
+            | "true"
+            | "false"
+            | "none"
+            | "def"
+            | "pub"
+            | "mut"
+            | "dyn"
+            | "where"
+    )
+}
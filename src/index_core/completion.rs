@@ -0,0 +1,345 @@
+//! Prefix completion over fully-qualified item paths.
+//!
+//! A [`CompletionIndex`] is a character trie keyed on lower-cased paths
+//! (`tokio::sync::Mutex`, `requests.adapters.HTTPAdapter`). Lookups walk the
+//! trie to the node for the typed prefix and rank every path underneath it by
+//! item kind, a popularity signal (how many indexed items live beneath the
+//! path) and how many segments the completion adds beyond the prefix.
+
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+use crate::index_core::traits::SearchIndexData;
+
+/// A single ranked completion.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompletionCandidate {
+    /// Fully-qualified path offered as completion
+    pub value: String,
+    /// Item kind (e.g. "struct", "module", "class")
+    pub kind: String,
+    /// Ranking score (higher is better)
+    pub score: f32,
+}
+
+#[derive(Debug, Clone)]
+struct CompletionEntry {
+    value: String,
+    kind: String,
+    depth: usize,
+    weight: f32,
+}
+
+#[derive(Debug, Clone, Default)]
+struct TrieNode {
+    children: BTreeMap<char, usize>,
+    entry: Option<usize>,
+}
+
+/// Trie-backed prefix completion index.
+#[derive(Debug, Clone)]
+pub struct CompletionIndex {
+    nodes: Vec<TrieNode>,
+    entries: Vec<CompletionEntry>,
+}
+
+impl Default for CompletionIndex {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CompletionIndex {
+    /// Create an empty completion index.
+    pub fn new() -> Self {
+        Self {
+            nodes: vec![TrieNode::default()],
+            entries: Vec::new(),
+        }
+    }
+
+    /// Build an index from `(path, kind)` pairs.
+    ///
+    /// Duplicate paths keep the highest-weighted kind. Popularity is derived
+    /// from the number of other paths nested beneath each path, so modules and
+    /// types with many members outrank leaf items of the same kind.
+    pub fn from_entries<I>(entries: I) -> Self
+    where
+        I: IntoIterator<Item = (String, String)>,
+    {
+        let mut kinds: HashMap<String, String> = HashMap::new();
+        for (value, kind) in entries {
+            if value.is_empty() {
+                continue;
+            }
+            match kinds.get(&value) {
+                Some(existing) if kind_weight(existing) >= kind_weight(&kind) => {}
+                _ => {
+                    kinds.insert(value, kind);
+                }
+            }
+        }
+
+        let mut descendants: HashMap<&str, usize> = HashMap::new();
+        for value in kinds.keys() {
+            for ancestor in ancestors(value) {
+                *descendants.entry(ancestor).or_insert(0) += 1;
+            }
+        }
+
+        let mut sorted: Vec<(&String, &String)> = kinds.iter().collect();
+        sorted.sort();
+
+        let mut index = Self::new();
+        for (value, kind) in sorted {
+            let popularity = descendants.get(value.as_str()).copied().unwrap_or(0);
+            let weight = kind_weight(kind) * (1.0 + 0.25 * (1.0 + popularity as f32).ln());
+            index.insert(CompletionEntry {
+                value: value.clone(),
+                kind: kind.clone(),
+                depth: path_depth(value),
+                weight,
+            });
+        }
+        index
+    }
+
+    /// Build an index from a rustdoc search index.
+    ///
+    /// Each item contributes `module::Name` (or `module::Parent::name` for
+    /// associated items); entries of `paths` are added as modules/types.
+    pub fn from_search_index(data: &SearchIndexData) -> Self {
+        let mut entries = Vec::with_capacity(data.items.len() + data.paths.len());
+        entries.push((data.crate_name.clone(), "module".to_string()));
+
+        for path in &data.paths {
+            if path.is_empty() {
+                continue;
+            }
+            let qualified = path == &data.crate_name
+                || path
+                    .strip_prefix(&data.crate_name)
+                    .is_some_and(|rest| rest.starts_with("::"));
+            let full = if qualified {
+                path.clone()
+            } else {
+                format!("{}::{}", data.crate_name, path)
+            };
+            entries.push((full, "module".to_string()));
+        }
+
+        for item in &data.items {
+            if item.name.is_empty() || item.kind == "impl" {
+                continue;
+            }
+            let mut full = if item.path.is_empty() {
+                data.crate_name.clone()
+            } else {
+                item.path.clone()
+            };
+            if let Some(parent) = item.parent_index.and_then(|idx| data.paths.get(idx)) {
+                let parent_name = parent.rsplit("::").next().unwrap_or(parent);
+                if !parent_name.is_empty() && !full.ends_with(&format!("::{parent_name}")) {
+                    full.push_str("::");
+                    full.push_str(parent_name);
+                }
+            }
+            full.push_str("::");
+            full.push_str(&item.name);
+            entries.push((full, item.kind.clone()));
+        }
+
+        Self::from_entries(entries)
+    }
+
+    /// Number of completable paths.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the index holds no paths.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Return up to `limit` completions for `prefix`, best first.
+    ///
+    /// Matching is case-insensitive. Completions that add more path segments
+    /// than the prefix are down-weighted so the next segment is offered before
+    /// deeply nested members.
+    pub fn complete(&self, prefix: &str, limit: usize) -> Vec<CompletionCandidate> {
+        if limit == 0 {
+            return Vec::new();
+        }
+        let prefix = prefix.trim();
+        let Some(start) = self.find_node(prefix) else {
+            return Vec::new();
+        };
+
+        let prefix_depth = path_depth(prefix);
+        let mut candidates = Vec::new();
+        let mut stack = vec![start];
+        while let Some(node_idx) = stack.pop() {
+            let node = &self.nodes[node_idx];
+            if let Some(entry_idx) = node.entry {
+                let entry = &self.entries[entry_idx];
+                let extra_depth = entry.depth.saturating_sub(prefix_depth);
+                let score = entry.weight * 0.5_f32.powi(extra_depth as i32);
+                candidates.push(CompletionCandidate {
+                    value: entry.value.clone(),
+                    kind: entry.kind.clone(),
+                    score,
+                });
+            }
+            stack.extend(node.children.values().copied());
+        }
+
+        candidates.sort_by(|a, b| {
+            b.score
+                .partial_cmp(&a.score)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| a.value.len().cmp(&b.value.len()))
+                .then_with(|| a.value.cmp(&b.value))
+        });
+        candidates.truncate(limit);
+        candidates
+    }
+
+    fn find_node(&self, prefix: &str) -> Option<usize> {
+        let mut current = 0;
+        for ch in prefix.chars().flat_map(char::to_lowercase) {
+            current = *self.nodes[current].children.get(&ch)?;
+        }
+        Some(current)
+    }
+
+    fn insert(&mut self, entry: CompletionEntry) {
+        let mut current = 0;
+        for ch in entry.value.chars().flat_map(char::to_lowercase) {
+            current = match self.nodes[current].children.get(&ch) {
+                Some(&next) => next,
+                None => {
+                    let next = self.nodes.len();
+                    self.nodes.push(TrieNode::default());
+                    self.nodes[current].children.insert(ch, next);
+                    next
+                }
+            };
+        }
+        // Paths differing only in case share a node; keep the stronger one.
+        match self.nodes[current].entry {
+            Some(existing) if self.entries[existing].weight >= entry.weight => {}
+            Some(existing) => self.entries[existing] = entry,
+            None => {
+                self.nodes[current].entry = Some(self.entries.len());
+                self.entries.push(entry);
+            }
+        }
+    }
+}
+
+/// Relative preference for each item kind when ranking completions.
+fn kind_weight(kind: &str) -> f32 {
+    match kind {
+        "struct" | "enum" | "trait" | "union" | "class" => 1.0,
+        "function" | "macro" | "derive" | "attr" => 0.9,
+        "module" => 0.85,
+        "type_def" | "trait_alias" | "primitive" => 0.8,
+        "method" | "tymethod" => 0.6,
+        "constant" | "static" | "assoc_const" | "assoc_type" => 0.55,
+        "variant" | "structfield" => 0.45,
+        _ => 0.3,
+    }
+}
+
+/// Number of separators (`::` for Rust, `.` for Python) in a path.
+fn path_depth(path: &str) -> usize {
+    path.matches("::").count() + path.matches('.').count()
+}
+
+/// Every proper ancestor of a path, e.g. `a::b` and `a` for `a::b::c`.
+fn ancestors(path: &str) -> impl Iterator<Item = &str> {
+    path.match_indices("::")
+        .chain(path.match_indices('.'))
+        .map(move |(idx, _)| &path[..idx])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::index_core::traits::SearchIndexItem;
+
+    fn item(name: &str, kind: &str, path: &str) -> SearchIndexItem {
+        SearchIndexItem {
+            name: name.to_string(),
+            kind: kind.to_string(),
+            path: path.to_string(),
+            description: String::new(),
+            parent_index: None,
+        }
+    }
+
+    #[test]
+    fn completes_rust_paths_by_kind_and_depth() {
+        let data = SearchIndexData {
+            crate_name: "tokio".to_string(),
+            version: "1.0.0".to_string(),
+            items: vec![
+                item("sync", "module", "tokio"),
+                item("Mutex", "struct", "tokio::sync"),
+                item("MutexGuard", "struct", "tokio::sync"),
+                item("mutex_helper", "function", "tokio::sync"),
+                item("lock", "method", "tokio::sync::Mutex"),
+                item("try_lock", "method", "tokio::sync::Mutex"),
+            ],
+            paths: vec![
+                "tokio::sync::Mutex".to_string(),
+                "tokio_util::codec".to_string(),
+            ],
+        };
+        let index = CompletionIndex::from_search_index(&data);
+        assert_eq!(
+            index.complete("tokio::tokio_util::co", 5)[0].value,
+            "tokio::tokio_util::codec"
+        );
+        assert!(index.complete("tokio_util", 5).is_empty());
+
+        let results = index.complete("tokio::sync::Mu", 10);
+        let values: Vec<&str> = results.iter().map(|c| c.value.as_str()).collect();
+        assert_eq!(values[0], "tokio::sync::Mutex");
+        assert_eq!(values[1], "tokio::sync::MutexGuard");
+        assert!(values.contains(&"tokio::sync::mutex_helper"));
+        let lock_pos = values
+            .iter()
+            .position(|v| *v == "tokio::sync::Mutex::lock")
+            .unwrap();
+        assert!(
+            lock_pos
+                > values
+                    .iter()
+                    .position(|v| v.ends_with("mutex_helper"))
+                    .unwrap()
+        );
+    }
+
+    #[test]
+    fn completes_dotted_paths_and_respects_limit() {
+        let index = CompletionIndex::from_entries(vec![
+            ("requests".to_string(), "module".to_string()),
+            ("requests.adapters".to_string(), "module".to_string()),
+            (
+                "requests.adapters.HTTPAdapter".to_string(),
+                "class".to_string(),
+            ),
+            ("requests.api".to_string(), "module".to_string()),
+        ]);
+
+        let results = index.complete("requests.ad", 5);
+        assert_eq!(results[0].value, "requests.adapters");
+        assert_eq!(results[0].kind, "module");
+        assert_eq!(results.len(), 2);
+
+        assert_eq!(index.complete("requests.", 1).len(), 1);
+        assert!(index.complete("flask", 5).is_empty());
+    }
+}
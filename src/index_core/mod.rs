@@ -7,10 +7,14 @@ use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use std::path::Path;
 
+pub mod bm25;
+pub mod completion;
 pub mod search;
 pub mod traits;
 pub mod types;
 
+pub use bm25::*;
+pub use completion::*;
 pub use search::*;
 pub use traits::*;
 pub use types::*;
@@ -2,14 +2,14 @@
 
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
 use tracing::debug;
 
+use crate::index_core::completion::CompletionIndex;
 use crate::index_core::types::*;
 
 /// Search engine for documentation items
 pub struct SearchEngine {
-    _placeholder: (),
+    completions: CompletionIndex,
 }
 
 /// Search configuration options
@@ -99,7 +99,15 @@ impl Default for SearchFilters {
 impl SearchEngine {
     /// Create a new search engine
     pub fn new() -> Result<Self> {
-        Ok(Self { _placeholder: () })
+        Ok(Self {
+            completions: CompletionIndex::new(),
+        })
+    }
+
+    /// Attach a completion index used by [`SearchEngine::get_suggestions`].
+    pub fn with_completions(mut self, completions: CompletionIndex) -> Self {
+        self.completions = completions;
+        self
     }
 
     /// Perform a comprehensive search
@@ -160,22 +168,20 @@ impl SearchEngine {
         Ok(results)
     }
 
-    /// Get search suggestions for autocomplete
+    /// Get search suggestions for autocomplete, ranked by the completion index
     pub fn get_suggestions(&self, prefix: &str, limit: usize) -> Result<Vec<String>> {
         debug!("Getting suggestions for prefix: {}", prefix);
 
-        let mut suggestions = HashSet::new();
-        if !prefix.is_empty() && limit > 0 {
-            suggestions.insert(format!("{prefix}Function"));
-            suggestions.insert(format!("{prefix}Struct"));
-            suggestions.insert(format!("{prefix}Trait"));
+        if prefix.is_empty() {
+            return Ok(Vec::new());
         }
 
-        let mut results: Vec<String> = suggestions.into_iter().collect();
-        results.sort();
-        results.truncate(limit);
-
-        Ok(results)
+        Ok(self
+            .completions
+            .complete(prefix, limit)
+            .into_iter()
+            .map(|candidate| candidate.value)
+            .collect())
     }
 
     /// Search for exact matches
@@ -4,9 +4,10 @@
 //! including parameter types and the main server implementation.
 
 pub use crate::server::{
-    CrateInfoParams, GetImplementationParams, GetItemDocParams, ListImplsForTypeParams,
-    ListTraitImplsParams, RustDocsMcpServer, SearchCratesParams, SearchSymbolsParams,
-    SemanticSearchParams, SourceSnippetParams, ToolConfig,
+    CompletePathParams, CrateInfoParams, GetImplementationParams, GetItemDocParams,
+    IndexHealthParams, ListImplsForTypeParams, ListTraitImplsParams, RustDocsMcpServer,
+    SearchCratesParams, SearchSymbolsGlobalParams, SearchSymbolsParams, SemanticSearchParams,
+    SourceSnippetParams, ToolConfig,
 };
 
 // Re-export commonly used dependencies for tests
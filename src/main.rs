@@ -234,6 +234,32 @@ enum Commands {
         version: Option<String>,
     },
 
+    /// Search symbols across all dependencies in Cargo.lock or the cache
+    SearchSymbolsGlobal {
+        /// Search query
+        query: String,
+
+        /// Filter by symbol kinds (comma-separated: struct,fn,trait,etc)
+        #[arg(long)]
+        kinds: Option<String>,
+
+        /// Maximum number of results
+        #[arg(long, short, default_value = "20")]
+        limit: u32,
+
+        /// Crates to search: lockfile or cache (default: lockfile if found)
+        #[arg(long)]
+        scope: Option<String>,
+
+        /// Context path (project directory containing Cargo.lock)
+        #[arg(long)]
+        context: Option<String>,
+
+        /// Fetch indexes for crates that are not cached yet
+        #[arg(long)]
+        fetch_missing: bool,
+    },
+
     // ===== Python/Node.js Tools =====
     /// Get implementation from installed package
     GetImplementation {
@@ -244,7 +270,7 @@ enum Commands {
         /// Package name
         package: String,
 
-        /// Item path (format: "path/to/file#ItemName")
+        /// Item path ("path/to/file#ItemName"; a dotted path like "requests.Session" for Python, or an import subpath like "sub#ItemName" for Node.js)
         path: String,
 
         /// Context path (project directory)
@@ -252,29 +278,68 @@ enum Commands {
         context: Option<String>,
     },
 
-    /// List all methods of a class (Python)
+    /// Get metadata of an installed package (Python dist-info or Node.js package.json)
+    PackageInfo {
+        /// Language (python or node)
+        #[arg(long, short, default_value = "python")]
+        language: String,
+
+        /// Package name; for Python, the distribution ("Pillow") or import name ("PIL")
+        package: String,
+
+        /// Context path (project directory)
+        #[arg(long)]
+        context: Option<String>,
+    },
+
+    /// List all methods of a class (Python or JavaScript/TypeScript)
     ListClassMethods {
+        /// Language (python or node)
+        #[arg(long, short, default_value = "python")]
+        language: String,
+
         /// Package name
         package: String,
 
-        /// Item path (format: "path/to/file#ClassName")
+        /// Item path ("path/to/file#ClassName" or a dotted path like "requests.Session")
         path: String,
 
-        /// Include private methods (starting with _)
+        /// Include private methods (starting with _; for JS/TS also #name and private)
         #[arg(long)]
         private: bool,
 
+        /// Include methods inherited from base classes (Python)
+        #[arg(long)]
+        inherited: bool,
+
         /// Context path (project directory)
         #[arg(long, default_value = ".")]
         context: String,
     },
 
-    /// Get a specific method from a class (Python)
+    /// Describe a class: fields, attributes, methods and bases (Python)
+    GetClassInfo {
+        /// Package name
+        package: String,
+
+        /// Item path ("path/to/file#ClassName" or a dotted path like "requests.Session")
+        path: String,
+
+        /// Context path (project directory)
+        #[arg(long, default_value = ".")]
+        context: String,
+    },
+
+    /// Get a specific method from a class (Python or JavaScript/TypeScript)
     GetClassMethod {
+        /// Language (python or node)
+        #[arg(long, short, default_value = "python")]
+        language: String,
+
         /// Package name
         package: String,
 
-        /// Item path (format: "path/to/file#ClassName")
+        /// Item path ("path/to/file#ClassName" or a dotted path like "requests.Session")
         path: String,
 
         /// Method name
@@ -285,8 +350,12 @@ enum Commands {
         context: String,
     },
 
-    /// Search for code patterns across a Python package
+    /// Search for code patterns across a Python or Node.js package
     SearchPackageCode {
+        /// Language (python or node)
+        #[arg(long, default_value = "python")]
+        language: String,
+
         /// Package name
         package: String,
 
@@ -306,14 +375,19 @@ enum Commands {
         context: String,
     },
 
-    /// Semantic search within a Python package
+    /// Semantic search within a Python package, Rust crate or Node.js package
     SemanticSearch {
-        /// Package name
+        /// Package or crate name; for Python, a comma-separated list or "*"
+        /// (every package in the virtual environment) searches several at once
         package: String,
 
         /// Natural language query
         query: String,
 
+        /// Language (python, rust or node)
+        #[arg(long, default_value = "python")]
+        language: String,
+
         /// Maximum number of results
         #[arg(long, short, default_value = "10")]
         limit: u32,
@@ -323,7 +397,52 @@ enum Commands {
         context: String,
     },
 
+    /// Complete a partially typed item path (Rust or Python)
+    CompletePath {
+        /// Language (rust or python)
+        #[arg(long, short)]
+        language: String,
+
+        /// Partial path (e.g. "tokio::sync::Mu" or "requests.ad")
+        prefix: String,
+
+        /// Crate or package name (defaults to the first path segment)
+        #[arg(long)]
+        package: Option<String>,
+
+        /// Crate version (Rust only)
+        #[arg(long)]
+        version: Option<String>,
+
+        /// Maximum number of completions
+        #[arg(long, default_value = "20")]
+        limit: u32,
+
+        /// Context path (project directory)
+        #[arg(long)]
+        context: Option<String>,
+    },
+
     // ===== Cache Management =====
+    /// Pre-index all dependencies from Cargo.lock, requirements.txt or package.json
+    Index {
+        /// Manifest to read dependencies from
+        #[arg(long)]
+        from: PathBuf,
+
+        /// Maximum number of packages indexed concurrently
+        #[arg(long, short, default_value = "4")]
+        jobs: usize,
+
+        /// Context path for resolving Python/Node packages (defaults to the manifest directory)
+        #[arg(long)]
+        context: Option<String>,
+
+        /// Exit with an error if any entry failed to index
+        #[arg(long)]
+        strict: bool,
+    },
+
     /// Get cache statistics
     CacheStats,
 
@@ -3,12 +3,15 @@
 //! A Model Context Protocol server that provides comprehensive access to Rust crate documentation,
 //! trait implementations, and source code exploration.
 
-use crate::doc_engine::DocEngine;
+use crate::doc_engine::{health, types::SymbolSearchScope, DocEngine};
 use anyhow::Result;
 use rmcp::{
     handler::server::router::tool::ToolRouter,
     handler::server::wrapper::Parameters,
-    model::{CallToolResult, ErrorData, Implementation, ServerCapabilities, ServerInfo},
+    model::{
+        CallToolResult, CompleteRequestParams, CompleteResult, CompletionInfo, ErrorData,
+        Implementation, ProgressNotificationParam, ServerCapabilities, ServerInfo,
+    },
     service::RequestContext,
     tool, tool_router, RoleServer, ServerHandler,
 };
@@ -149,6 +152,16 @@ pub struct CrateInfoParams {
     pub name: String,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct PackageInfoParams {
+    /// The language of the package ("python" or "node")
+    pub language: String,
+    /// Package name; for Python, the distribution name ("Pillow") or import name ("PIL")
+    pub package_name: String,
+    /// Optional project context path
+    pub context_path: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GetItemDocParams {
     pub crate_name: String,
@@ -185,12 +198,16 @@ pub struct GetImplementationParams {
     /// The name of the package as known to its package manager (e.g., "curly", "express").
     pub package_name: String,
     /// Path to the item RELATIVE TO PACKAGE ROOT, format: "path/to/file#item_name".
+    /// Python items may instead be given as a dotted import path. For Node.js,
+    /// the path may also be an import subpath ("sub#item" for `pkg/sub`, "#item"
+    /// for the package entry), resolved through package.json `exports`/`main`.
     ///
-    /// IMPORTANT: Do NOT include the package name as a prefix.
+    /// IMPORTANT: Do NOT include the package name as a prefix of file paths.
     ///
     /// Examples (assuming package "requests"):
     /// - ✅ CORRECT: "sessions.py#Session"
     /// - ✅ CORRECT: "api.py#get"
+    /// - ✅ CORRECT: "requests.adapters.HTTPAdapter.send" (Python dotted path)
     /// - ❌ WRONG: "requests/sessions.py#Session"
     pub item_path: String,
     /// Optional path to a project/environment to search within (especially for Node.js). Defaults to current dir.
@@ -206,12 +223,34 @@ pub struct SearchSymbolsParams {
     pub version: Option<String>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SearchSymbolsGlobalParams {
+    /// Symbol name or fragment to search for
+    pub query: String,
+    /// Optional item kinds to keep (e.g. "function", "struct")
+    pub kinds: Option<Vec<String>>,
+    /// Optional maximum number of merged results (defaults to 20, max 100)
+    pub limit: Option<u32>,
+    /// "lockfile" (Cargo.lock dependencies) or "cache" (all cached crates).
+    /// Defaults to "lockfile" when a Cargo.lock is found, else "cache".
+    pub scope: Option<String>,
+    /// Optional project root used to locate Cargo.lock
+    pub context_path: Option<String>,
+    /// Fetch missing crate indexes from docs.rs (defaults to false)
+    pub fetch_missing: Option<bool>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct SemanticSearchParams {
-    /// Language to search (currently supports "python")
+    /// Language to search: "python", "rust" or "node"
     pub language: String,
-    /// Package or module name to search within
+    /// Package, module or crate name to search within. For Python, "*"
+    /// searches every package installed in the context's virtual environment.
+    #[serde(default)]
     pub package_name: String,
+    /// Additional Python packages searched together with `package_name`,
+    /// returning one ranking whose results are tagged by package
+    pub packages: Option<Vec<String>>,
     /// Natural-language query describing the desired functionality
     pub query: String,
     /// Optional maximum number of results (defaults to 10, max 50)
@@ -220,43 +259,86 @@ pub struct SemanticSearchParams {
     pub context_path: Option<String>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct CompletePathParams {
+    /// Language of the path: "rust" (`::`-separated) or "python" (`.`-separated)
+    pub language: String,
+    /// Partially typed path, e.g. "tokio::sync::Mu" or "requests.ad"
+    pub prefix: String,
+    /// Optional crate/package name (defaults to the first path segment)
+    pub package_name: Option<String>,
+    /// Optional crate version (Rust only; defaults to latest)
+    pub version: Option<String>,
+    /// Optional maximum number of completions (defaults to 20, max 100)
+    pub limit: Option<u32>,
+    /// Optional project root to prefer when resolving local packages (Python only)
+    pub context_path: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ListClassMethodsParams {
-    /// The language of the package (currently only "python" is supported)
+    /// The language of the package ("python" or "node")
     pub language: String,
     /// The name of the package
     pub package_name: String,
-    /// Path to the class RELATIVE TO PACKAGE ROOT, format: "path/to/file#ClassName"
+    /// Path to the class RELATIVE TO PACKAGE ROOT, format: "path/to/file#ClassName",
+    /// or its dotted import path (Python)
     ///
-    /// IMPORTANT: Do NOT include the package name as a prefix.
+    /// IMPORTANT: Do NOT include the package name as a prefix of file paths.
     ///
     /// Examples (assuming package "requests"):
     /// - ✅ CORRECT: "sessions.py#Session"
+    /// - ✅ CORRECT: "requests.Session"
     /// - ✅ CORRECT: "adapters.py#HTTPAdapter"
+    /// - ✅ CORRECT: "lib/client.js#Client" (Node.js)
     /// - ❌ WRONG: "requests/sessions.py#Session"
     /// - ❌ WRONG: "requests/adapters.py#HTTPAdapter"
     pub item_path: String,
-    /// Include private methods (starting with _)
+    /// Include private methods (starting with _; for JS/TS also `#name` and `private`)
     pub include_private: Option<bool>,
-    /// Include inherited methods
+    /// Include methods inherited from base classes, in MRO order, each
+    /// tagged with its defining class (Python only)
     pub include_inherited: Option<bool>,
     /// Optional project context path
     pub context_path: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
-pub struct GetClassMethodParams {
+pub struct GetClassInfoParams {
     /// The language of the package (currently only "python" is supported)
     pub language: String,
     /// The name of the package
     pub package_name: String,
-    /// Path to the class RELATIVE TO PACKAGE ROOT, format: "path/to/file#ClassName"
+    /// Path to the class RELATIVE TO PACKAGE ROOT, format: "path/to/file#ClassName",
+    /// or its dotted import path
+    ///
+    /// IMPORTANT: Do NOT include the package name as a prefix of file paths.
+    ///
+    /// Examples (assuming package "requests"):
+    /// - ✅ CORRECT: "sessions.py#Session"
+    /// - ✅ CORRECT: "requests.Session"
+    /// - ❌ WRONG: "requests/sessions.py#Session"
+    pub item_path: String,
+    /// Optional project context path
+    pub context_path: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GetClassMethodParams {
+    /// The language of the package ("python" or "node")
+    pub language: String,
+    /// The name of the package
+    pub package_name: String,
+    /// Path to the class RELATIVE TO PACKAGE ROOT, format: "path/to/file#ClassName",
+    /// or its dotted import path (Python)
     ///
-    /// IMPORTANT: Do NOT include the package name as a prefix.
+    /// IMPORTANT: Do NOT include the package name as a prefix of file paths.
     ///
     /// Examples (assuming package "requests"):
     /// - ✅ CORRECT: "sessions.py#Session"
+    /// - ✅ CORRECT: "requests.Session"
     /// - ✅ CORRECT: "adapters.py#HTTPAdapter"
+    /// - ✅ CORRECT: "lib/client.js#Client" (Node.js)
     /// - ❌ WRONG: "requests/sessions.py#Session"
     /// - ❌ WRONG: "requests/adapters.py#HTTPAdapter"
     pub item_path: String,
@@ -268,7 +350,7 @@ pub struct GetClassMethodParams {
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct SearchPackageCodeParams {
-    /// The language of the package (currently only "python" is supported)
+    /// The language of the package ("python" or "node")
     pub language: String,
     /// The name of the package to search
     pub package_name: String,
@@ -290,6 +372,12 @@ pub struct ClearCacheParams {
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct CacheStatsParams {}
 
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct IndexHealthParams {
+    /// Optional language filter: "rust" (symbol indexes), "python" or "node" (semantic indexes)
+    pub language: Option<String>,
+}
+
 /// Utility to validate crate names
 fn validate_crate_name(name: &str) -> Result<(), ErrorData> {
     if name.is_empty() {
@@ -400,9 +488,9 @@ fn validate_python_item_path(item_path: &str, package_name: &str) -> Result<(),
     }
 
     // Also validate the format
-    if !item_path.contains('#') {
+    if !item_path.contains('#') && !is_dotted_name(item_path) {
         return Err(ErrorData::invalid_params(
-            "item_path must contain '#' to separate file path from item name (e.g., 'file.py#ClassName')",
+            "item_path must contain '#' to separate file path from item name (e.g., 'file.py#ClassName') or be a dotted import path (e.g., 'requests.Session')",
             None,
         ));
     }
@@ -410,6 +498,61 @@ fn validate_python_item_path(item_path: &str, package_name: &str) -> Result<(),
     Ok(())
 }
 
+/// Whether a path looks like a Python import path (`requests.adapters.HTTPAdapter`).
+fn is_dotted_name(path: &str) -> bool {
+    path.split('.').all(|segment| {
+        segment
+            .chars()
+            .next()
+            .is_some_and(|first| first.is_alphabetic() || first == '_')
+            && segment.chars().all(|c| c.is_alphanumeric() || c == '_')
+    })
+}
+
+/// Validate a Node.js item path. Unlike Python paths, the module part may
+/// name the package itself (`pkg#Client`, `@scope/pkg/sub#Client`) because
+/// it resolves like an import specifier; an install path spelled out through
+/// `node_modules` is rejected instead.
+fn validate_node_item_path(item_path: &str, package_name: &str) -> Result<(), ErrorData> {
+    let (module, item_name) = split_node_item_path(item_path)?;
+    if module.trim().is_empty() || item_name.trim().is_empty() {
+        return Err(ErrorData::invalid_params(
+            "Node.js item_path needs both a module and an item name (e.g., 'lib/client.js#Client')",
+            None,
+        ));
+    }
+
+    let install_dir = format!("node_modules/{}/", package_name.trim());
+    if let Some(pos) = module.find(&install_dir) {
+        let suggested_path = format!("{}#{item_name}", &module[pos + install_dir.len()..]);
+        return Err(ErrorData::invalid_params(
+            format!(
+                "item_path should not include the install path of '{package_name}'. \
+                 The path is relative to the package root. \
+                 Try using '{suggested_path}' instead of '{item_path}'"
+            ),
+            None,
+        ));
+    }
+    Ok(())
+}
+
+/// Split a Node.js item path ("path/to/file#ClassName", or an import
+/// subpath such as "sub#ClassName") into module and item name.
+fn split_node_item_path(item_path: &str) -> Result<(&str, &str), ErrorData> {
+    item_path.split_once('#').ok_or_else(|| {
+        ErrorData::invalid_params(
+            "Node.js item_path must contain '#' to separate the module from the class name (e.g., 'lib/client.js#Client')",
+            None,
+        )
+    })
+}
+
+fn to_json(value: &impl Serialize, what: &str) -> Result<serde_json::Value, ErrorData> {
+    serde_json::to_value(value)
+        .map_err(|e| ErrorData::internal_error(format!("Failed to serialize {what}: {e}"), None))
+}
+
 #[tool_router]
 impl RustDocsMcpServer {
     /// Search for crates on crates.io
@@ -494,6 +637,47 @@ impl RustDocsMcpServer {
         Ok(CallToolResult::structured(json_value))
     }
 
+    /// Get metadata of an installed package
+    #[tool(
+        description = "Get metadata of an installed Python or Node.js package. Python: version, summary, license, requirements with extras, classifiers, console scripts and the import names it provides (e.g. 'Pillow' provides 'PIL'), from its dist-info. Node.js: version, main/module/types/exports, dependencies, peerDependencies, engines, bin entries and every installed copy, from package.json"
+    )]
+    pub async fn package_info(
+        &self,
+        params: Parameters<PackageInfoParams>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let PackageInfoParams {
+            language,
+            package_name,
+            context_path,
+        } = params.0;
+
+        let lang_lower = self.check_python_or_node(&language)?;
+        if package_name.trim().is_empty() {
+            return Err(ErrorData::invalid_params(
+                "A valid package_name is required.",
+                None,
+            ));
+        }
+
+        let info = match lang_lower.as_str() {
+            "python" => self
+                .engine
+                .python_package_info(&package_name, context_path.as_deref())
+                .await
+                .and_then(|info| Ok(serde_json::to_value(&info)?)),
+            _ => self
+                .engine
+                .node_package_info(&package_name, context_path.as_deref())
+                .await
+                .and_then(|info| Ok(serde_json::to_value(&info)?)),
+        };
+        let json_value = info.map_err(|e| {
+            ErrorData::internal_error(format!("Failed to read metadata for '{package_name}': {e}. Verify the package is installed in the project's environment."), None)
+        })?;
+
+        Ok(CallToolResult::structured(json_value))
+    }
+
     /// Get documentation for a specific item in a crate
     #[tool(description = "Get documentation for a specific item in a crate")]
     pub async fn get_item_doc(
@@ -706,7 +890,7 @@ impl RustDocsMcpServer {
 
     /// Get the implementation and documentation for a code item from a local environment
     #[tool(
-        description = "Get the implementation and documentation for an item from an installed package (Python/Node.js)."
+        description = "Get the implementation and documentation for an item from an installed package (Python/Node.js). Node.js results include the declared signature and TSDoc from the package typings or @types/<name> when available."
     )]
     pub async fn get_implementation(
         &self,
@@ -739,7 +923,7 @@ impl RustDocsMcpServer {
                 None,
             ));
         }
-        if item_path.trim().is_empty() || !item_path.contains('#') {
+        if item_path.trim().is_empty() || (!item_path.contains('#') && lang_lower != "python") {
             return Err(ErrorData::invalid_params(
                 "item_path must be in the format 'path/to/file#item_name'.",
                 None,
@@ -747,8 +931,10 @@ impl RustDocsMcpServer {
         }
 
         // Validate that item_path doesn't include package name prefix for Python/Node
-        if lang_lower == "python" || lang_lower == "node" {
-            validate_python_item_path(&item_path, &package_name)?;
+        match lang_lower.as_str() {
+            "python" => validate_python_item_path(&item_path, &package_name)?,
+            "node" => validate_node_item_path(&item_path, &package_name)?,
+            _ => {}
         }
 
         let context = tokio::time::timeout(
@@ -933,77 +1119,182 @@ impl RustDocsMcpServer {
         Ok(CallToolResult::structured(json_value))
     }
 
-    /// Perform semantic semantic search across local language packages (Python support).
+    /// Search symbols across every crate of the project or the local cache
     #[tool(
-        description = "Perform semantic search within a local package (currently Python support)"
+        description = "Search symbols across all crates in the project's Cargo.lock or the local cache; hits are merged and tagged with their crate"
     )]
-    pub async fn semantic_search(
+    pub async fn search_symbols_global(
         &self,
-        params: Parameters<SemanticSearchParams>,
+        params: Parameters<SearchSymbolsGlobalParams>,
     ) -> Result<CallToolResult, ErrorData> {
-        if !self.config.python_enabled {
+        if !self.config.rust_enabled {
             return Err(ErrorData::invalid_request(
-                "Python tools are disabled. To enable, start the server without the --no-python flag (or use --python-only for Python-only mode).",
+                "Rust tools are disabled. To enable, start the server without the --no-rust flag (or use --rust-only for Rust-only mode).",
                 None,
             ));
         }
 
-        let SemanticSearchParams {
-            language,
-            package_name,
+        let SearchSymbolsGlobalParams {
             query,
+            kinds,
             limit,
+            scope,
             context_path,
+            fetch_missing,
         } = params.0;
 
-        if language.trim().is_empty() {
-            return Err(ErrorData::invalid_params("language cannot be empty", None));
+        if query.trim().is_empty() {
+            return Err(ErrorData::invalid_params(
+                "Search query cannot be empty",
+                None,
+            ));
         }
 
-        let normalized_language = language.trim().to_lowercase();
-        if normalized_language != "python" {
+        if query.len() > 256 {
             return Err(ErrorData::invalid_params(
-                "semantic search currently supports language 'python' only",
+                "Search query too long (max 256 characters)",
                 None,
             ));
         }
 
-        if package_name.trim().is_empty() {
+        let search_limit = limit.unwrap_or(20);
+        if search_limit > 100 {
             return Err(ErrorData::invalid_params(
-                "package_name cannot be empty",
+                "Search limit too large (max 100)",
                 None,
             ));
         }
 
-        if query.trim().is_empty() {
-            return Err(ErrorData::invalid_params("query cannot be empty", None));
+        let scope = match scope.as_deref().map(|s| s.trim().to_lowercase()).as_deref() {
+            None | Some("") => None,
+            Some("lockfile") => Some(SymbolSearchScope::Lockfile),
+            Some("cache") => Some(SymbolSearchScope::Cache),
+            Some(other) => {
+                return Err(ErrorData::invalid_params(
+                    format!("Unknown scope '{other}'. Expected 'lockfile' or 'cache'"),
+                    None,
+                ));
+            }
+        };
+
+        let response = tokio::time::timeout(
+            std::time::Duration::from_secs(120),
+            self.engine.search_symbols_global(
+                query.trim(),
+                kinds.as_deref(),
+                search_limit,
+                scope,
+                context_path
+                    .as_deref()
+                    .map(|s| s.trim())
+                    .filter(|s| !s.is_empty()),
+                fetch_missing.unwrap_or(false),
+            ),
+        )
+        .await
+        .map_err(|_| {
+            ErrorData::internal_error(
+                format!("Timeout searching symbols across crates for query: {query}"),
+                None,
+            )
+        })?
+        .map_err(|e| {
+            ErrorData::internal_error(
+                format!("Failed to search symbols across crates for '{query}': {e}"),
+                None,
+            )
+        })?;
+
+        let json_value = serde_json::to_value(&response).map_err(|e| {
+            ErrorData::internal_error(
+                format!("Failed to serialize symbol search results: {e}"),
+                None,
+            )
+        })?;
+
+        Ok(CallToolResult::structured(json_value))
+    }
+
+    /// Complete a partially typed Rust or Python item path
+    #[tool(
+        description = "Complete a partially typed item path (e.g. 'tokio::sync::Mu', 'requests.ad'), ranked by item kind and popularity"
+    )]
+    pub async fn complete_path(
+        &self,
+        params: Parameters<CompletePathParams>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let CompletePathParams {
+            language,
+            prefix,
+            package_name,
+            version,
+            limit,
+            context_path,
+        } = params.0;
+
+        let normalized_language = language.trim().to_lowercase();
+        match normalized_language.as_str() {
+            "rust" if !self.config.rust_enabled => {
+                return Err(ErrorData::invalid_request(
+                    "Rust tools are disabled. To enable, start the server without the --no-rust flag (or use --rust-only for Rust-only mode).",
+                    None,
+                ));
+            }
+            "python" if !self.config.python_enabled => {
+                return Err(ErrorData::invalid_request(
+                    "Python tools are disabled. To enable, start the server without the --no-python flag (or use --python-only for Python-only mode).",
+                    None,
+                ));
+            }
+            "rust" | "python" => {}
+            _ => {
+                return Err(ErrorData::invalid_params(
+                    "path completion currently supports languages 'rust' and 'python'",
+                    None,
+                ));
+            }
         }
 
-        if query.len() > 512 {
+        if prefix.trim().is_empty() {
+            return Err(ErrorData::invalid_params("prefix cannot be empty", None));
+        }
+        if prefix.len() > 256 {
             return Err(ErrorData::invalid_params(
-                "query too long (max 512 characters)",
+                "prefix too long (max 256 characters)",
                 None,
             ));
         }
 
-        let search_limit = limit.unwrap_or(10);
-        if search_limit == 0 {
+        let package_name = package_name
+            .as_deref()
+            .map(str::trim)
+            .filter(|s| !s.is_empty());
+        if normalized_language == "rust" {
+            let crate_name = package_name
+                .or_else(|| prefix.trim().split("::").next())
+                .unwrap_or_default();
+            validate_crate_name(crate_name)?;
+        }
+
+        let completion_limit = limit.unwrap_or(20);
+        if completion_limit == 0 {
             return Err(ErrorData::invalid_params(
                 "limit must be greater than zero",
                 None,
             ));
         }
-        if search_limit > 50 {
-            return Err(ErrorData::invalid_params("limit too large (max 50)", None));
+        if completion_limit > 100 {
+            return Err(ErrorData::invalid_params("limit too large (max 100)", None));
         }
 
-        let results = tokio::time::timeout(
+        let completions = tokio::time::timeout(
             std::time::Duration::from_secs(30),
-            self.engine.semantic_search(
+            self.engine.complete_path(
                 &normalized_language,
-                package_name.trim(),
-                query.trim(),
-                search_limit as usize,
+                &prefix,
+                package_name,
+                version.as_deref(),
+                completion_limit as usize,
                 context_path
                     .as_deref()
                     .map(|s| s.trim())
@@ -1012,21 +1303,160 @@ impl RustDocsMcpServer {
         )
         .await
         .map_err(|_| {
-            ErrorData::internal_error(
-                format!(
-                    "Timeout performing semantic search in package '{}' for query '{}'",
-                    package_name, query
-                ),
-                None,
-            )
+            ErrorData::internal_error(format!("Timeout completing path '{prefix}'"), None)
         })?
         .map_err(|e| {
             ErrorData::internal_error(
-                format!("Failed semantic search in package '{package_name}' for '{query}': {e}. Ensure the package is installed in the specified context path."),
+                format!("Failed to complete path '{prefix}': {e}. Ensure the crate exists on docs.rs or the package is installed in the specified context path."),
                 None,
             )
         })?;
 
+        let json_value = serde_json::to_value(&completions).map_err(|e| {
+            ErrorData::internal_error(format!("Failed to serialize completions: {e}"), None)
+        })?;
+
+        Ok(CallToolResult::structured(json_value))
+    }
+
+    /// Perform semantic search within a Python package, Rust crate or Node.js package
+    #[tool(
+        description = "Natural-language search within a Python package, Rust crate or Node.js package, ranked by names, signatures and docs"
+    )]
+    pub async fn semantic_search(
+        &self,
+        params: Parameters<SemanticSearchParams>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let SemanticSearchParams {
+            language,
+            package_name,
+            packages,
+            query,
+            limit,
+            context_path,
+        } = params.0;
+
+        if language.trim().is_empty() {
+            return Err(ErrorData::invalid_params("language cannot be empty", None));
+        }
+
+        let normalized_language = language.trim().to_lowercase();
+        match normalized_language.as_str() {
+            "python" if !self.config.python_enabled => {
+                return Err(ErrorData::invalid_request(
+                    "Python tools are disabled. To enable, start the server without the --no-python flag (or use --python-only for Python-only mode).",
+                    None,
+                ));
+            }
+            "rust" if !self.config.rust_enabled => {
+                return Err(ErrorData::invalid_request(
+                    "Rust tools are disabled. To enable, start the server without the --no-rust flag.",
+                    None,
+                ));
+            }
+            "node" if !self.config.node_enabled => {
+                return Err(ErrorData::invalid_request(
+                    "Node.js tools are disabled. To enable, start the server without the --no-node flag (or use --node-only for Node.js-only mode).",
+                    None,
+                ));
+            }
+            "python" | "rust" | "node" => {}
+            _ => {
+                return Err(ErrorData::invalid_params(
+                    "semantic search supports languages 'python', 'rust' and 'node'",
+                    None,
+                ));
+            }
+        }
+
+        let package_set: Vec<String> = std::iter::once(package_name)
+            .chain(packages.unwrap_or_default())
+            .map(|name| name.trim().to_string())
+            .filter(|name| !name.is_empty())
+            .collect();
+        if package_set.is_empty() {
+            return Err(ErrorData::invalid_params(
+                "package_name cannot be empty",
+                None,
+            ));
+        }
+        let multi_package = package_set.len() > 1 || package_set[0] == "*";
+        if multi_package && normalized_language != "python" {
+            return Err(ErrorData::invalid_params(
+                "searching several packages at once is supported for language 'python' only",
+                None,
+            ));
+        }
+        let package_label = package_set.join(", ");
+
+        if query.trim().is_empty() {
+            return Err(ErrorData::invalid_params("query cannot be empty", None));
+        }
+
+        if query.len() > 512 {
+            return Err(ErrorData::invalid_params(
+                "query too long (max 512 characters)",
+                None,
+            ));
+        }
+
+        let search_limit = limit.unwrap_or(10);
+        if search_limit == 0 {
+            return Err(ErrorData::invalid_params(
+                "limit must be greater than zero",
+                None,
+            ));
+        }
+        if search_limit > 50 {
+            return Err(ErrorData::invalid_params("limit too large (max 50)", None));
+        }
+
+        let context_path = context_path
+            .as_deref()
+            .map(|s| s.trim())
+            .filter(|s| !s.is_empty());
+        let search = async {
+            if multi_package {
+                self.engine
+                    .semantic_search_python_packages(
+                        &package_set,
+                        query.trim(),
+                        search_limit as usize,
+                        context_path,
+                    )
+                    .await
+            } else {
+                self.engine
+                    .semantic_search(
+                        &normalized_language,
+                        &package_set[0],
+                        query.trim(),
+                        search_limit as usize,
+                        context_path,
+                    )
+                    .await
+            }
+        };
+        // Indexing a whole environment on first use takes longer than one package
+        let timeout_secs = if multi_package { 180 } else { 30 };
+        let results = tokio::time::timeout(std::time::Duration::from_secs(timeout_secs), search)
+            .await
+            .map_err(|_| {
+                ErrorData::internal_error(
+                    format!(
+                        "Timeout performing semantic search in package '{}' for query '{}'",
+                        package_label, query
+                    ),
+                    None,
+                )
+            })?
+            .map_err(|e| {
+                ErrorData::internal_error(
+                    format!("Failed semantic search in package '{package_label}' for '{query}': {e}. Ensure the package is installed in the specified context path (Python, Node.js) or the crate exists on docs.rs or in the local cargo registry (Rust)."),
+                    None,
+                )
+            })?;
+
         let json_value = serde_json::to_value(&results).map_err(|e| {
             ErrorData::internal_error(
                 format!("Failed to serialize semantic search results: {e}"),
@@ -1038,10 +1468,97 @@ impl RustDocsMcpServer {
     }
 
     /// List all methods of a class
-    #[tool(description = "List all methods of a class with signatures and metadata")]
+    #[tool(
+        description = "List all methods of a Python or JavaScript/TypeScript class with signatures and metadata (for JS/TS: static/getter/setter/async/private flags, parameter and return types, JSDoc)"
+    )]
     pub async fn list_class_methods(
         &self,
         params: Parameters<ListClassMethodsParams>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let ListClassMethodsParams {
+            language,
+            package_name,
+            item_path,
+            include_private,
+            include_inherited,
+            context_path,
+        } = params.0;
+
+        let lang_lower = self.check_python_or_node(&language)?;
+
+        // Validate that item_path doesn't include package name prefix
+        if lang_lower == "node" {
+            validate_node_item_path(&item_path, &package_name)?;
+        } else {
+            validate_python_item_path(&item_path, &package_name)?;
+        }
+
+        let context = std::path::PathBuf::from(context_path.as_deref().unwrap_or("."));
+
+        let methods = if lang_lower == "node" {
+            if include_inherited.unwrap_or(false) {
+                return Err(ErrorData::invalid_params(
+                    "include_inherited is only supported for Python",
+                    None,
+                ));
+            }
+            let (relative_path, class_name) = split_node_item_path(&item_path)?;
+            self.engine
+                .node_processor
+                .list_class_methods(
+                    &package_name,
+                    &context,
+                    relative_path,
+                    class_name,
+                    include_private.unwrap_or(false),
+                )
+                .await
+                .map_err(|e| {
+                    ErrorData::internal_error(format!("Failed to list methods for class '{class_name}' in '{package_name}': {e}. Verify the class exists at '{relative_path}'."), None)
+                })
+                .and_then(|methods| to_json(&methods, "class methods"))?
+        } else {
+            // "path/to/file#ClassName", or a dotted import path such as "requests.Session"
+            let (relative_path, class_name) = self
+                .engine
+                .python_processor
+                .resolve_item_path(&package_name, &context, &item_path)
+                .await
+                .map_err(|e| {
+                    ErrorData::invalid_params(
+                        format!("Could not resolve item_path '{item_path}': {e}"),
+                        None,
+                    )
+                })?;
+            let (relative_path, class_name) = (relative_path.as_str(), class_name.as_str());
+
+            self.engine
+                .python_processor
+                .list_class_methods(
+                    &package_name,
+                    &context,
+                    relative_path,
+                    class_name,
+                    include_private.unwrap_or(false),
+                    include_inherited.unwrap_or(false),
+                )
+                .await
+                .map_err(|e| {
+                    ErrorData::internal_error(format!("Failed to list methods for class '{class_name}' in '{package_name}': {e}. Verify the class exists at '{relative_path}'."), None)
+                })
+                .and_then(|methods| to_json(&methods, "class methods"))?
+        };
+
+        Ok(CallToolResult::structured(methods))
+    }
+
+    /// Get a specific method from a class
+    #[tool(
+        description = "Describe a class: base classes, methods, and its fields and attributes with types and defaults (dataclass, attrs, NamedTuple, TypedDict and pydantic fields, class attributes, `self.x` assignments in __init__, __slots__)"
+    )]
+    pub async fn get_class_info(
+        &self,
+        params: Parameters<GetClassInfoParams>,
     ) -> Result<CallToolResult, ErrorData> {
         if !self.config.python_enabled {
             return Err(ErrorData::invalid_request(
@@ -1050,12 +1567,10 @@ impl RustDocsMcpServer {
             ));
         }
 
-        let ListClassMethodsParams {
+        let GetClassInfoParams {
             language,
             package_name,
             item_path,
-            include_private,
-            include_inherited: _,
             context_path,
         } = params.0;
 
@@ -1069,54 +1584,43 @@ impl RustDocsMcpServer {
         // Validate that item_path doesn't include package name prefix
         validate_python_item_path(&item_path, &package_name)?;
 
-        // Parse item_path: "path/to/file#ClassName"
-        let parts: Vec<&str> = item_path.split('#').collect();
-        if parts.len() != 2 {
-            return Err(ErrorData::invalid_params(
-                "item_path must be in format 'path/to/file#ClassName'",
-                None,
-            ));
-        }
-        let relative_path = parts[0];
-        let class_name = parts[1];
-
         let context = std::path::PathBuf::from(context_path.as_deref().unwrap_or("."));
 
-        let methods = self
+        // "path/to/file#ClassName", or a dotted import path such as "requests.Session"
+        let (relative_path, class_name) = self
             .engine
             .python_processor
-            .list_class_methods(
-                &package_name,
-                &context,
-                relative_path,
-                class_name,
-                include_private.unwrap_or(false),
-            )
+            .resolve_item_path(&package_name, &context, &item_path)
+            .await
+            .map_err(|e| {
+                ErrorData::invalid_params(
+                    format!("Could not resolve item_path '{item_path}': {e}"),
+                    None,
+                )
+            })?;
+        let (relative_path, class_name) = (relative_path.as_str(), class_name.as_str());
+
+        let info = self
+            .engine
+            .python_processor
+            .get_class_info(&package_name, &context, relative_path, class_name)
             .await
             .map_err(|e| {
-                ErrorData::internal_error(format!("Failed to list methods for class '{class_name}' in '{package_name}': {e}. Verify the class exists at '{relative_path}'."), None)
+                ErrorData::internal_error(format!("Failed to describe class '{class_name}' in '{package_name}': {e}. Verify the class exists at '{relative_path}'."), None)
             })?;
 
-        let json_value = serde_json::to_value(&methods).map_err(|e| {
-            ErrorData::internal_error(format!("Failed to serialize class methods: {e}"), None)
+        let json_value = serde_json::to_value(&info).map_err(|e| {
+            ErrorData::internal_error(format!("Failed to serialize class info: {e}"), None)
         })?;
 
         Ok(CallToolResult::structured(json_value))
     }
 
-    /// Get a specific method from a class
     #[tool(description = "Get the implementation of a specific method from a class")]
     pub async fn get_class_method(
         &self,
         params: Parameters<GetClassMethodParams>,
     ) -> Result<CallToolResult, ErrorData> {
-        if !self.config.python_enabled {
-            return Err(ErrorData::invalid_request(
-                "Python tools are disabled. To enable, start the server without the --no-python flag (or use --python-only for Python-only mode).",
-                None,
-            ));
-        }
-
         let GetClassMethodParams {
             language,
             package_name,
@@ -1125,64 +1629,75 @@ impl RustDocsMcpServer {
             context_path,
         } = params.0;
 
-        if language.trim().to_lowercase() != "python" {
-            return Err(ErrorData::invalid_params(
-                "Currently only 'python' is supported",
-                None,
-            ));
-        }
+        let lang_lower = self.check_python_or_node(&language)?;
 
         // Validate that item_path doesn't include package name prefix
-        validate_python_item_path(&item_path, &package_name)?;
-
-        // Parse item_path: "path/to/file#ClassName"
-        let parts: Vec<&str> = item_path.split('#').collect();
-        if parts.len() != 2 {
-            return Err(ErrorData::invalid_params(
-                "item_path must be in format 'path/to/file#ClassName'",
-                None,
-            ));
+        if lang_lower == "node" {
+            validate_node_item_path(&item_path, &package_name)?;
+        } else {
+            validate_python_item_path(&item_path, &package_name)?;
         }
-        let relative_path = parts[0];
-        let class_name = parts[1];
 
         let context = std::path::PathBuf::from(context_path.as_deref().unwrap_or("."));
 
-        let method = self
-            .engine
-            .python_processor
-            .get_class_method(
-                &package_name,
-                &context,
-                relative_path,
-                class_name,
-                &method_name,
-            )
-            .await
-            .map_err(|e| {
-                ErrorData::internal_error(format!("Failed to get method '{method_name}' from class '{class_name}': {e}. Verify the method exists and is accessible."), None)
-            })?;
+        let method = if lang_lower == "node" {
+            let (relative_path, class_name) = split_node_item_path(&item_path)?;
+            self.engine
+                .node_processor
+                .get_class_method(
+                    &package_name,
+                    &context,
+                    relative_path,
+                    class_name,
+                    &method_name,
+                )
+                .await
+                .map_err(|e| {
+                    ErrorData::internal_error(format!("Failed to get method '{method_name}' from class '{class_name}': {e}. Verify the method exists and is accessible."), None)
+                })
+                .and_then(|method| to_json(&method, "class method data"))?
+        } else {
+            // "path/to/file#ClassName", or a dotted import path such as "requests.Session"
+            let (relative_path, class_name) = self
+                .engine
+                .python_processor
+                .resolve_item_path(&package_name, &context, &item_path)
+                .await
+                .map_err(|e| {
+                    ErrorData::invalid_params(
+                        format!("Could not resolve item_path '{item_path}': {e}"),
+                        None,
+                    )
+                })?;
+            let (relative_path, class_name) = (relative_path.as_str(), class_name.as_str());
 
-        let json_value = serde_json::to_value(&method).map_err(|e| {
-            ErrorData::internal_error(format!("Failed to serialize class method data: {e}"), None)
-        })?;
+            self.engine
+                .python_processor
+                .get_class_method(
+                    &package_name,
+                    &context,
+                    relative_path,
+                    class_name,
+                    &method_name,
+                )
+                .await
+                .map_err(|e| {
+                    ErrorData::internal_error(format!("Failed to get method '{method_name}' from class '{class_name}': {e}. Verify the method exists and is accessible."), None)
+                })
+                .and_then(|method| to_json(&method, "class method data"))?
+        };
 
-        Ok(CallToolResult::structured(json_value))
+        Ok(CallToolResult::structured(method))
     }
 
     /// Search code across a package
-    #[tool(description = "Search for code patterns across an entire Python package")]
+    #[tool(
+        description = "Search for code patterns across an entire Python or Node.js (JavaScript/TypeScript) package"
+    )]
     pub async fn search_package_code(
         &self,
         params: Parameters<SearchPackageCodeParams>,
     ) -> Result<CallToolResult, ErrorData> {
-        if !self.config.python_enabled {
-            return Err(ErrorData::invalid_request(
-                "Python tools are disabled. To enable, start the server without the --no-python flag (or use --python-only for Python-only mode).",
-                None,
-            ));
-        }
-
         let SearchPackageCodeParams {
             language,
             package_name,
@@ -1192,12 +1707,7 @@ impl RustDocsMcpServer {
             context_path,
         } = params.0;
 
-        if language.trim().to_lowercase() != "python" {
-            return Err(ErrorData::invalid_params(
-                "Currently only 'python' is supported",
-                None,
-            ));
-        }
+        let lang_lower = self.check_python_or_node(&language)?;
 
         let mode = match search_mode.to_lowercase().as_str() {
             "name" => crate::doc_engine::python_analyzer::SearchMode::Name,
@@ -1219,14 +1729,20 @@ impl RustDocsMcpServer {
             return Err(ErrorData::invalid_params("limit too large (max 100)", None));
         }
 
-        let results = self
-            .engine
-            .python_processor
-            .search_package(&package_name, &context, &pattern, mode, search_limit)
-            .await
-            .map_err(|e| {
-                ErrorData::internal_error(format!("Failed to search package '{package_name}' for pattern '{pattern}': {e}. Check that the package is installed and the regex pattern is valid."), None)
-            })?;
+        let results = if lang_lower == "node" {
+            self.engine
+                .node_processor
+                .search_package(&package_name, &context, &pattern, mode, search_limit)
+                .await
+        } else {
+            self.engine
+                .python_processor
+                .search_package(&package_name, &context, &pattern, mode, search_limit)
+                .await
+        }
+        .map_err(|e| {
+            ErrorData::internal_error(format!("Failed to search package '{package_name}' for pattern '{pattern}': {e}. Check that the package is installed and the regex pattern is valid."), None)
+        })?;
 
         let json_value = serde_json::to_value(&results).map_err(|e| {
             ErrorData::internal_error(
@@ -1238,6 +1754,69 @@ impl RustDocsMcpServer {
         Ok(CallToolResult::structured(json_value))
     }
 
+    /// Report health of built indexes
+    #[tool(
+        description = "Report size, build duration, staleness and validation problems for every built Rust symbol index and Python or Node.js semantic index"
+    )]
+    pub async fn index_health(
+        &self,
+        params: Parameters<IndexHealthParams>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let language = params
+            .0
+            .language
+            .as_deref()
+            .map(|s| s.trim().to_lowercase())
+            .filter(|s| !s.is_empty());
+
+        match language.as_deref() {
+            None => {}
+            Some("rust") if !self.config.rust_enabled => {
+                return Err(ErrorData::invalid_request(
+                    "Rust tools are disabled. To enable, start the server without the --no-rust flag (or use --rust-only for Rust-only mode).",
+                    None,
+                ));
+            }
+            Some("python") if !self.config.python_enabled => {
+                return Err(ErrorData::invalid_request(
+                    "Python tools are disabled. To enable, start the server without the --no-python flag (or use --python-only for Python-only mode).",
+                    None,
+                ));
+            }
+            Some("node") if !self.config.node_enabled => {
+                return Err(ErrorData::invalid_request(
+                    "Node.js tools are disabled. To enable, start the server without the --no-node flag (or use --node-only for Node.js-only mode).",
+                    None,
+                ));
+            }
+            Some("rust") | Some("python") | Some("node") => {}
+            Some(other) => {
+                return Err(ErrorData::invalid_params(
+                    format!("Unsupported language '{other}'. Use 'rust', 'python' or 'node'."),
+                    None,
+                ));
+            }
+        }
+
+        let report = tokio::time::timeout(
+            std::time::Duration::from_secs(30),
+            self.engine.index_health(language.as_deref()),
+        )
+        .await
+        .map_err(|_| {
+            ErrorData::internal_error("Timeout collecting index health".to_string(), None)
+        })?
+        .map_err(|e| {
+            ErrorData::internal_error(format!("Failed to collect index health: {e}"), None)
+        })?;
+
+        let json_value = serde_json::to_value(&report).map_err(|e| {
+            ErrorData::internal_error(format!("Failed to serialize index health: {e}"), None)
+        })?;
+
+        Ok(CallToolResult::structured(json_value))
+    }
+
     /// Get cache statistics
     #[tool(description = "Get cache statistics and performance metrics")]
     pub async fn get_cache_stats(
@@ -1334,11 +1913,113 @@ impl RustDocsMcpServer {
     }
 }
 
+impl RustDocsMcpServer {
+    /// Validate the language of a tool supporting Python and Node.js, and
+    /// check its tools are enabled. Returns the lowercased language.
+    fn check_python_or_node(&self, language: &str) -> Result<String, ErrorData> {
+        let lang_lower = language.trim().to_lowercase();
+        match lang_lower.as_str() {
+            "python" if !self.config.python_enabled => Err(ErrorData::invalid_request(
+                "Python tools are disabled. To enable, start the server without the --no-python flag (or use --python-only for Python-only mode).",
+                None,
+            )),
+            "node" if !self.config.node_enabled => Err(ErrorData::invalid_request(
+                "Node.js tools are disabled. To enable, start the server without the --no-node flag (or use --node-only for Node.js-only mode).",
+                None,
+            )),
+            "python" | "node" => Ok(lang_lower),
+            _ => Err(ErrorData::invalid_params(
+                "Supported languages are 'python' and 'node'",
+                None,
+            )),
+        }
+    }
+
+    /// Resolve an MCP `completion/complete` request for a path-like argument.
+    ///
+    /// Language and package come from previously entered arguments
+    /// (`language`, `crate_name`, `package_name`); otherwise `::` in the value
+    /// selects Rust and `.` selects Python. Crate-relative values (e.g. the
+    /// `path` of `get_item_doc`) are qualified for lookup and returned
+    /// relative again. Any failure yields no completions.
+    async fn complete_argument(&self, request: &CompleteRequestParams) -> Option<Vec<String>> {
+        const PATH_ARGUMENTS: &[&str] = &["path", "prefix", "trait_path", "type_path"];
+
+        let value = request.argument.value.trim();
+        if !PATH_ARGUMENTS.contains(&request.argument.name.as_str()) || value.is_empty() {
+            return None;
+        }
+
+        let arg = |name: &str| {
+            request
+                .context
+                .as_ref()
+                .and_then(|c| c.get_argument(name))
+                .map(|s| s.trim())
+                .filter(|s| !s.is_empty())
+        };
+
+        let language = match arg("language") {
+            Some(language) => language.to_lowercase(),
+            None if arg("crate_name").is_some() || value.contains("::") => "rust".to_string(),
+            None if value.contains('.') => "python".to_string(),
+            None => return None,
+        };
+        let (enabled, separator) = match language.as_str() {
+            "rust" => (self.config.rust_enabled, "::"),
+            "python" => (self.config.python_enabled, "."),
+            _ => return None,
+        };
+        if !enabled {
+            return None;
+        }
+
+        let package = arg("crate_name").or_else(|| arg("package_name"));
+        let qualifier = package
+            .map(|p| format!("{}{separator}", p.replace('-', "_")))
+            .filter(|q| !value.starts_with(q.as_str()) && !q.starts_with(value));
+        let query = format!("{}{value}", qualifier.as_deref().unwrap_or_default());
+
+        let completions = tokio::time::timeout(
+            std::time::Duration::from_secs(10),
+            self.engine.complete_path(
+                &language,
+                &query,
+                package,
+                arg("version"),
+                CompletionInfo::MAX_VALUES,
+                arg("context_path"),
+            ),
+        )
+        .await
+        .ok()?
+        .map_err(|e| tracing::debug!("Completion for '{query}' failed: {e}"))
+        .ok()?;
+
+        Some(
+            completions
+                .into_iter()
+                .map(|c| match &qualifier {
+                    Some(q) => c
+                        .value
+                        .strip_prefix(q.as_str())
+                        .unwrap_or(&c.value)
+                        .to_string(),
+                    None => c.value,
+                })
+                .collect(),
+        )
+    }
+}
+
 impl ServerHandler for RustDocsMcpServer {
     fn get_info(&self) -> ServerInfo {
         ServerInfo {
             protocol_version: Default::default(),
-            capabilities: ServerCapabilities::builder().enable_tools().build(),
+            capabilities: ServerCapabilities::builder()
+                .enable_completions()
+                .enable_tools()
+                .build(),
             server_info: Implementation {
                 name: "rust-docs-mcp-server".to_string(),
                 version: env!("CARGO_PKG_VERSION").to_string(),
@@ -1347,7 +2028,7 @@ impl ServerHandler for RustDocsMcpServer {
                 icons: Some(vec![]),
             },
             instructions: Some(
-                "Rust Documentation MCP Server - Query Rust crate documentation, explore traits, implementations, and source code. Use search_crates to find crates, crate_info for details, get_item_doc for documentation, list_trait_impls/list_impls_for_type for implementation exploration, source_snippet for code viewing, search_symbols for symbol discovery, get_cache_stats for cache statistics, clear_cache to clear cache entries, and cleanup_cache to remove expired entries."
+                "Rust Documentation MCP Server - Query Rust crate documentation, explore traits, implementations, and source code. Use search_crates to find crates, crate_info for details, get_item_doc for documentation, list_trait_impls/list_impls_for_type for implementation exploration, source_snippet for code viewing, search_symbols for symbol discovery, search_symbols_global to find symbols across all dependencies, index_health to inspect built indexes, get_cache_stats for cache statistics, clear_cache to clear cache entries, and cleanup_cache to remove expired entries."
                     .to_string(),
             ),
         }
@@ -1375,7 +2056,7 @@ impl ServerHandler for RustDocsMcpServer {
             .filter(|tool| {
                 let name = tool.name.as_ref();
                 match name {
-                    // Rust tools (8 tools)
+                    // Rust tools (9 tools)
                     "search_crates"
                     | "crate_info"
                     | "get_item_doc"
@@ -1383,21 +2064,35 @@ impl ServerHandler for RustDocsMcpServer {
                     | "list_impls_for_type"
                     | "source_snippet"
                     | "resolve_imports"
-                    | "search_symbols" => self.config.rust_enabled,
-                    // Python tools (4 tools)
-                    "semantic_search"
-                    | "list_class_methods"
-                    | "get_class_method"
-                    | "search_package_code" => self.config.python_enabled,
+                    | "search_symbols"
+                    | "search_symbols_global" => self.config.rust_enabled,
+                    // Python tools (1 tool)
+                    "get_class_info" => self.config.python_enabled,
                     // Cache tools (3 tools)
                     "get_cache_stats" | "clear_cache" | "cleanup_cache" => {
                         self.config.cache_enabled
                     }
-                    // Cross-language tool (get_implementation supports Python and Node.js)
-                    "get_implementation" => {
+                    // Cross-language tools (Python and Node.js)
+                    "get_implementation"
+                    | "package_info"
+                    | "list_class_methods"
+                    | "get_class_method"
+                    | "search_package_code" => {
                         // Show this tool if either Python or Node.js is enabled
                         self.config.python_enabled || self.config.node_enabled
                     }
+                    "semantic_search" => {
+                        self.config.rust_enabled
+                            || self.config.python_enabled
+                            || self.config.node_enabled
+                    }
+                    // Cross-language tools (Rust and Python)
+                    "complete_path" => self.config.rust_enabled || self.config.python_enabled,
+                    "index_health" => {
+                        self.config.rust_enabled
+                            || self.config.python_enabled
+                            || self.config.node_enabled
+                    }
                     // Default: include the tool (shouldn't happen for known tools)
                     _ => true,
                 }
@@ -1411,6 +2106,17 @@ impl ServerHandler for RustDocsMcpServer {
         })
     }
 
+    async fn complete(
+        &self,
+        request: CompleteRequestParams,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<CompleteResult, ErrorData> {
+        let values = self.complete_argument(&request).await.unwrap_or_default();
+        let completion = CompletionInfo::with_all_values(values)
+            .map_err(|e| ErrorData::internal_error(e, None))?;
+        Ok(CompleteResult { completion })
+    }
+
     async fn call_tool(
         &self,
         request: rmcp::model::CallToolRequestParams,
@@ -1418,8 +2124,57 @@ impl ServerHandler for RustDocsMcpServer {
     ) -> Result<rmcp::model::CallToolResult, ErrorData> {
         use rmcp::handler::server::tool::ToolCallContext;
 
+        // Index builds triggered by this call stream progress to the client
+        // when it asked for progress notifications.
+        let progress_token = request
+            .meta
+            .as_ref()
+            .and_then(|meta| meta.get_progress_token())
+            .or_else(|| context.meta.get_progress_token());
+        let request_id = health::next_request_id();
+        let forwarder = progress_token.map(|token| {
+            let mut events = self.engine.subscribe_build_progress();
+            let peer = context.peer.clone();
+            tokio::spawn(async move {
+                let mut sent = 0u32;
+                loop {
+                    let event = match events.recv().await {
+                        Ok(event) => event,
+                        Err(tokio::sync::broadcast::error::RecvError::Lagged(_)) => continue,
+                        Err(tokio::sync::broadcast::error::RecvError::Closed) => break,
+                    };
+                    if event.request_id != Some(request_id) {
+                        continue;
+                    }
+                    sent += 1;
+                    let progress = &event.progress;
+                    let message = format!(
+                        "{}: {:?} {}/{}",
+                        event.index_id,
+                        progress.phase,
+                        progress.items_processed,
+                        progress.total_items
+                    );
+                    let param = ProgressNotificationParam {
+                        progress_token: token.clone(),
+                        progress: f64::from(sent),
+                        total: None,
+                        message: Some(message),
+                    };
+                    if peer.notify_progress(param).await.is_err() {
+                        break;
+                    }
+                }
+            })
+        });
+
         let tool_context = ToolCallContext::new(self, request, context);
-        self.tool_router.call(tool_context).await
+        let result =
+            health::attribute_builds(request_id, self.tool_router.call(tool_context)).await;
+        if let Some(forwarder) = forwarder {
+            forwarder.abort();
+        }
+        result
     }
 }
 
@@ -1521,4 +2276,20 @@ mod tests {
         assert!(validate_item_path("std::::HashMap").is_err());
         assert!(validate_item_path(&"a".repeat(1000)).is_err());
     }
+
+    #[test]
+    fn test_validate_node_item_path() {
+        assert!(validate_node_item_path("lib/client.js#Client", "axios").is_ok());
+        // Import specifiers may name the package, scoped or not
+        assert!(validate_node_item_path("axios#Axios", "axios").is_ok());
+        assert!(validate_node_item_path("@scope/pkg/sub#Client", "@scope/pkg").is_ok());
+        assert!(validate_node_item_path("my_pkg/index.js#Client", "my-pkg").is_ok());
+
+        let err =
+            validate_node_item_path("node_modules/@scope/pkg/lib/client.js#Client", "@scope/pkg")
+                .unwrap_err();
+        assert!(err.message.contains("'lib/client.js#Client'"));
+        assert!(validate_node_item_path("lib/client.js", "axios").is_err());
+        assert!(validate_node_item_path("lib/client.js#", "axios").is_err());
+    }
 }
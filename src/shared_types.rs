@@ -166,15 +166,78 @@ pub struct SemanticSearchResult {
     pub module_path: String,
     pub item_name: String,
     pub qualified_path: String,
+    /// Other import paths of the same item, e.g. the defining module when
+    /// `qualified_path` is a package-level re-export
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub alternate_paths: Vec<String>,
     pub kind: String,
     pub file: String,
     pub line: u32,
     pub score: f32,
     pub doc_preview: Option<String>,
+    /// Full docstring split into sections (Python only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub structured_docstring: Option<StructuredDocstring>,
     pub signature: Option<String>,
     pub source_preview: Option<String>,
 }
 
+/// Docstring convention a [`StructuredDocstring`] was parsed from.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum DocstringStyle {
+    Google,
+    Numpy,
+    Sphinx,
+    #[default]
+    Plain,
+}
+
+/// Docstring split into its conventional sections.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct StructuredDocstring {
+    pub style: DocstringStyle,
+    /// First paragraph
+    pub summary: Option<String>,
+    /// Remaining prose, including sections without a dedicated field
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub parameters: Vec<DocstringParameter>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub returns: Option<DocstringReturns>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub raises: Vec<DocstringRaises>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub examples: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deprecated: Option<String>,
+}
+
+/// A documented parameter.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct DocstringParameter {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub type_name: Option<String>,
+    pub description: Option<String>,
+}
+
+/// The documented return (or yielded) value.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct DocstringReturns {
+    #[serde(rename = "type")]
+    pub type_name: Option<String>,
+    pub description: Option<String>,
+}
+
+/// A documented exception.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct DocstringRaises {
+    pub exception: String,
+    pub description: Option<String>,
+}
+
 /// Code snippet with context.
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
 pub struct SourceSnippet {
@@ -0,0 +1,5 @@
+var searchIndex = JSON.parse('{\
+"other":{"doc":"","t":[5],"n":["unrelated"],"q":["other"],"d":[""],"i":[0],"f":[[5,[],[]]],"p":[]},\
+"demo_crate":{"doc":"Demo crate.","t":[3,11,0,3,11,8,10,5],"n":["Widget","new","sync","Mutex","lock","Render","render","helper"],"q":["demo_crate","","","demo_crate::sync","","demo_crate","","demo_crate::sync"],"d":["A widget.","Create a widget.","Synchronization primitives.","A mutex.","Lock it.","Things that render.","Render it.","Helps."],"i":[0,1,0,0,2,0,3,0],"f":[null,[[],["widget",3]],null,null,[[["mutex",3]]],null,[[["self"]]],[[]]],"p":[[3,"Widget"],[3,"Mutex"],[8,"Render"]]}\
+}');
+if (window.initSearch) {window.initSearch(searchIndex)};
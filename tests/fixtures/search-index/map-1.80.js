@@ -0,0 +1,5 @@
+var searchIndex = new Map(JSON.parse('[\
+["demo",{"t":"FNCFNKMH","n":["Widget","new","sync","Mutex","lock","Render","render","helper"],"q":[[0,"demo"],[3,"demo::sync"],[5,"demo"],[7,"demo::sync"]],"i":[0,1,0,0,2,0,3,0],"f":"````{{}b}`{{{f}}{}}`{{{d}}{}}{{}{}}","D":"h","p":[[5,"Widget",0],[5,"Mutex",3],[10,"Render",0]],"b":[],"c":"OjAAAAAAAAA=","e":"OzAAAAEAAAAAAAEAEAAAAAEA"}]\
+]'));
+if (typeof exports !== 'undefined') exports.searchIndex = searchIndex;
+else if (window.initSearch) window.initSearch(searchIndex);
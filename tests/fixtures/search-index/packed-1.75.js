@@ -0,0 +1,5 @@
+var searchIndex = JSON.parse('{\
+"demo":{"doc":"Demo crate.","t":"FNCFNKMH","n":["Widget","new","sync","Mutex","lock","Render","render","helper"],"q":[[0,"demo"],[3,"demo::sync"],[5,"demo"],[7,"demo::sync"]],"d":["A widget.","Create a widget.","Synchronization primitives.","A mutex.","Lock it.","Things that render.","Render it.","Helps."],"i":[0,1,0,0,2,0,3,0],"f":"````{{}b}`{{{f}}{}}`{{{d}}{}}{{}{}}","c":[],"p":[[5,"Widget"],[5,"Mutex"],[10,"Render"]]}\
+}');
+if (typeof window !== 'undefined' && window.initSearch) {window.initSearch(searchIndex)};
+if (typeof exports !== 'undefined') {exports.searchIndex = searchIndex};
@@ -0,0 +1,3 @@
+var N = null;var searchIndex = {};
+searchIndex["demo"]={"doc":"Demo crate.","items":[[3,"Widget","demo","A widget.",N,N],[11,"new","","Create a widget.",0,{"i":[],"o":{"n":"self"}}],[0,"sync","","Synchronization {primitives}.",N,N],[3,"Mutex","demo::sync","A mutex.",N,N],[11,"lock","","Lock it.",1,N],[8,"Render","demo","Things that render.",N,N],[10,"render","","Render it.",2,N],[5,"helper","demo::sync","Helps.",N,N]],"paths":[[3,"Widget"],[3,"Mutex"],[8,"Render"]]};
+initSearch(searchIndex);
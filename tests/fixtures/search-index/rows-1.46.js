@@ -0,0 +1,4 @@
+var searchIndex = JSON.parse('{\
+"demo":{"doc":"Demo crate.","i":[[3,"Widget","demo","A widget. It\'s \\"quoted\\".",null,null],[11,"new","","Create a widget.",0,[[],["self"]]],[0,"sync","","Synchronization primitives.",null,null],[3,"Mutex","demo::sync","A mutex.",null,null],[11,"lock","","Lock it.",1,null],[8,"Render","demo","Things that render.",null,null],[10,"render","","Render it.",2,null],[5,"helper","demo::sync","Helps.",null,null]],"p":[[3,"Widget"],[3,"Mutex"],[8,"Render"]]}\
+}');
+addSearchOptions(searchIndex);initSearch(searchIndex);
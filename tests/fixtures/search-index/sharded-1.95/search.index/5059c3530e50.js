@@ -0,0 +1 @@
+rn_("JQBAAAAiIQAlACYAbRAAFQAWACUAQwAAIiEAJQAmAG0QABUAFgBRQAAABAAHAAgAGQAdAB4A4wCEoBAAAAALBBAR1wUAAAABAwEQBQE=")
\ No newline at end of file
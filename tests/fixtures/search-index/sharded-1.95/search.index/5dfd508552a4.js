@@ -0,0 +1 @@
+rn_("JQBAAAADIgBlEQAXABgABQHAAAABIgASEQAYAGNkBQHAAACTIwACFwBhcCFBAAATABsAHADHBgCLsAAAIwABoGAAAAAioBAAAAAGoDAAAAASoAAAAAAUoAAAAAADoAAAAAAPKDk3ASIAAAA=")
\ No newline at end of file
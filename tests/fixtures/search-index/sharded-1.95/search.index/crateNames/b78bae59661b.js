@@ -0,0 +1 @@
+rd_("ddemoiunrelated")
\ No newline at end of file
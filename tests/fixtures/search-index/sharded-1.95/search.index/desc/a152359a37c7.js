@@ -0,0 +1 @@
+rd_("nMake a widget.kDemo crate.AoReturns the argument unchanged.0BaCalls <code>U::from(self)</code>.0hLock it.AmSynchronization {primitives}.gA lock.oRenders things.AnA widget. It\xe2\x80\x99s \xe2\x80\x9cquoted\xe2\x80\x9d.fHelps.jRender it.AaNot part of demo.")
\ No newline at end of file
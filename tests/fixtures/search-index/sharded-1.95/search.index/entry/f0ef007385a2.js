@@ -0,0 +1 @@
+rd_("Ac[6,13,7,7,21,0,0,0]Aa[6,3,0,0,0,0,0,0]Ac[6,13,7,7,21,5,0,0]Ae[6,13,13,13,16,5,0,0]Ac[6,13,7,7,21,6,0,0]Ae[6,13,13,13,16,6,0,0]Ae[6,13,13,13,16,0,0,0]Aa[6,2,7,0,0,0,0,0]Ab[6,5,13,0,0,0,0,0]Ab[6,10,7,0,0,0,0,0]Aa[6,5,7,0,0,0,0,0]Ad[6,13,7,7,21,17,0,0]Af[6,13,13,13,16,17,0,0]Ab[6,7,13,0,0,0,0,0]Ac[6,12,7,7,18,0,0,0]Ac[6,13,7,7,21,2,0,0]Ae[6,13,13,13,16,2,0,0]Ad[6,13,7,7,21,26,0,0]Af[6,13,13,13,16,26,0,0]Ad[6,13,7,7,21,27,0,0]Af[6,13,13,13,16,27,0,0]Ab[35,3,0,0,0,0,0,0]Ac[35,7,36,0,0,0,0,0]Ad[6,13,7,7,21,34,0,0]Af[6,13,13,13,16,34,0,0]")
\ No newline at end of file
@@ -0,0 +1 @@
+rd_("m[\"{{}Bj}\",[]]A`[\"{cc{}}\",[\"T\"]]0Aa[\"{{}c{}}\",[\"U\"]]0Ae[\"{{{Df{B`}}}An}\",[]]Ai[\"{Df{{Df{c}}}{}}\",[\"T\"]]0m[\"{{}An}\",[]]Ae[\"{{{Df{Bd}}}An}\",[]]m[\"{DfBh}\",[]]0An[\"{c{{Bf{e}}}{}{}}\",[\"U\",\"T\"]]0Ai[\"{{}{{Bf{c}}}{}}\",[\"U\"]]04Ba[\"{{{Df{f}}}{{Df{fc}}}{}}\",[\"T\"]]0")
\ No newline at end of file
@@ -0,0 +1 @@
+rb_("Q2YAAgkAAAAKAAAABAcAAAAIAAAAHwAAACAAAAAEFQAAABYAAAAdAAAAHgAAAAACJQAAACYAAABsAAAAAh0AAAAeAAAA")
\ No newline at end of file
@@ -0,0 +1 @@
+rd_("b()cAnycmutcnewdFromdIntoddemodfrom0dinto0dlockdsync0duniteMutexfBorrowfRenderfResultfTypeIdfWidgetfborrow0fhelperfrendergTryFromgTryIntogtype_id0htry_from0htry_into0iBorrowMutireferenceiunrelated0jborrow_mut0")
\ No newline at end of file
@@ -0,0 +1 @@
+rd_("b()canycmutcnewdfromdintoddemo2211dlockdsync0dunitemutexfborrowfrenderfresultftypeidfwidget44fhelper4gtryfromgtryinto441100iborrowmutireferenceiunrelated022")
\ No newline at end of file
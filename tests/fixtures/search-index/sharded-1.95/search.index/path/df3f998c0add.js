@@ -0,0 +1 @@
+rd_("f[1,\"\"]A`[10,\"core::any\"]f[0,\"\"]Ad[10,\"core::convert\"]0f[3,\"\"]j[2,\"demo\"]5Am[5,\"demo::sync\",\"demo::sync\"]Ac[10,\"core::borrow\"]Ab[10,\"demo\",\"demo\"]Ba[6,\"core::result\",\"core::result\"]Ak[5,\"core::any\",\"core::any\"]Aa[5,\"demo\",\"demo\"]884;7")
\ No newline at end of file
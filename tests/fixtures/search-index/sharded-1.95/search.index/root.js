@@ -0,0 +1 @@
+rr_('{"normalizedName":{"I":"BQNAAAAjGQAdAB4AIxoAHwAgAJEMACMTABsAHABmaW5wAQAFAcEAACETABsAHAAAFwBpcpUBQAAAACIAAAsAZWsMACFCAAAhACUAJgAARGRnZXQAABQA8gABAWltANQDAAAADQUBNQBAAAABDwBlAgAhACUAJgAEAcAAAAF0DgCWZWxhdGVkIwBpclKAAQKgEAAAABJubHRRQAAABQAJAAoAGgAfACAABQHAAACQIwAADwBkeCBEcGVpZAAAEwAbABwABAHBeQAAI3JvbRkAHQAeACNudG8aAB8AIABmafIAAgJyeWVvANcCAAAADAQCDQQBkEJuYwAADAASgAEBoCAAAAASeXUFAcAAACMZAB0AHgAjGgAfACAAZmklAEIAACIhACUAJgBtEAAVABYAJQBAAAAiIQAlACYAbRAAFQAWAFFAAAAEAAcACAAZAB0AHgD7Am13AEN1bHQAABIAEEJlcgAAEQAYABKAAQGgEAAAACJkYwBGZXJlbmNlAAAiAHKAAwGwQAAjAAFmbnNs8gABA2VvcnkA0xEAAAAGAVFBAAAFAAkACgAaAB8AIACVAEAAAAAiAGUMACYCgIGG53AAAAADsCAAEQAHoBAAAAAOsGAAIwABoAAAAAABEAAADAFFAAF0AQDngAAAAA9lAQIAAADTIQAAAAQBEgABAaAAAAAABnVvANYEAAAAAwERBAEAQmNrAAALABKAAQOwMAAjAAGgIAAAABegAAAAABJvYXB0JQBAAAACFABnEwAbABwAAAJ0bwAA0wUAAAAEAdMaAAAABQEygAECoAAAAAAObmR0AEVlbHBlcgAAFwAAAm9tAADTBAAAAAMB0xkAAAAEARKAAQGgUAAAACJyZQCAAQHm8AAAAAawAAARAAdtchIAAQGgIAAAABRlZwDVEwAAAAgBBwEkAEVvcnJvdwAAInV0IQAlACYAbRAAFQAWAACAAQHnkAAAAAGwIAAjAAFudACADwnikAAAAAA+cV5v/NE70uBJA6gEM82SUIRJkVPWzMJ/JpeYM1VXcTZnvqkZ8gjf9xQIgKGRDVwJbxgeiLwoq4wNHiAQyE1CsMJDatSmd3YtG4LT09lYUTBYkvSgAAAAAAALJ//DaJ1d/VCFUqSgIAAAABSgAAAAAAtQWcNTDlAg+nHt68mgAAAAAA9VSkMUyH8oYWJkZmhpbG1ucnN0dXcpY2Vna29weHk=","N":"Bg","E":"OjAAAAAAAAA=","H":"iNrxq+NE"},"crateNames":{"N":"b","E":"OjAAAAAAAAA=","H":"t4uuWWYb"},"name":{"N":"Bg","E":"OjAAAAAAAAA=","H":"3tagKYKi"},"path":{"N":"Ac","E":"OzAAAAEAABMABgADAAAABwAEAA0AAAAVAAMAGwAFACQAAgA=","H":"3z+ZjArd"},"entry":{"N":"Ai","E":"OjAAAAEAAAAAAA0AEAAAAAAAAQACAAQABQAMAA4AEAASABMAGQAaACEAIgA=","H":"8O8Ac4Wi"},"desc":{"N":"n","E":"OzAAAAEAABgACQAAAAIABAABAAwAAAAOAAAAEAAAABIAAQAVAAEAGQAKACUAAQA=","H":"oVI1mjfH"},"function":{"N":"Ac","E":"OzAAAAEAABMABQAAAAIABAACAAwACAAZAAEAIQACAA==","H":"GW01WwWg"},"type":{"N":"i","E":"OzAAAAEAAB0ABQABAAAAAwAKABAAAAAVAAwAIwADAA==","H":"yJzPoz+a"},"alias":{"N":"`","E":"OzAAAAEAACYAAQAAACYA","H":"p2IVDFMs"},"generic_inverted_index":{"N":"b","E":"OjAAAAAAAAA=","H":"BPsF/IdY"}}')
\ No newline at end of file
@@ -0,0 +1 @@
+rd_("Bc[\"\",\"AAIXAAAAJAAAAAACCwAAABgAAAA=\"]Bo[\"AAAAAAACJQAAACYAAAA=\",\"AAAAAAACJQAAACYAAAA=\"]1Ac[\"AAAAAQsAAAA=\",\"\"]Ac[\"AAAAARgAAAA=\",\"\"]Be[\"\",\"AAACHwAAACAAAAACHQAAAB4AAAA=\",1]Ag[\"\",\"AAACGwAAABwAAAA=\"]o[\"\",\"AAEDAAAA\"]Em[\"AAACGwAAABwAAAAECwAAABUAAAAWAAAAGAAAAAACJQAAACYAAAA=\",\"AAAAAhUAAAAWAAAAAAIlAAAAJgAAAA==\",1]")
\ No newline at end of file
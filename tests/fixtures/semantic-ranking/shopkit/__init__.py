@@ -0,0 +1,4 @@
+"""Shopkit: a small storefront toolkit used as a ranking fixture."""
+
+from .cart import ShoppingCart, calculate_total
+from .users import create_user, delete_user
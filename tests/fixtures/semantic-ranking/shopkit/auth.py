@@ -0,0 +1,14 @@
+def authenticate(username, password):
+    """Check a username and password and return the matching account."""
+
+
+def issue_token(user, ttl=3600):
+    """Create a signed session token for an authenticated user."""
+
+
+def verify_token(token):
+    """Validate a session token signature and expiry."""
+
+
+def hash_password(password, salt):
+    """Derive a secure hash of a password for storage."""
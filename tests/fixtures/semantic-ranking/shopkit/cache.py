@@ -0,0 +1,7 @@
+def memoize(func, ttl=60):
+    """Decorator caching function results for ``ttl`` seconds."""
+    return func
+
+
+def invalidate_cache(key):
+    """Drop a cached value so the next lookup recomputes it."""
@@ -0,0 +1,18 @@
+class ShoppingCart:
+    """A customer's basket of items awaiting checkout."""
+
+    def add_item(self, sku, quantity=1):
+        pass
+
+    def remove_item(self, sku):
+        pass
+
+
+def calculate_total(items, tax_rate=0.0):
+    """Sum the item prices in a basket and apply sales tax."""
+    return sum(item.price for item in items) * (1 + tax_rate)
+
+
+def apply_discount(total, code):
+    """Reduce an order total using a promotional discount code."""
+    return total
@@ -0,0 +1,24 @@
+import json
+
+
+class ConfigError(Exception):
+    """Raised when a configuration file is missing or malformed."""
+
+
+def load_config(path):
+    """Read the JSON configuration file at ``path`` and return a dict."""
+    with open(path) as fh:
+        return json.load(fh)
+
+
+def save_settings(settings, path):
+    """Write application settings back to disk as JSON."""
+    with open(path, "w") as fh:
+        json.dump(settings, fh)
+
+
+def merge_overrides(base, overrides):
+    """Combine default values with user supplied overrides."""
+    merged = dict(base)
+    merged.update(overrides)
+    return merged
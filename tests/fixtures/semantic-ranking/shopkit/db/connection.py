@@ -0,0 +1,10 @@
+class ConnectionPool:
+    """Reusable pool of open database connections."""
+
+
+def open_connection(dsn, timeout=5):
+    """Connect to the database described by a DSN string."""
+
+
+def close_connection(conn):
+    """Close a database connection and release it to the pool."""
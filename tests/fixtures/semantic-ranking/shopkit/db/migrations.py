@@ -0,0 +1,6 @@
+def apply_migrations(conn, directory):
+    """Run pending schema migration scripts in order."""
+
+
+def rollback_migration(conn, version):
+    """Undo the schema migration with the given version number."""
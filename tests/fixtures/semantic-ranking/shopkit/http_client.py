@@ -0,0 +1,21 @@
+class RetryPolicy:
+    """Exponential backoff policy deciding when a failed call is retried."""
+
+    def __init__(self, attempts=3, backoff=0.5):
+        self.attempts = attempts
+        self.backoff = backoff
+
+
+def send_request(method, url, payload=None, timeout=10):
+    """Send an HTTP request and return the decoded response body."""
+    raise NotImplementedError
+
+
+def fetch_json(url):
+    """Download a URL and parse the JSON document it returns."""
+    return send_request("GET", url)
+
+
+def build_query_string(params):
+    """Encode a mapping of query parameters for use in a URL."""
+    return "&".join(f"{k}={v}" for k, v in params.items())
@@ -0,0 +1,23 @@
+class UserRepository:
+    """Storage of user accounts, looked up by id or email address."""
+
+    def find_by_email(self, email):
+        return None
+
+
+def create_user(email, password):
+    """Register a brand new account and send the welcome email."""
+    return {"email": email}
+
+
+def delete_user(user_id):
+    """Permanently remove an account and all of its data."""
+
+
+def reset_password(user):
+    """Email a password reset link to a user who forgot their password."""
+
+
+def list_active_users(repository):
+    """Return every account that logged in during the last thirty days."""
+    return []
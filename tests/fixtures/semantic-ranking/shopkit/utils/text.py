@@ -0,0 +1,15 @@
+def slugify(text):
+    """Turn arbitrary text into a lowercase url slug."""
+    return text.lower().replace(" ", "-")
+
+
+def truncate_string(value, max_len=80):
+    """Shorten long strings, adding an ellipsis when cut."""
+    return value[:max_len]
+
+
+def parse_env(name, default=None):
+    """Read an environment variable, falling back to a default."""
+    import os
+
+    return os.environ.get(name, default)
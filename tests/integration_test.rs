@@ -5,9 +5,9 @@
 use anyhow::Result;
 use dociium::doc_engine::types::ImportResolutionParams;
 use dociium::{
-    CrateInfoParams, GetImplementationParams, GetItemDocParams, ListImplsForTypeParams,
-    ListTraitImplsParams, RustDocsMcpServer, SearchCratesParams, SearchSymbolsParams,
-    SourceSnippetParams, ToolConfig,
+    CrateInfoParams, GetImplementationParams, GetItemDocParams, IndexHealthParams,
+    ListImplsForTypeParams, ListTraitImplsParams, RustDocsMcpServer, SearchCratesParams,
+    SearchSymbolsGlobalParams, SearchSymbolsParams, SourceSnippetParams, ToolConfig,
 };
 use rmcp::{handler::server::wrapper::Parameters, model::CallToolResult, ServerHandler};
 use std::fs;
@@ -587,6 +587,304 @@ async fn test_get_implementation_invalid_params() {
     assert!(response.is_err());
 }
 
+#[tokio::test]
+async fn test_search_symbols_global_over_cache() {
+    use dociium::doc_engine::cache::Cache;
+    use dociium::doc_engine::types::{SearchIndexData, SearchIndexItem};
+
+    let (server, temp_dir) = create_test_server().await.unwrap();
+    let item = |name: &str, kind: &str, path: &str| SearchIndexItem {
+        name: name.to_string(),
+        kind: kind.to_string(),
+        path: path.to_string(),
+        description: String::new(),
+        parent_index: None,
+    };
+    let cache = Cache::new(temp_dir.path()).unwrap();
+    for (crate_name, version, items) in [
+        (
+            "backoff",
+            "0.4.0",
+            vec![item("retry", "function", "backoff::future")],
+        ),
+        (
+            "reqwest_retry",
+            "0.7.0",
+            vec![
+                item("RetryPolicy", "struct", "reqwest_retry::policies"),
+                item("Client", "struct", "reqwest_retry"),
+            ],
+        ),
+        (
+            "backoff",
+            "0.3.0",
+            vec![item("retry", "function", "backoff")],
+        ),
+    ] {
+        let data = SearchIndexData {
+            crate_name: crate_name.to_string(),
+            version: version.to_string(),
+            items,
+            paths: vec![],
+        };
+        cache.store_crate_index(crate_name, version, &data).unwrap();
+    }
+
+    let params = Parameters(SearchSymbolsGlobalParams {
+        query: "retry".to_string(),
+        kinds: None,
+        limit: Some(10),
+        scope: Some("cache".to_string()),
+        context_path: None,
+        fetch_missing: None,
+    });
+    let result = server.search_symbols_global(params).await.unwrap();
+    let json: serde_json::Value = serde_json::from_str(&get_text_content(&result)).unwrap();
+
+    assert_eq!(
+        json["crates_searched"], 2,
+        "only the newest backoff is searched"
+    );
+    let results = json["results"].as_array().unwrap();
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[0]["crate_name"], "backoff");
+    assert_eq!(results[0]["crate_version"], "0.4.0");
+    assert_eq!(results[0]["path"], "backoff::future");
+    // Name match outranks a match on the crate path alone
+    assert_eq!(results[1]["crate_name"], "reqwest_retry");
+    assert_eq!(results[1]["path"], "reqwest_retry::policies");
+    assert_eq!(results[2]["path"], "reqwest_retry");
+    // Scores are normalized by each crate's best hit before merging
+    assert_eq!(results[0]["score"], 1.0);
+    assert_eq!(results[1]["score"], 1.0);
+    assert!(results[2]["score"].as_f64().unwrap() < 1.0);
+}
+
+#[tokio::test]
+async fn test_search_symbols_global_invalid_scope() {
+    let (server, _temp_dir) = create_test_server().await.unwrap();
+    let params = Parameters(SearchSymbolsGlobalParams {
+        query: "retry".to_string(),
+        kinds: None,
+        limit: None,
+        scope: Some("everywhere".to_string()),
+        context_path: None,
+        fetch_missing: None,
+    });
+    assert!(server.search_symbols_global(params).await.is_err());
+}
+
+#[tokio::test]
+async fn test_index_health_reports_validation_problems() {
+    use dociium::doc_engine::cache::Cache;
+    use dociium::doc_engine::types::{SearchIndexData, SearchIndexItem};
+
+    let (server, temp_dir) = create_test_server().await.unwrap();
+    let data = SearchIndexData {
+        crate_name: "broken".to_string(),
+        version: "1.0.0".to_string(),
+        items: vec![
+            SearchIndexItem {
+                name: "Widget".to_string(),
+                kind: "struct".to_string(),
+                path: "broken::widgets".to_string(),
+                description: "A widget".to_string(),
+                parent_index: None,
+            },
+            SearchIndexItem {
+                name: "orphan".to_string(),
+                kind: "method".to_string(),
+                path: String::new(),
+                description: String::new(),
+                parent_index: Some(7),
+            },
+        ],
+        paths: vec![],
+    };
+    Cache::new(temp_dir.path())
+        .unwrap()
+        .store_crate_index("broken", "1.0.0", &data)
+        .unwrap();
+
+    let empty = server
+        .index_health(Parameters(IndexHealthParams { language: None }))
+        .await
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_str(&get_text_content(&empty)).unwrap();
+    assert!(json["indexes"].as_array().unwrap().is_empty());
+
+    // Building the index through a search registers it with the tracker
+    let params = Parameters(SearchSymbolsGlobalParams {
+        query: "widget".to_string(),
+        kinds: None,
+        limit: None,
+        scope: Some("cache".to_string()),
+        context_path: None,
+        fetch_missing: None,
+    });
+    server.search_symbols_global(params).await.unwrap();
+
+    let result = server
+        .index_health(Parameters(IndexHealthParams {
+            language: Some("rust".to_string()),
+        }))
+        .await
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_str(&get_text_content(&result)).unwrap();
+    let indexes = json["indexes"].as_array().unwrap();
+    assert_eq!(indexes.len(), 1);
+    let index = &indexes[0];
+    assert_eq!(index["index_id"], "rust:broken@1.0.0");
+    assert_eq!(index["kind"], "rust_symbols");
+    assert_eq!(index["item_count"], 2);
+    assert!(index["size_bytes"].as_u64().unwrap() > 0);
+    assert_eq!(index["stale"], false);
+    assert_eq!(index["validation"]["is_valid"], false);
+    assert_eq!(index["validation"]["stats"]["broken_links"], 1);
+    let errors = index["validation"]["errors"].as_array().unwrap();
+    assert!(errors
+        .iter()
+        .any(|e| e.as_str().unwrap().contains("has no path")));
+    assert!(errors
+        .iter()
+        .any(|e| e.as_str().unwrap().contains("dangling parent_index")));
+
+    let invalid = server
+        .index_health(Parameters(IndexHealthParams {
+            language: Some("cobol".to_string()),
+        }))
+        .await;
+    assert!(invalid.is_err());
+}
+
+#[tokio::test]
+async fn test_index_manifest_reports_each_entry() {
+    use dociium::doc_engine::cache::Cache;
+    use dociium::doc_engine::types::{BatchIndexTarget, SearchIndexData, SearchIndexItem};
+
+    let temp_dir = TempDir::new().unwrap();
+    let cache_dir = temp_dir.path().join("cache");
+    let project = temp_dir.path().join("project");
+    fs::create_dir_all(project.join("node_modules/leftpad")).unwrap();
+    fs::write(
+        project.join("node_modules/leftpad/package.json"),
+        r#"{"name": "leftpad", "main": "index.js"}"#,
+    )
+    .unwrap();
+    fs::write(
+        project.join("node_modules/leftpad/index.js"),
+        "/** Pad a string on the left. */\nexport function leftPad(text, width) {}\n",
+    )
+    .unwrap();
+    let site_packages = project.join(".venv/lib/python3.12/site-packages");
+    fs::create_dir_all(site_packages.join("yaml")).unwrap();
+    fs::write(
+        site_packages.join("yaml/__init__.py"),
+        "def safe_load(stream):\n    \"\"\"Parse a YAML document.\"\"\"\n",
+    )
+    .unwrap();
+    fs::create_dir_all(site_packages.join("PyYAML-6.0.1.dist-info")).unwrap();
+    fs::write(
+        site_packages.join("PyYAML-6.0.1.dist-info/top_level.txt"),
+        // `tests` is listed but not installed; it is skipped
+        "_yaml\ntests\nyaml\n",
+    )
+    .unwrap();
+    fs::write(
+        project.join("Cargo.lock"),
+        r#"
+[[package]]
+name = "backoff"
+version = "0.4.0"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+"#,
+    )
+    .unwrap();
+
+    let data = SearchIndexData {
+        crate_name: "backoff".to_string(),
+        version: "0.4.0".to_string(),
+        items: vec![SearchIndexItem {
+            name: "retry".to_string(),
+            kind: "function".to_string(),
+            path: "backoff::future".to_string(),
+            description: String::new(),
+            parent_index: None,
+        }],
+        paths: vec![],
+    };
+    Cache::new(&cache_dir)
+        .unwrap()
+        .store_crate_index("backoff", "0.4.0", &data)
+        .unwrap();
+
+    let engine = dociium::doc_engine::DocEngine::new(&cache_dir)
+        .await
+        .unwrap();
+
+    let report = engine
+        .index_manifest(&project.join("Cargo.lock"), 2, None)
+        .await
+        .unwrap();
+    assert_eq!(report.total, 1);
+    assert_eq!(report.succeeded, 1);
+    assert_eq!(report.entries[0].name, "backoff");
+    assert_eq!(report.entries[0].item_count, Some(1));
+
+    let target = |language: &str, name: &str| BatchIndexTarget {
+        language: language.to_string(),
+        name: name.to_string(),
+        version: None,
+    };
+    let report = engine
+        .index_batch(
+            vec![
+                target("node", "leftpad"),
+                target("node", "missing"),
+                target("cobol", "ledger"),
+                target("python", "PyYAML"),
+            ],
+            2,
+            project.to_str(),
+        )
+        .await;
+    assert_eq!(report.total, 4);
+    assert_eq!(report.succeeded, 2);
+    assert_eq!(report.failed, 2);
+    let names: Vec<&str> = report.entries.iter().map(|e| e.name.as_str()).collect();
+    assert_eq!(names, vec!["leftpad", "missing", "ledger", "PyYAML"]);
+    assert!(report.entries[0].success);
+    assert!(report.entries[0].item_count.unwrap() >= 1);
+    assert!(report.entries[0]
+        .location
+        .as_deref()
+        .unwrap()
+        .ends_with("leftpad"));
+    assert!(report.entries[1].error.is_some());
+    assert!(report.entries[2]
+        .error
+        .as_deref()
+        .unwrap()
+        .contains("Unsupported language"));
+    assert!(report.entries[3].success, "{:?}", report.entries[3].error);
+    assert!(report.entries[3]
+        .location
+        .as_deref()
+        .unwrap()
+        .ends_with("yaml"));
+
+    let health = engine.index_health(Some("node")).await.unwrap();
+    assert_eq!(health.indexes.len(), 1);
+    assert_eq!(health.indexes[0].name, "leftpad");
+    assert_eq!(health.indexes[0].item_count, 1);
+    assert!(!health.indexes[0].stale);
+
+    assert!(engine
+        .index_manifest(&project.join("Pipfile"), 2, None)
+        .await
+        .is_err());
+}
+
 //
 // New tests for multi-language import resolution
 //
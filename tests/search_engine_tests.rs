@@ -5,9 +5,10 @@
 //!   - QueryBuilder string assembly
 //!   - Default configuration invariants
 //!   - Basic / fuzzy / exact search placeholder behaviors
-//!   - Suggestions logic
+//!   - Suggestions logic backed by the completion index
 //!   - Filter wiring exposure (even though current engine is placeholder)
 
+use dociium::index_core::completion::CompletionIndex;
 use dociium::index_core::search::{QueryBuilder, SearchConfig, SearchEngine, SearchFilters};
 
 /// Helper: assert substrings exist in query output
@@ -101,17 +102,36 @@ fn test_fuzzy_search_empty_term() {
     assert!(results.is_empty());
 }
 
+/// Helper: engine with a small completion index attached
+fn engine_with_completions() -> SearchEngine {
+    let completions = CompletionIndex::from_entries(vec![
+        ("Vec".to_string(), "struct".to_string()),
+        ("VecDeque".to_string(), "struct".to_string()),
+        ("MyStruct".to_string(), "struct".to_string()),
+        ("MyTrait".to_string(), "trait".to_string()),
+        ("my_function".to_string(), "function".to_string()),
+    ]);
+    SearchEngine::new().unwrap().with_completions(completions)
+}
+
 #[test]
 fn test_suggestions_basic() {
-    let engine = SearchEngine::new().unwrap();
+    let engine = engine_with_completions();
     let suggestions = engine.get_suggestions("Vec", 5).unwrap();
     assert!(!suggestions.is_empty());
     assert!(suggestions.iter().any(|s| s.starts_with("Vec")));
+    assert_eq!(suggestions[0], "Vec");
 }
 
 #[test]
-fn test_suggestions_limit() {
+fn test_suggestions_without_index() {
     let engine = SearchEngine::new().unwrap();
+    assert!(engine.get_suggestions("Vec", 5).unwrap().is_empty());
+}
+
+#[test]
+fn test_suggestions_limit() {
+    let engine = engine_with_completions();
     let suggestions = engine.get_suggestions("My", 1).unwrap();
     assert!(
         suggestions.len() <= 1,
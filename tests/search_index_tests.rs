@@ -0,0 +1,206 @@
+//! Fixture-based tests for the versioned rustdoc search-index decoder.
+//!
+//! Each fixture under `tests/fixtures/search-index/` mirrors the layout one
+//! rustdoc generation emits for the same small `demo` crate:
+//!   - `Widget` (struct) with method `new`
+//!   - `sync` (module) containing `Mutex` (struct) with method `lock`
+//!   - `Render` (trait) with required method `render`
+//!   - `sync::helper` (function)
+//!
+//! `sharded-1.95/` is the `search.index/` directory rustdoc 1.95 generated
+//! for that crate, documented together with a dependency `unrelated`.
+
+use dociium::doc_engine::rustdoc_index::{
+    decode_sharded_index, parse_all_items_page, parse_search_index, DecodedSearchIndex,
+    SearchIndexFormat, ShardedIndexRoot,
+};
+use dociium::doc_engine::types::SearchIndexData;
+use std::collections::HashMap;
+
+fn fixture(name: &str) -> String {
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures/search-index")
+        .join(name);
+    std::fs::read_to_string(&path).unwrap_or_else(|e| panic!("{}: {e}", path.display()))
+}
+
+fn decode(name: &str, crate_name: &str) -> DecodedSearchIndex {
+    parse_search_index(&fixture(name), crate_name, "0.1.0")
+        .unwrap_or_else(|e| panic!("{name}: {e:#}"))
+}
+
+/// (name, kind, path, parent path)
+fn summarize(data: &SearchIndexData) -> Vec<(String, String, String, Option<String>)> {
+    data.items
+        .iter()
+        .map(|item| {
+            (
+                item.name.clone(),
+                item.kind.clone(),
+                item.path.clone(),
+                item.parent_index.map(|idx| data.paths[idx].clone()),
+            )
+        })
+        .collect()
+}
+
+fn expected_items(krate: &str) -> Vec<(String, String, String, Option<String>)> {
+    let sync = format!("{krate}::sync");
+    let parent = |name: &str, module: &str| Some(format!("{module}::{name}"));
+    vec![
+        ("Widget", "struct", krate.to_string(), None),
+        ("new", "method", krate.to_string(), parent("Widget", krate)),
+        ("sync", "module", krate.to_string(), None),
+        ("Mutex", "struct", sync.clone(), None),
+        ("lock", "method", sync.clone(), parent("Mutex", &sync)),
+        ("Render", "trait", krate.to_string(), None),
+        (
+            "render",
+            "tymethod",
+            krate.to_string(),
+            parent("Render", krate),
+        ),
+        ("helper", "function", sync.clone(), None),
+    ]
+    .into_iter()
+    .map(|(name, kind, path, parent)| (name.to_string(), kind.to_string(), path, parent))
+    .collect()
+}
+
+#[test]
+fn decodes_row_tuples_with_null_alias() {
+    let decoded = decode("rows-1.30.js", "demo");
+    assert_eq!(decoded.format, SearchIndexFormat::RowTuples);
+    assert_eq!(summarize(&decoded.data), expected_items("demo"));
+    assert_eq!(
+        decoded.data.items[2].description,
+        "Synchronization {primitives}."
+    );
+}
+
+#[test]
+fn decodes_row_tuples_from_json_parse_literal() {
+    let decoded = decode("rows-1.46.js", "demo");
+    assert_eq!(decoded.format, SearchIndexFormat::RowTuples);
+    assert_eq!(summarize(&decoded.data), expected_items("demo"));
+    assert_eq!(
+        decoded.data.items[0].description,
+        "A widget. It's \"quoted\"."
+    );
+}
+
+#[test]
+fn decodes_columns_and_selects_requested_crate() {
+    let decoded = decode("columns-1.60.js", "demo-crate");
+    assert_eq!(decoded.format, SearchIndexFormat::Columns);
+    assert_eq!(decoded.data.crate_name, "demo-crate");
+    assert_eq!(summarize(&decoded.data), expected_items("demo_crate"));
+    assert_eq!(decoded.data.items[4].description, "Lock it.");
+    assert!(decoded.data.items.iter().all(|i| i.name != "unrelated"));
+}
+
+#[test]
+fn decodes_packed_columns_with_new_kind_numbering() {
+    let decoded = decode("packed-1.75.js", "demo");
+    assert_eq!(decoded.format, SearchIndexFormat::PackedColumns);
+    assert_eq!(summarize(&decoded.data), expected_items("demo"));
+    assert_eq!(decoded.data.items[7].description, "Helps.");
+}
+
+#[test]
+fn decodes_map_wrapped_index_without_inline_descriptions() {
+    let decoded = decode("map-1.80.js", "demo");
+    assert_eq!(decoded.format, SearchIndexFormat::PackedColumns);
+    assert_eq!(summarize(&decoded.data), expected_items("demo"));
+    assert!(decoded.data.items.iter().all(|i| i.description.is_empty()));
+}
+
+fn decode_sharded(crate_name: &str) -> anyhow::Result<DecodedSearchIndex> {
+    let root = ShardedIndexRoot::parse(&fixture("sharded-1.95/search.index/root.js"))?;
+    let shards: HashMap<String, String> = root
+        .shard_files()
+        .into_iter()
+        .map(|file| {
+            let contents = fixture(&format!("sharded-1.95/search.index/{file}"));
+            (file, contents)
+        })
+        .collect();
+    decode_sharded_index(&root, &shards, crate_name, "0.1.0")
+}
+
+#[test]
+fn decodes_sharded_index_columns() {
+    let decoded = decode_sharded("demo").unwrap();
+    assert_eq!(decoded.format, SearchIndexFormat::Sharded);
+
+    // The index also lists blanket impl methods (`from`, `into`, ..)
+    let expected = expected_items("demo");
+    let mut items: Vec<_> = summarize(&decoded.data)
+        .into_iter()
+        .filter(|item| expected.iter().any(|e| e.0 == item.0))
+        .collect();
+    items.sort();
+    let mut expected = expected;
+    expected.sort();
+    assert_eq!(items, expected);
+
+    let description = |name: &str| {
+        decoded
+            .data
+            .items
+            .iter()
+            .find(|item| item.name == name)
+            .map(|item| item.description.as_str())
+            .unwrap()
+    };
+    assert_eq!(description("sync"), "Synchronization {primitives}.");
+    assert_eq!(description("Widget"), "A widget. It’s “quoted”.");
+    assert_eq!(description("lock"), "Lock it.");
+
+    let from = summarize(&decoded.data)
+        .into_iter()
+        .find(|item| item.0 == "from")
+        .unwrap();
+    assert_eq!(from.1, "method");
+    assert_eq!(from.3.as_deref(), Some("demo::Widget"));
+    assert!(decoded.data.items.iter().all(|i| i.name != "unrelated"));
+    assert!(decoded.data.items.iter().all(|i| i.name != "demo"));
+
+    let unrelated = decode_sharded("unrelated").unwrap();
+    assert_eq!(summarize(&unrelated.data).len(), 1);
+    assert!(decode_sharded("absent").is_err());
+}
+
+#[test]
+fn decodes_all_items_page_without_search_index() {
+    let decoded = parse_all_items_page(&fixture("all-items-1.91.html"), "demo", "0.1.0").unwrap();
+    assert_eq!(decoded.format, SearchIndexFormat::AllItemsPage);
+    let items: Vec<(&str, &str, &str)> = decoded
+        .data
+        .items
+        .iter()
+        .map(|i| (i.name.as_str(), i.kind.as_str(), i.path.as_str()))
+        .collect();
+    assert_eq!(
+        items,
+        vec![
+            ("Widget", "struct", "demo"),
+            ("Mutex", "struct", "demo::sync"),
+            ("Render", "trait", "demo"),
+            ("helper", "function", "demo::sync"),
+            ("Result", "type_def", "demo"),
+        ]
+    );
+}
+
+#[test]
+fn reports_missing_crate_and_unknown_layouts() {
+    let err = parse_search_index(&fixture("packed-1.75.js"), "absent", "1.0.0").unwrap_err();
+    assert!(err.to_string().contains("absent"));
+
+    let unknown = r#"var searchIndex = JSON.parse('{"demo":{"x":[1,2,3]}}');"#;
+    let err = parse_search_index(unknown, "demo", "1.0.0").unwrap_err();
+    assert!(err.to_string().contains("Unrecognized search index layout"));
+
+    assert!(parse_all_items_page("<html><body></body></html>", "demo", "1.0.0").is_err());
+}